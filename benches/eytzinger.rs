@@ -0,0 +1,60 @@
+//! Measures lookups into 10M `u64`s in the Eytzinger layout against binary search on the sorted array
+//!
+//! Run with `cargo bench --bench eytzinger`. `SortedSlice::search` is used for the sorted array, so that neither side
+//! pays for a per-call sortedness check.
+//!
+//! On a single core VM, `EytzingerLayout::search` took 510-590ns against 565-625ns for `SortedSlice::search` over
+//! several runs, a speedup of 1.05-1.15x. Past the top levels the descent misses the cache as often as binary search,
+//! so most of the gain comes from those levels sharing cache lines.
+
+use binary_search::{variations::EytzingerLayout, SortedSlice};
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+const LEN: u64 = 10_000_000;
+const SAMPLES: usize = 11;
+const LOOKUPS: usize = 200_000;
+
+/// Returns the median time per lookup of `lookup` over `SAMPLES` runs of `LOOKUPS` lookups each
+fn measure<F>(targets: &[u64], mut lookup: F) -> Duration
+where
+    F: FnMut(&u64) -> Option<usize>,
+{
+    let mut samples: Vec<Duration> = (0..SAMPLES)
+        .map(|_| {
+            let start = Instant::now();
+
+            for target in targets.iter().cycle().take(LOOKUPS) {
+                black_box(lookup(black_box(target)));
+            }
+
+            start.elapsed() / LOOKUPS as u32
+        })
+        .collect();
+
+    samples.sort_unstable();
+    samples[SAMPLES / 2]
+}
+
+fn main() {
+    let arr: Vec<u64> = (0..LEN).map(|element| element * 3).collect();
+    let sorted = SortedSlice::new(&arr).unwrap();
+    let layout = EytzingerLayout::from_sorted(&arr);
+    // Scattered targets, so that consecutive lookups do not share cache lines
+    let targets: Vec<u64> = (0..LOOKUPS as u64)
+        .map(|index| (index.wrapping_mul(0x9e37_79b9_7f4a_7c15) >> 20) % (LEN * 3))
+        .collect();
+
+    let binary = measure(&targets, |target| sorted.search(target));
+    let eytzinger = measure(&targets, |target| layout.search(target));
+
+    println!(
+        "len {}: SortedSlice::search {:>6.1?}, EytzingerLayout::search {:>6.1?}, speedup {:.2}x",
+        LEN,
+        binary,
+        eytzinger,
+        binary.as_secs_f64() / eytzinger.as_secs_f64()
+    );
+}
@@ -0,0 +1,55 @@
+//! Measures hot lookups through `SortedSlice`, which runs the search kernels without the per-call sortedness check
+//!
+//! Run with `cargo bench --bench lookup`, and with `cargo bench --bench lookup --features checked-indexing` for the
+//! bounds-checked kernels. `slice::binary_search` is reported as a baseline.
+
+use binary_search::SortedSlice;
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+const SAMPLES: usize = 21;
+const LOOKUPS: usize = 10_000;
+
+/// Returns the median time per lookup of `lookup` over `SAMPLES` runs of `LOOKUPS` lookups each
+fn measure<F>(targets: &[String], mut lookup: F) -> Duration
+where
+    F: FnMut(&String) -> Option<usize>,
+{
+    let mut samples: Vec<Duration> = (0..SAMPLES)
+        .map(|_| {
+            let start = Instant::now();
+
+            for target in targets.iter().cycle().take(LOOKUPS) {
+                black_box(lookup(black_box(target)));
+            }
+
+            start.elapsed() / LOOKUPS as u32
+        })
+        .collect();
+
+    samples.sort_unstable();
+    samples[SAMPLES / 2]
+}
+
+fn main() {
+    for &len in &[1_000, 100_000, 1_000_000] {
+        // Strings, so that comparisons are not trivially inlined integer compares
+        let arr: Vec<String> = (0..len)
+            .map(|element| format!("{:08}", element * 2))
+            .collect();
+        let targets: Vec<String> = (0..1024)
+            .map(|index| format!("{:08}", (index * 7919) % (len * 2)))
+            .collect();
+        let sorted = SortedSlice::new(&arr).unwrap();
+
+        let kernel = measure(&targets, |target| sorted.search(target));
+        let baseline = measure(&targets, |target| arr.binary_search(target).ok());
+
+        println!(
+            "len {:>9}: SortedSlice::search {:>6.1?}, slice::binary_search {:>6.1?}",
+            len, kernel, baseline
+        );
+    }
+}
@@ -0,0 +1,52 @@
+//! Measures lookups into tiny sorted runs, comparing `binary_search_hybrid` with plain `binary_search`
+//!
+//! Run with `cargo bench --bench small`.
+
+use binary_search::variations::{self, DEFAULT_HYBRID_THRESHOLD};
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+const SAMPLES: usize = 21;
+const LOOKUPS: usize = 100_000;
+
+/// Returns the median time per lookup of `lookup` over `SAMPLES` runs of `LOOKUPS` lookups each
+fn measure<F>(targets: &[u32], mut lookup: F) -> Duration
+where
+    F: FnMut(&u32) -> Option<usize>,
+{
+    let mut samples: Vec<Duration> = (0..SAMPLES)
+        .map(|_| {
+            let start = Instant::now();
+
+            for target in targets.iter().cycle().take(LOOKUPS) {
+                black_box(lookup(black_box(target)));
+            }
+
+            start.elapsed() / LOOKUPS as u32
+        })
+        .collect();
+
+    samples.sort_unstable();
+    samples[SAMPLES / 2]
+}
+
+fn main() {
+    for &len in &[4u32, 8, 16, 32, 64] {
+        let arr: Vec<u32> = (0..len).map(|element| element * 2).collect();
+        let targets: Vec<u32> = (0..1024).map(|index| (index * 7919) % (len * 2)).collect();
+
+        let binary = measure(&targets, |target| {
+            binary_search::binary_search(target, &arr)
+        });
+        let hybrid = measure(&targets, |target| {
+            variations::binary_search_hybrid(target, &arr, DEFAULT_HYBRID_THRESHOLD)
+        });
+
+        println!(
+            "len {:>3}: binary_search {:>6.1?}, binary_search_hybrid {:>6.1?}",
+            len, binary, hybrid
+        );
+    }
+}
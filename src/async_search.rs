@@ -0,0 +1,299 @@
+//! Searches over containers whose elements are read asynchronously, such as blocks of remote storage
+//!
+//! Implement [`AsyncRandomAccess`] for a source to search it with [`search_async`] and the rank functions in this
+//! module. They mirror the functions of [`random_access`](crate::random_access), awaiting every probe and returning
+//! the first error of the source. No executor is required: the searches are plain futures.
+//!
+//! Unlike the synchronous searches, the source is not checked to be sorted, as that would read every element.
+
+use ::core::{cmp::Ordering, future::Future};
+
+/// A container whose elements can be read by index, asynchronously
+pub trait AsyncRandomAccess<K> {
+    /// The error returned by failed reads
+    type Error;
+
+    /// Returns the number of elements.
+    fn len(&self) -> u64;
+
+    /// Reads the element at `index`.
+    ///
+    /// The searches only call this with indices smaller than [`len`](Self::len), and await each read before
+    /// starting the next one.
+    fn get(&self, index: u64) -> impl Future<Output = Result<K, Self::Error>>;
+
+    /// Returns `true` if there are no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) on `src` in order to find the index
+/// of `target`, like [`search_in`](crate::random_access::search_in).
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::async_search::{self, AsyncRandomAccess};
+/// # use std::{future::Future, pin::pin, task::{Context, Poll, Waker}};
+/// #
+/// # fn block_on<F: Future>(future: F) -> F::Output {
+/// #     let mut future = pin!(future);
+/// #     let mut context = Context::from_waker(Waker::noop());
+/// #
+/// #     loop {
+/// #         if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+///
+/// struct Blocks(Vec<u32>);
+///
+/// impl AsyncRandomAccess<u32> for Blocks {
+///     type Error = String;
+///
+///     fn len(&self) -> u64 {
+///         self.0.len() as u64
+///     }
+///
+///     async fn get(&self, index: u64) -> Result<u32, String> {
+///         // A network read would be awaited here
+///         Ok(self.0[index as usize])
+///     }
+/// }
+///
+/// let blocks = Blocks(vec![1, 3, 5, 7, 9]);
+///
+/// assert_eq!(block_on(async_search::search_async(&7, &blocks)), Ok(Some(3)));
+/// assert_eq!(block_on(async_search::search_async(&4, &blocks)), Ok(None));
+/// ```
+///
+/// # Errors
+///
+/// The function returns the first error of `src`, without reading further.
+pub async fn search_async<K, S>(target: &K, src: &S) -> Result<Option<u64>, S::Error>
+where
+    K: Ord,
+    S: AsyncRandomAccess<K> + ?Sized,
+{
+    let mut left = 0;
+    let mut right = src.len();
+
+    while left < right {
+        let middle = left + (right - left) / 2;
+
+        match src.get(middle).await?.cmp(target) {
+            Ordering::Equal => return Ok(Some(middle)),
+            Ordering::Greater => right = middle,
+            Ordering::Less => left = middle + 1,
+        }
+    }
+
+    Ok(None)
+}
+
+/// Calculates the leftmost rank of `target` in `src`, i.e. the number of elements smaller than it, like
+/// [`leftmost_rank_in`](crate::random_access::leftmost_rank_in).
+///
+/// # Errors
+///
+/// The function returns the first error of `src`, without reading further.
+pub async fn leftmost_rank_async<K, S>(target: &K, src: &S) -> Result<u64, S::Error>
+where
+    K: Ord,
+    S: AsyncRandomAccess<K> + ?Sized,
+{
+    partition_point(src, |element| element < target).await
+}
+
+/// Calculates the rightmost rank of `target` in `src`, like
+/// [`rightmost_rank_in`](crate::random_access::rightmost_rank_in).
+///
+/// Returns 0 if no element is smaller than or equal to `target`.
+///
+/// # Errors
+///
+/// The function returns the first error of `src`, without reading further.
+pub async fn rightmost_rank_async<K, S>(target: &K, src: &S) -> Result<u64, S::Error>
+where
+    K: Ord,
+    S: AsyncRandomAccess<K> + ?Sized,
+{
+    let rank = partition_point(src, |element| element <= target).await?;
+
+    Ok(rank.saturating_sub(1))
+}
+
+/// Returns the index of the first element of `src` for which `pred` is false
+async fn partition_point<K, S, P>(src: &S, mut pred: P) -> Result<u64, S::Error>
+where
+    S: AsyncRandomAccess<K> + ?Sized,
+    P: FnMut(&K) -> bool,
+{
+    let mut left = 0;
+    let mut right = src.len();
+
+    while left < right {
+        let middle = left + (right - left) / 2;
+
+        if pred(&src.get(middle).await?) {
+            left = middle + 1;
+        } else {
+            right = middle;
+        }
+    }
+
+    Ok(left)
+}
+
+#[cfg(test)]
+mod async_search_tests {
+    use super::{leftmost_rank_async, rightmost_rank_async, search_async, AsyncRandomAccess};
+    use crate::{binary_search, leftmost_rank, rightmost_rank};
+    use std::{
+        cell::RefCell,
+        future::Future,
+        pin::{pin, Pin},
+        task::{Context, Poll, Waker},
+    };
+
+    /// Polls `future` to completion on the current thread
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut context = Context::from_waker(Waker::noop());
+
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+
+    /// Future that is pending once before completing, like a read that has to wait for the storage
+    struct YieldOnce(bool);
+
+    impl Future for YieldOnce {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                Poll::Ready(())
+            } else {
+                self.0 = true;
+                context.waker().wake_by_ref();
+
+                Poll::Pending
+            }
+        }
+    }
+
+    /// Source recording the order of its probes, and failing the probe numbered `fail_at`
+    struct Mock {
+        elements: Vec<i32>,
+        probes: RefCell<Vec<u64>>,
+        fail_at: Option<usize>,
+    }
+
+    impl Mock {
+        fn new(elements: &[i32]) -> Self {
+            Self {
+                elements: elements.to_vec(),
+                probes: RefCell::new(Vec::new()),
+                fail_at: None,
+            }
+        }
+    }
+
+    impl AsyncRandomAccess<i32> for Mock {
+        type Error = String;
+
+        fn len(&self) -> u64 {
+            self.elements.len() as u64
+        }
+
+        async fn get(&self, index: u64) -> Result<i32, String> {
+            YieldOnce(false).await;
+
+            let probe = {
+                let mut probes = self.probes.borrow_mut();
+
+                probes.push(index);
+                probes.len() - 1
+            };
+
+            if self.fail_at == Some(probe) {
+                Err(format!("read of block {} failed", index))
+            } else {
+                Ok(self.elements[index as usize])
+            }
+        }
+    }
+
+    #[test]
+    fn searches_match_slice_functions() {
+        let arr = [1, 2, 4, 4, 4, 5, 6, 7];
+        let mock = Mock::new(&arr);
+
+        for target in 0..9 {
+            assert_eq!(
+                block_on(search_async(&target, &mock)),
+                Ok(binary_search(&target, &arr).map(|index| index as u64))
+            );
+            assert_eq!(
+                block_on(leftmost_rank_async(&target, &mock)),
+                Ok(leftmost_rank(&target, &arr) as u64)
+            );
+            assert_eq!(
+                block_on(rightmost_rank_async(&target, &mock)),
+                Ok(rightmost_rank(&target, &arr) as u64)
+            );
+        }
+    }
+
+    #[test]
+    fn search_async_returns_none_for_empty_source() {
+        let mock = Mock::new(&[]);
+
+        assert!(mock.is_empty());
+        assert_eq!(block_on(search_async(&5, &mock)), Ok(None));
+        assert_eq!(block_on(leftmost_rank_async(&5, &mock)), Ok(0));
+        assert_eq!(block_on(rightmost_rank_async(&5, &mock)), Ok(0));
+        assert!(mock.probes.borrow().is_empty());
+    }
+
+    #[test]
+    fn search_async_probes_like_binary_search() {
+        let mock = Mock::new(&[1, 3, 5, 7, 9, 11, 13, 15, 17, 19]);
+
+        assert_eq!(block_on(search_async(&7, &mock)), Ok(Some(3)));
+        assert_eq!(*mock.probes.borrow(), vec![5, 2, 4, 3]);
+    }
+
+    #[test]
+    fn searches_stop_at_the_first_error() {
+        let mut mock = Mock::new(&[1, 3, 5, 7, 9, 11, 13, 15, 17, 19]);
+
+        mock.fail_at = Some(1);
+
+        assert_eq!(
+            block_on(search_async(&7, &mock)),
+            Err(String::from("read of block 2 failed"))
+        );
+        assert_eq!(*mock.probes.borrow(), vec![5, 2]);
+
+        mock.probes.borrow_mut().clear();
+
+        assert_eq!(
+            block_on(leftmost_rank_async(&19, &mock)),
+            Err(String::from("read of block 8 failed"))
+        );
+        assert_eq!(*mock.probes.borrow(), vec![5, 8]);
+
+        mock.probes.borrow_mut().clear();
+        mock.fail_at = Some(0);
+
+        assert!(block_on(rightmost_rank_async(&1, &mock)).is_err());
+        assert_eq!(mock.probes.borrow().len(), 1);
+    }
+}
@@ -0,0 +1,436 @@
+//! Searches for many targets at once, sharing work between them
+
+use crate::{core, utils};
+
+/// Calculates, for every element of `a`, whether it has a match in `b`, with multiset semantics: the k-th copy of an
+/// element in `a` only has a match if `b` holds at least k copies of it.
+///
+/// Both arrays are walked once together. Each search gallops forwards from the previous match, so the walk costs
+/// O(|a| log(|b| / |a|)) comparisons when `a` is much shorter than `b`, and degrades gracefully into a linear merge
+/// when both have similar lengths.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::batch;
+///
+/// let a = [1, 3, 3, 3, 8];
+/// let b = [2, 3, 3, 5, 8];
+/// let flags = batch::presence_flags(&a, &b);
+///
+/// assert_eq!(flags, vec![false, true, true, false, true]);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if either array is not sorted.
+pub fn presence_flags<T>(a: &[T], b: &[T]) -> Vec<bool>
+where
+    T: Ord,
+{
+    presence_matches(a, b)
+        .into_iter()
+        .map(|matched| matched.is_some())
+        .collect()
+}
+
+/// Calculates, for every element of `a`, the index in `b` of its match, with the same multiset semantics as
+/// [`presence_flags`]. The k-th copy of an element in `a` is matched with its k-th copy in `b`, i.e. the leftmost
+/// occurrence not matched yet.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::batch;
+///
+/// let a = [1, 3, 3, 3, 8];
+/// let b = [2, 3, 3, 5, 8];
+/// let matches = batch::presence_matches(&a, &b);
+///
+/// assert_eq!(matches, vec![None, Some(1), Some(2), None, Some(4)]);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if either array is not sorted.
+pub fn presence_matches<T>(a: &[T], b: &[T]) -> Vec<Option<usize>>
+where
+    T: Ord,
+{
+    if !utils::is_sorted(a) || !utils::is_sorted(b) {
+        panic!("Batch search encountered an array that is note sorted");
+    }
+
+    let mut matches = Vec::with_capacity(a.len());
+    // Every element of b before `next` is either matched or smaller than the current element of a
+    let mut next = 0;
+
+    for element in a {
+        next += gallop_lower_bound(element, &b[next..]);
+
+        if next < b.len() && b[next] == *element {
+            matches.push(Some(next));
+            next += 1;
+        } else {
+            matches.push(None);
+        }
+    }
+
+    matches
+}
+
+/// Performs a search for every element of `targets` in `arr`, returning the index of the leftmost match of each.
+///
+/// When `targets` is sorted, each search gallops forwards from the rank of the previous target, for a total of
+/// O(q log(n / q) + q) comparisons for q targets. Otherwise every target is searched independently in O(log n).
+/// Either way `arr` is validated only once.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::batch;
+///
+/// let targets = [2, 5, 5, 9];
+/// let arr = [1, 2, 3, 5, 8, 9];
+/// let found = batch::search_many(&targets, &arr);
+///
+/// assert_eq!(found, vec![Some(1), Some(3), Some(3), Some(5)]);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if `arr` is not sorted.
+pub fn search_many<T>(targets: &[T], arr: &[T]) -> Vec<Option<usize>>
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Batch search encountered an array that is note sorted");
+    }
+
+    if !utils::is_sorted(targets) {
+        return targets
+            .iter()
+            .map(|target| found_at(core::lower_bound(target, arr), target, arr))
+            .collect();
+    }
+
+    search_sorted_targets(targets, arr)
+}
+
+/// Performs a search for every element of the sorted `targets` in `arr`, returning the index of the leftmost match of
+/// each.
+///
+/// The lower bound of the search only moves forwards: each target gallops from the rank of the previous one, so
+/// the total work is O(q log(n / q) + q) comparisons for q targets, never more than O(n + q log n), instead of
+/// O(q log n) with every search starting over. Unlike [`search_many`], unsorted targets are rejected rather than
+/// searched independently.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::batch;
+///
+/// let targets = [1, 3, 4, 9];
+/// let arr = [1, 2, 3, 5, 8, 9];
+/// let found = batch::batch_search(&targets, &arr);
+///
+/// assert_eq!(found, vec![Some(0), Some(2), None, Some(5)]);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if `targets` or `arr` is not sorted.
+pub fn batch_search<T>(targets: &[T], arr: &[T]) -> Vec<Option<usize>>
+where
+    T: Ord,
+{
+    if !utils::is_sorted(targets) || !utils::is_sorted(arr) {
+        panic!("Batch search encountered an array that is note sorted");
+    }
+
+    search_sorted_targets(targets, arr)
+}
+
+/// Searches every element of the sorted `targets` in `arr`, galloping forwards from the rank of the previous target
+fn search_sorted_targets<T>(targets: &[T], arr: &[T]) -> Vec<Option<usize>>
+where
+    T: Ord,
+{
+    // Every element of arr before `rank` is smaller than the current target
+    let mut rank = 0;
+
+    targets
+        .iter()
+        .map(|target| {
+            rank += gallop_lower_bound(target, &arr[rank..]);
+
+            found_at(rank, target, arr)
+        })
+        .collect()
+}
+
+/// Returns `rank` if the element of `arr` at it is `target`
+fn found_at<T>(rank: usize, target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    if rank < arr.len() && arr[rank] == *target {
+        Some(rank)
+    } else {
+        None
+    }
+}
+
+/// Calculates the leftmost rank of `target`, galloping from the start of `arr` so that the cost is logarithmic in
+/// the rank rather than in the length
+fn gallop_lower_bound<T>(target: &T, arr: &[T]) -> usize
+where
+    T: Ord,
+{
+    let mut bound = 1;
+
+    while bound < arr.len() && arr[bound - 1] < *target {
+        bound = bound.saturating_mul(2);
+    }
+
+    let start = bound / 2;
+    let end = bound.min(arr.len());
+
+    start + core::lower_bound(target, &arr[start..end])
+}
+
+#[cfg(test)]
+mod batch_tests {
+    use super::{
+        batch_search, gallop_lower_bound, presence_flags, presence_matches, search_many,
+        search_sorted_targets,
+    };
+    use crate::{binary_search, binary_search_first, core};
+    use std::collections::BTreeMap;
+
+    /// Computes the flags by counting the copies of each element of `b`
+    fn counting_flags(a: &[i32], b: &[i32]) -> Vec<bool> {
+        let mut counts = BTreeMap::new();
+
+        for element in b {
+            *counts.entry(element).or_insert(0) += 1;
+        }
+
+        a.iter()
+            .map(|element| match counts.get_mut(element) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+
+                    true
+                }
+                _ => false,
+            })
+            .collect()
+    }
+
+    #[test]
+    #[should_panic(expected = "Batch search encountered an array that is note sorted")]
+    fn presence_flags_panics_when_arr_is_not_sorted() {
+        let a = [1, 2, 3];
+        let b = [1, 3, 2];
+
+        presence_flags(&a, &b);
+    }
+
+    #[test]
+    fn presence_flags_handles_empty_arrs() {
+        let a = [1, 2];
+        let b = [];
+
+        assert_eq!(presence_flags(&a, &b), vec![false, false]);
+        assert!(presence_flags(&b, &a).is_empty());
+    }
+
+    #[test]
+    fn presence_flags_limits_matches_to_copies_in_b() {
+        let a = [2, 2, 2, 5];
+        let b = [2, 5];
+
+        assert_eq!(presence_flags(&a, &b), counting_flags(&a, &b));
+        assert_eq!(presence_matches(&a, &b), vec![Some(0), None, None, Some(1)]);
+    }
+
+    #[test]
+    fn presence_flags_ignores_extra_copies_in_b() {
+        let a = [2, 5];
+        let b = [2, 2, 2, 5, 5];
+
+        assert_eq!(presence_flags(&a, &b), counting_flags(&a, &b));
+        assert_eq!(presence_matches(&a, &b), vec![Some(0), Some(3)]);
+    }
+
+    #[test]
+    fn presence_flags_returns_all_false_for_disjoint_arrs() {
+        let a = [1, 3, 5, 7];
+        let b = [2, 4, 6, 8];
+
+        assert_eq!(presence_flags(&a, &b), vec![false; 4]);
+    }
+
+    #[test]
+    fn presence_matches_returns_every_index_for_identical_arrs() {
+        let a = [1, 1, 2, 3, 3, 3];
+        let matches = presence_matches(&a, &a);
+
+        assert_eq!(matches, (0..6).map(Some).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn presence_flags_matches_counting_reference() {
+        let a: Vec<i32> = (0..300).map(|value| value / 3 * 2).collect();
+        let mut b: Vec<i32> = (0..500).map(|value| value * value % 97).collect();
+
+        b.sort();
+
+        assert_eq!(presence_flags(&a, &b), counting_flags(&a, &b));
+    }
+
+    #[test]
+    fn gallop_lower_bound_matches_lower_bound() {
+        let arr = [1, 2, 2, 4, 4, 4, 4, 7, 9, 9, 12];
+
+        for target in 0..14 {
+            assert_eq!(
+                gallop_lower_bound(&target, &arr),
+                core::lower_bound(&target, &arr)
+            );
+        }
+    }
+
+    #[test]
+    fn gallop_does_not_overflow_the_bound_on_maximal_lengths() {
+        use std::{cmp::Ordering, ptr::NonNull, slice};
+
+        /// Zero-sized element ordered below every target, so that the gallop runs until the bound passes the length
+        #[derive(Debug, PartialEq, Eq)]
+        struct Below;
+
+        impl PartialOrd for Below {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Below {
+            fn cmp(&self, _: &Self) -> Ordering {
+                Ordering::Less
+            }
+        }
+
+        // SAFETY: a slice of zero-sized elements may have any length, and a dangling pointer is valid for it
+        let arr: &[Below] =
+            unsafe { slice::from_raw_parts(NonNull::dangling().as_ptr(), usize::MAX) };
+
+        assert_eq!(gallop_lower_bound(&Below, arr), usize::MAX);
+        // Validating such an array takes usize::MAX comparisons, so this goes through the gallop shared by
+        // search_many and batch_search without it
+        assert_eq!(
+            search_sorted_targets(&[Below, Below], arr),
+            vec![None, None]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Batch search encountered an array that is note sorted")]
+    fn search_many_panics_when_arr_is_not_sorted() {
+        let targets = [1];
+        let arr = [1, 3, 2];
+
+        search_many(&targets, &arr);
+    }
+
+    #[test]
+    fn search_many_returns_leftmost_matches_for_sorted_targets() {
+        let targets = [0, 2, 4, 4, 6, 11];
+        let arr = [1, 2, 2, 4, 4, 4, 7, 10];
+        let found = search_many(&targets, &arr);
+
+        assert_eq!(found, vec![None, Some(1), Some(3), Some(3), None, None]);
+    }
+
+    #[test]
+    fn search_many_falls_back_for_unsorted_targets() {
+        let targets = [7, 2, 10, 3, 1];
+        let arr = [1, 2, 2, 4, 4, 4, 7, 10];
+        let found = search_many(&targets, &arr);
+
+        assert_eq!(found, vec![Some(6), Some(1), Some(7), None, Some(0)]);
+    }
+
+    #[test]
+    fn search_many_handles_empty_inputs() {
+        let arr = [1, 2, 3];
+
+        assert!(search_many(&[], &arr).is_empty());
+        assert_eq!(search_many(&[1, 2], &[]), vec![None, None]);
+    }
+
+    #[test]
+    fn search_many_matches_independent_searches() {
+        let arr: Vec<i32> = (0..1_000).map(|value| value / 2 * 3).collect();
+        let targets: Vec<i32> = (0..400).map(|value| value * 4).collect();
+        let found = search_many(&targets, &arr);
+
+        for (target, found) in targets.iter().zip(found) {
+            let rank = core::lower_bound(target, &arr);
+            let expected = if arr.get(rank) == Some(target) {
+                Some(rank)
+            } else {
+                None
+            };
+
+            assert_eq!(found, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Batch search encountered an array that is note sorted")]
+    fn batch_search_panics_when_targets_are_not_sorted() {
+        batch_search(&[3, 1], &[1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Batch search encountered an array that is note sorted")]
+    fn batch_search_panics_when_arr_is_not_sorted() {
+        batch_search(&[1, 3], &[1, 3, 2]);
+    }
+
+    #[test]
+    fn batch_search_handles_empty_inputs() {
+        assert!(batch_search(&[], &[1, 2]).is_empty());
+        assert_eq!(batch_search(&[1, 2], &[]), vec![None, None]);
+    }
+
+    #[test]
+    fn batch_search_matches_binary_search_per_target() {
+        let arr: Vec<i32> = (0..500).map(|element| element * 3).collect();
+
+        for step in 1..20 {
+            let targets: Vec<i32> = (-5..1510).step_by(step).collect();
+            let found = batch_search(&targets, &arr);
+
+            assert_eq!(found.len(), targets.len());
+
+            for (target, found) in targets.iter().zip(found) {
+                assert_eq!(found, binary_search(target, &arr), "searching {}", target);
+            }
+        }
+    }
+
+    #[test]
+    fn batch_search_returns_leftmost_matches_with_duplicates() {
+        let targets = [0, 1, 1, 2, 4, 4, 7];
+        let arr = [1, 1, 1, 2, 2, 4, 6, 6, 7];
+        let found = batch_search(&targets, &arr);
+
+        for (target, found) in targets.iter().zip(found) {
+            assert_eq!(found, binary_search_first(target, &arr));
+        }
+    }
+}
@@ -0,0 +1,667 @@
+//! Searches over integer keys packed in byte buffers, such as memory-mapped files, without copying them out
+//!
+//! Each function reads the buffer as consecutive keys of a fixed width and byte order, decoding every probed key
+//! from a copy of its bytes. The buffer therefore doesn't need to be aligned, and returned indices count keys rather
+//! than bytes. A buffer whose length is not a multiple of the key width is rejected with a [`KeyWidthError`].
+//!
+//! Every function checks that the keys are sorted, which decodes the whole buffer. For a memory-mapped file searched
+//! many times, check the order once and then use the `*_unchecked` variants, which only decode the probed keys.
+
+use ::core::fmt;
+
+use crate::utils;
+
+/// Error returned when the length of a buffer is not a multiple of the width of its keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyWidthError {
+    /// The length of the buffer, in bytes
+    buf_len: usize,
+    /// The width of a key, in bytes
+    width: usize,
+}
+
+impl KeyWidthError {
+    /// Returns the length of the buffer, in bytes
+    pub fn buf_len(&self) -> usize {
+        self.buf_len
+    }
+
+    /// Returns the width of a key, in bytes
+    pub fn width(&self) -> usize {
+        self.width
+    }
+}
+
+impl fmt::Display for KeyWidthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "buffer of {} bytes is not a multiple of the key width {}",
+            self.buf_len, self.width
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KeyWidthError {}
+
+macro_rules! bytes_search {
+    (
+        $int:ident,
+        $order:literal,
+        $to_bytes:ident,
+        $from_bytes:ident,
+        $search:ident,
+        $leftmost_rank:ident,
+        $rightmost_rank:ident,
+        $search_unchecked:ident,
+        $leftmost_rank_unchecked:ident,
+        $rightmost_rank_unchecked:ident
+    ) => {
+        #[doc = concat!(
+            "Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) on `buf`, read as ",
+            $order,
+            "-endian `",
+            stringify!($int),
+            "` keys, in order to find the index of `target`."
+        )]
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use binary_search::bytes;
+        ///
+        #[doc = concat!(
+            "let buf: Vec<u8> = [1", stringify!($int), ", 3, 5, 7].iter().flat_map(|key| key.",
+            stringify!($to_bytes), "()).collect();"
+        )]
+        ///
+        #[doc = concat!("assert_eq!(bytes::", stringify!($search), "(5, &buf), Ok(Some(2)));")]
+        #[doc = concat!("assert_eq!(bytes::", stringify!($search), "(4, &buf), Ok(None));")]
+        #[doc = concat!("assert!(bytes::", stringify!($search), "(5, &buf[1..]).is_err());")]
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// The function returns a [`KeyWidthError`] if the length of `buf` is not a multiple of the key width.
+        ///
+        /// # Panics
+        ///
+        /// The function panics if the keys are not sorted.
+        pub fn $search(target: $int, buf: &[u8]) -> Result<Option<usize>, KeyWidthError> {
+            search(target, buf, $int::$from_bytes)
+        }
+
+        #[doc = concat!(
+            "Calculates the leftmost rank of `target` in `buf`, read as ",
+            $order,
+            "-endian `",
+            stringify!($int),
+            "` keys, i.e. the number of keys smaller than it."
+        )]
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use binary_search::bytes;
+        ///
+        #[doc = concat!(
+            "let buf: Vec<u8> = [1", stringify!($int), ", 4, 4, 4, 7].iter().flat_map(|key| key.",
+            stringify!($to_bytes), "()).collect();"
+        )]
+        ///
+        #[doc = concat!("assert_eq!(bytes::", stringify!($leftmost_rank), "(4, &buf), Ok(1));")]
+        #[doc = concat!("assert_eq!(bytes::", stringify!($leftmost_rank), "(8, &buf), Ok(5));")]
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// The function returns a [`KeyWidthError`] if the length of `buf` is not a multiple of the key width.
+        ///
+        /// # Panics
+        ///
+        /// The function panics if the keys are not sorted.
+        pub fn $leftmost_rank(target: $int, buf: &[u8]) -> Result<usize, KeyWidthError> {
+            leftmost_rank(target, buf, $int::$from_bytes)
+        }
+
+        #[doc = concat!(
+            "Calculates the rightmost rank of `target` in `buf`, read as ",
+            $order,
+            "-endian `",
+            stringify!($int),
+            "` keys, like [`rightmost_rank`](crate::rightmost_rank) on a slice."
+        )]
+        ///
+        /// Returns 0 if no key is smaller than or equal to `target`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use binary_search::bytes;
+        ///
+        #[doc = concat!(
+            "let buf: Vec<u8> = [1", stringify!($int), ", 4, 4, 4, 7].iter().flat_map(|key| key.",
+            stringify!($to_bytes), "()).collect();"
+        )]
+        ///
+        #[doc = concat!("assert_eq!(bytes::", stringify!($rightmost_rank), "(4, &buf), Ok(3));")]
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// The function returns a [`KeyWidthError`] if the length of `buf` is not a multiple of the key width.
+        ///
+        /// # Panics
+        ///
+        /// The function panics if the keys are not sorted.
+        pub fn $rightmost_rank(target: $int, buf: &[u8]) -> Result<usize, KeyWidthError> {
+            rightmost_rank(target, buf, $int::$from_bytes)
+        }
+
+        #[doc = concat!(
+            "Performs binary search on `buf`, read as ", $order, "-endian `", stringify!($int), "` keys, like [`",
+            stringify!($search), "`], without checking that the keys are sorted."
+        )]
+        ///
+        #[doc = concat!(
+            "This skips the `O(n)` scan of [`", stringify!($search), "`], so a search only decodes the keys it ",
+            "probes. Passing unsorted keys is not memory unsafety, it only gives a meaningless result. Every probe is ",
+            "a key of `buf`, so the search never panics."
+        )]
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use binary_search::bytes;
+        ///
+        #[doc = concat!(
+            "let buf: Vec<u8> = [1", stringify!($int), ", 3, 5, 7].iter().flat_map(|key| key.",
+            stringify!($to_bytes), "()).collect();"
+        )]
+        ///
+        #[doc = concat!("assert_eq!(bytes::", stringify!($search_unchecked), "(5, &buf), Ok(Some(2)));")]
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// The function returns a [`KeyWidthError`] if the length of `buf` is not a multiple of the key width.
+        pub fn $search_unchecked(target: $int, buf: &[u8]) -> Result<Option<usize>, KeyWidthError> {
+            search_unchecked(target, buf, $int::$from_bytes)
+        }
+
+        #[doc = concat!(
+            "Calculates the leftmost rank of `target` in `buf`, read as ", $order, "-endian `", stringify!($int),
+            "` keys, like [`", stringify!($leftmost_rank), "`], without checking that the keys are sorted."
+        )]
+        ///
+        #[doc = concat!(
+            "This skips the `O(n)` scan of [`", stringify!($leftmost_rank), "`]. Passing unsorted keys is not memory ",
+            "unsafety, it only gives a meaningless result, and the search never panics."
+        )]
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use binary_search::bytes;
+        ///
+        #[doc = concat!(
+            "let buf: Vec<u8> = [1", stringify!($int), ", 4, 4, 4, 7].iter().flat_map(|key| key.",
+            stringify!($to_bytes), "()).collect();"
+        )]
+        ///
+        #[doc = concat!("assert_eq!(bytes::", stringify!($leftmost_rank_unchecked), "(4, &buf), Ok(1));")]
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// The function returns a [`KeyWidthError`] if the length of `buf` is not a multiple of the key width.
+        pub fn $leftmost_rank_unchecked(target: $int, buf: &[u8]) -> Result<usize, KeyWidthError> {
+            leftmost_rank_unchecked(target, buf, $int::$from_bytes)
+        }
+
+        #[doc = concat!(
+            "Calculates the rightmost rank of `target` in `buf`, read as ", $order, "-endian `", stringify!($int),
+            "` keys, like [`", stringify!($rightmost_rank), "`], without checking that the keys are sorted."
+        )]
+        ///
+        #[doc = concat!(
+            "This skips the `O(n)` scan of [`", stringify!($rightmost_rank), "`]. Passing unsorted keys is not memory ",
+            "unsafety, it only gives a meaningless result, and the search never panics."
+        )]
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use binary_search::bytes;
+        ///
+        #[doc = concat!(
+            "let buf: Vec<u8> = [1", stringify!($int), ", 4, 4, 4, 7].iter().flat_map(|key| key.",
+            stringify!($to_bytes), "()).collect();"
+        )]
+        ///
+        #[doc = concat!("assert_eq!(bytes::", stringify!($rightmost_rank_unchecked), "(4, &buf), Ok(3));")]
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// The function returns a [`KeyWidthError`] if the length of `buf` is not a multiple of the key width.
+        pub fn $rightmost_rank_unchecked(target: $int, buf: &[u8]) -> Result<usize, KeyWidthError> {
+            rightmost_rank_unchecked(target, buf, $int::$from_bytes)
+        }
+    };
+}
+
+bytes_search!(
+    u16,
+    "little",
+    to_le_bytes,
+    from_le_bytes,
+    search_u16_le,
+    leftmost_rank_u16_le,
+    rightmost_rank_u16_le,
+    search_u16_le_unchecked,
+    leftmost_rank_u16_le_unchecked,
+    rightmost_rank_u16_le_unchecked
+);
+bytes_search!(
+    u16,
+    "big",
+    to_be_bytes,
+    from_be_bytes,
+    search_u16_be,
+    leftmost_rank_u16_be,
+    rightmost_rank_u16_be,
+    search_u16_be_unchecked,
+    leftmost_rank_u16_be_unchecked,
+    rightmost_rank_u16_be_unchecked
+);
+bytes_search!(
+    u32,
+    "little",
+    to_le_bytes,
+    from_le_bytes,
+    search_u32_le,
+    leftmost_rank_u32_le,
+    rightmost_rank_u32_le,
+    search_u32_le_unchecked,
+    leftmost_rank_u32_le_unchecked,
+    rightmost_rank_u32_le_unchecked
+);
+bytes_search!(
+    u32,
+    "big",
+    to_be_bytes,
+    from_be_bytes,
+    search_u32_be,
+    leftmost_rank_u32_be,
+    rightmost_rank_u32_be,
+    search_u32_be_unchecked,
+    leftmost_rank_u32_be_unchecked,
+    rightmost_rank_u32_be_unchecked
+);
+bytes_search!(
+    u64,
+    "little",
+    to_le_bytes,
+    from_le_bytes,
+    search_u64_le,
+    leftmost_rank_u64_le,
+    rightmost_rank_u64_le,
+    search_u64_le_unchecked,
+    leftmost_rank_u64_le_unchecked,
+    rightmost_rank_u64_le_unchecked
+);
+bytes_search!(
+    u64,
+    "big",
+    to_be_bytes,
+    from_be_bytes,
+    search_u64_be,
+    leftmost_rank_u64_be,
+    rightmost_rank_u64_be,
+    search_u64_be_unchecked,
+    leftmost_rank_u64_be_unchecked,
+    rightmost_rank_u64_be_unchecked
+);
+bytes_search!(
+    i64,
+    "little",
+    to_le_bytes,
+    from_le_bytes,
+    search_i64_le,
+    leftmost_rank_i64_le,
+    rightmost_rank_i64_le,
+    search_i64_le_unchecked,
+    leftmost_rank_i64_le_unchecked,
+    rightmost_rank_i64_le_unchecked
+);
+bytes_search!(
+    i64,
+    "big",
+    to_be_bytes,
+    from_be_bytes,
+    search_i64_be,
+    leftmost_rank_i64_be,
+    rightmost_rank_i64_be,
+    search_i64_be_unchecked,
+    leftmost_rank_i64_be_unchecked,
+    rightmost_rank_i64_be_unchecked
+);
+
+/// Searches the keys of `buf` decoded by `from_bytes` for `target`
+fn search<K, const W: usize>(
+    target: K,
+    buf: &[u8],
+    from_bytes: fn([u8; W]) -> K,
+) -> Result<Option<usize>, KeyWidthError>
+where
+    K: Ord + Copy,
+{
+    check_sorted(buf, from_bytes)?;
+    search_unchecked(target, buf, from_bytes)
+}
+
+/// Searches the keys of `buf` decoded by `from_bytes` for `target`, without checking their order
+fn search_unchecked<K, const W: usize>(
+    target: K,
+    buf: &[u8],
+    from_bytes: fn([u8; W]) -> K,
+) -> Result<Option<usize>, KeyWidthError>
+where
+    K: Ord + Copy,
+{
+    let rank = leftmost_rank_unchecked(target, buf, from_bytes)?;
+
+    if rank < buf.len() / W && key(buf, rank, from_bytes) == target {
+        Ok(Some(rank))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Counts the keys of `buf` decoded by `from_bytes` that are smaller than `target`
+fn leftmost_rank<K, const W: usize>(
+    target: K,
+    buf: &[u8],
+    from_bytes: fn([u8; W]) -> K,
+) -> Result<usize, KeyWidthError>
+where
+    K: Ord,
+{
+    check_sorted(buf, from_bytes)?;
+    leftmost_rank_unchecked(target, buf, from_bytes)
+}
+
+/// Counts the keys of `buf` decoded by `from_bytes` that are smaller than `target`, without checking their order
+fn leftmost_rank_unchecked<K, const W: usize>(
+    target: K,
+    buf: &[u8],
+    from_bytes: fn([u8; W]) -> K,
+) -> Result<usize, KeyWidthError>
+where
+    K: Ord,
+{
+    partition_point(buf, from_bytes, |key| key < target)
+}
+
+/// Returns the index of the last key of `buf` decoded by `from_bytes` that is smaller than or equal to `target`, or 0
+fn rightmost_rank<K, const W: usize>(
+    target: K,
+    buf: &[u8],
+    from_bytes: fn([u8; W]) -> K,
+) -> Result<usize, KeyWidthError>
+where
+    K: Ord,
+{
+    check_sorted(buf, from_bytes)?;
+    rightmost_rank_unchecked(target, buf, from_bytes)
+}
+
+/// Returns the index of the last key of `buf` decoded by `from_bytes` that is smaller than or equal to `target`, or 0,
+/// without checking their order
+fn rightmost_rank_unchecked<K, const W: usize>(
+    target: K,
+    buf: &[u8],
+    from_bytes: fn([u8; W]) -> K,
+) -> Result<usize, KeyWidthError>
+where
+    K: Ord,
+{
+    partition_point(buf, from_bytes, |key| key <= target).map(|rank| rank.saturating_sub(1))
+}
+
+/// Checks that `buf` holds whole keys, and panics if the keys decoded by `from_bytes` are not sorted
+fn check_sorted<K, const W: usize>(
+    buf: &[u8],
+    from_bytes: fn([u8; W]) -> K,
+) -> Result<(), KeyWidthError>
+where
+    K: Ord,
+{
+    let len = key_count::<W>(buf)?;
+
+    if (1..len).any(|index| key(buf, index - 1, from_bytes) > key(buf, index, from_bytes)) {
+        panic!("Bytes search encountered an array that is note sorted");
+    }
+
+    Ok(())
+}
+
+/// Returns the index of the first key of `buf` decoded by `from_bytes` for which `pred` is false
+fn partition_point<K, P, const W: usize>(
+    buf: &[u8],
+    from_bytes: fn([u8; W]) -> K,
+    mut pred: P,
+) -> Result<usize, KeyWidthError>
+where
+    P: FnMut(K) -> bool,
+{
+    let mut left = 0;
+    let mut right = key_count::<W>(buf)?;
+
+    while left < right {
+        let middle = utils::midpoint(left, right);
+
+        if pred(key(buf, middle, from_bytes)) {
+            left = middle + 1;
+        } else {
+            right = middle;
+        }
+    }
+
+    Ok(left)
+}
+
+/// Returns the number of keys of width `W` that `buf` holds, if its length is a multiple of `W`
+fn key_count<const W: usize>(buf: &[u8]) -> Result<usize, KeyWidthError> {
+    if buf.len().is_multiple_of(W) {
+        Ok(buf.len() / W)
+    } else {
+        Err(KeyWidthError {
+            buf_len: buf.len(),
+            width: W,
+        })
+    }
+}
+
+/// Decodes the key at `index` from a copy of its bytes, so that `buf` doesn't need to be aligned
+fn key<K, const W: usize>(buf: &[u8], index: usize, from_bytes: fn([u8; W]) -> K) -> K {
+    let mut bytes = [0; W];
+
+    bytes.copy_from_slice(&buf[index * W..(index + 1) * W]);
+
+    from_bytes(bytes)
+}
+
+#[cfg(test)]
+mod bytes_tests {
+    #[cfg(feature = "std")]
+    use super::{
+        leftmost_rank_i64_be, leftmost_rank_u16_le, leftmost_rank_u32_be, rightmost_rank_i64_le,
+        rightmost_rank_u16_be, search_i64_be, search_i64_le, search_u32_be, search_u32_le,
+        search_u64_be, KeyWidthError,
+    };
+    use super::{
+        leftmost_rank_u32_le, leftmost_rank_u32_le_unchecked, leftmost_rank_u64_le,
+        rightmost_rank_u32_le, rightmost_rank_u32_le_unchecked, rightmost_rank_u64_le,
+        search_u16_be, search_u16_le, search_u32_le_unchecked, search_u64_le,
+        search_u64_le_unchecked,
+    };
+    #[cfg(feature = "std")]
+    use crate::{leftmost_rank, rightmost_rank};
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn search_rejects_buffer_with_partial_key() {
+        let buf = [0; 12];
+        let error = search_u64_le(0, &buf).unwrap_err();
+
+        assert_eq!(
+            error,
+            KeyWidthError {
+                buf_len: 12,
+                width: 8
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "buffer of 12 bytes is not a multiple of the key width 8"
+        );
+        assert!(leftmost_rank_u32_be(0, &buf[..3]).is_err());
+        assert!(rightmost_rank_u16_be(0, &buf[..5]).is_err());
+    }
+
+    #[test]
+    fn search_returns_none_for_empty_buffer() {
+        assert_eq!(search_u64_le(5, &[]), Ok(None));
+        assert_eq!(leftmost_rank_u64_le(5, &[]), Ok(0));
+        assert_eq!(rightmost_rank_u64_le(5, &[]), Ok(0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic(expected = "Bytes search encountered an array that is note sorted")]
+    fn search_panics_when_keys_are_not_sorted() {
+        let buf: Vec<u8> = [3u32, 1, 2]
+            .iter()
+            .flat_map(|key| key.to_le_bytes())
+            .collect();
+
+        let _ = search_u32_le(1, &buf);
+    }
+
+    #[test]
+    fn search_reads_keys_in_the_given_byte_order() {
+        // The same bytes hold 0x0001 then 0x0100 in little-endian order, but 0x0100 then 0x0001 in big-endian order
+        let le = [0x01, 0x00, 0x00, 0x01];
+        let be = [0x00, 0x01, 0x01, 0x00];
+
+        assert_eq!(search_u16_le(0x0001, &le), Ok(Some(0)));
+        assert_eq!(search_u16_le(0x0100, &le), Ok(Some(1)));
+        assert_eq!(search_u16_be(0x0001, &be), Ok(Some(0)));
+        assert_eq!(search_u16_be(0x0100, &be), Ok(Some(1)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn searches_match_slice_functions_on_unaligned_buffers() {
+        let keys: Vec<u64> = vec![1, 5, 5, 5, 9, 1 << 40, u64::MAX];
+        let mut storage = vec![0xaa];
+
+        storage.extend(keys.iter().flat_map(|key| key.to_le_bytes()));
+
+        // Every key of the buffer starts one byte past a multiple of 8
+        let buf = &storage[1..];
+
+        for &target in &[0, 1, 4, 5, 6, 9, 1 << 40, u64::MAX - 1, u64::MAX] {
+            assert_eq!(
+                search_u64_le(target, buf).unwrap().map(|index| keys[index]),
+                keys.binary_search(&target).ok().map(|_| target)
+            );
+            assert_eq!(
+                leftmost_rank_u64_le(target, buf),
+                Ok(leftmost_rank(&target, &keys))
+            );
+            assert_eq!(
+                rightmost_rank_u64_le(target, buf),
+                Ok(rightmost_rank(&target, &keys))
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn searches_handle_every_width_and_signed_keys() {
+        let signed: Vec<i64> = vec![i64::MIN, -40, -1, 0, 3, i64::MAX];
+        let le: Vec<u8> = signed.iter().flat_map(|key| key.to_le_bytes()).collect();
+        let be: Vec<u8> = signed.iter().flat_map(|key| key.to_be_bytes()).collect();
+        let small: Vec<u8> = [2u16, 4, 600]
+            .iter()
+            .flat_map(|key| key.to_le_bytes())
+            .collect();
+        let medium: Vec<u8> = [7u32, 70_000]
+            .iter()
+            .flat_map(|key| key.to_be_bytes())
+            .collect();
+
+        for (index, &key) in signed.iter().enumerate() {
+            assert_eq!(search_i64_le(key, &le), Ok(Some(index)));
+            assert_eq!(search_i64_be(key, &be), Ok(Some(index)));
+            assert_eq!(leftmost_rank_i64_be(key, &be), Ok(index));
+            assert_eq!(rightmost_rank_i64_le(key, &le), Ok(index));
+        }
+
+        assert_eq!(search_i64_le(-2, &le), Ok(None));
+        assert_eq!(search_u16_le(600, &small), Ok(Some(2)));
+        assert_eq!(leftmost_rank_u16_le(5, &small), Ok(2));
+        assert_eq!(search_u32_be(70_000, &medium), Ok(Some(1)));
+        assert_eq!(search_u32_le(7, &medium), Ok(None));
+        assert_eq!(
+            search_u64_be(1 << 40, &(1u64 << 40).to_be_bytes()),
+            Ok(Some(0))
+        );
+    }
+
+    #[test]
+    fn unchecked_searches_match_checked_searches_on_sorted_keys() {
+        let keys = [1u32, 4, 4, 4, 7, 70_000];
+        let mut buf = [0; 24];
+
+        for (bytes, key) in buf.chunks_exact_mut(4).zip(&keys) {
+            bytes.copy_from_slice(&key.to_le_bytes());
+        }
+
+        for target in 0..10 {
+            assert_eq!(
+                search_u32_le_unchecked(target, &buf).map(|found| found.map(|index| keys[index])),
+                Ok(keys.binary_search(&target).ok().map(|_| target))
+            );
+            assert_eq!(
+                leftmost_rank_u32_le_unchecked(target, &buf),
+                leftmost_rank_u32_le(target, &buf)
+            );
+            assert_eq!(
+                rightmost_rank_u32_le_unchecked(target, &buf),
+                rightmost_rank_u32_le(target, &buf)
+            );
+        }
+    }
+
+    #[test]
+    fn unchecked_searches_do_not_panic_on_unsorted_keys() {
+        let mut buf = [0; 40];
+
+        for (bytes, key) in buf.chunks_exact_mut(8).zip(&[9u64, 2, 7, 1, 5]) {
+            bytes.copy_from_slice(&key.to_le_bytes());
+        }
+
+        for target in 0..11 {
+            let found = search_u64_le_unchecked(target, &buf).unwrap();
+
+            assert!(found.is_none_or(|index| index < 5));
+        }
+
+        assert!(search_u64_le_unchecked(0, &buf[..39]).is_err());
+    }
+}
@@ -0,0 +1,416 @@
+//! Contains functions for finding the elements closest to a target
+
+use crate::{core, utils};
+use ::core::{
+    cmp::Ordering,
+    ops::{Range, Sub},
+};
+
+/// Calculates the index of the element of `arr` closest to `target` by absolute difference.
+///
+/// The index of an element equal to `target` is returned if there is one. Otherwise, the two elements around the
+/// insertion point of `target` are compared, and ties go to the lower index.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::closest;
+///
+/// let target = 8;
+/// let arr = [1, 5, 9, 14];
+/// let found = closest::search_closest(&target, &arr);
+///
+/// assert_eq!(found, Some(2));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn search_closest<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord + Sub<Output = T> + Copy,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Closest search encountered an array that is note sorted");
+    }
+
+    let rank = core::lower_bound(target, arr);
+
+    if rank == arr.len() {
+        return rank.checked_sub(1);
+    }
+
+    if rank == 0 || arr[rank] == *target {
+        return Some(rank);
+    }
+
+    // arr[rank - 1] < target < arr[rank], so both differences are positive
+    if *target - arr[rank - 1] <= arr[rank] - *target {
+        Some(rank - 1)
+    } else {
+        Some(rank)
+    }
+}
+
+/// Which element [`nearest`] returns when `target` is exactly as far from the elements on both of its sides
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TieBreak {
+    /// Return the smaller element
+    PreferLower,
+    /// Return the greater element
+    PreferHigher,
+}
+
+/// Calculates the index of the element of `arr` nearest to `target`, as measured by `distance`.
+///
+/// `distance` is called with `target` and an element, so any `Ord` type can be searched given a way to measure how
+/// far apart two values are. Only the floor and the ceiling of `target` are candidates: `distance` must grow as
+/// elements get further from `target`. The index of the first element equal to `target` is returned if there is one;
+/// otherwise `tie_break` decides between two equally distant candidates. If `max_distance` is given, a nearest
+/// element further than it is not returned.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::closest::{self, TieBreak};
+///
+/// let samples = [100, 200, 300];
+/// let distance = |a: &i32, b: &i32| (a - b).abs();
+///
+/// assert_eq!(closest::nearest(&150, &samples, distance, TieBreak::PreferHigher, None), Some(1));
+/// assert_eq!(closest::nearest(&240, &samples, distance, TieBreak::PreferLower, Some(30)), None);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn nearest<T, D, F>(
+    target: &T,
+    arr: &[T],
+    mut distance: F,
+    tie_break: TieBreak,
+    max_distance: Option<D>,
+) -> Option<usize>
+where
+    T: Ord,
+    D: Ord,
+    F: FnMut(&T, &T) -> D,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Closest search encountered an array that is note sorted");
+    }
+
+    let (index, nearest_distance) = match (core::floor(target, arr), core::ceil(target, arr)) {
+        (_, Some(ceil)) if arr[ceil] == *target => (ceil, distance(target, &arr[ceil])),
+        (Some(floor), Some(ceil)) => {
+            let below = distance(target, &arr[floor]);
+            let above = distance(target, &arr[ceil]);
+
+            match below.cmp(&above) {
+                Ordering::Less => (floor, below),
+                Ordering::Greater => (ceil, above),
+                Ordering::Equal => match tie_break {
+                    TieBreak::PreferLower => (floor, below),
+                    TieBreak::PreferHigher => (ceil, above),
+                },
+            }
+        }
+        (Some(floor), None) => (floor, distance(target, &arr[floor])),
+        (None, Some(ceil)) => (ceil, distance(target, &arr[ceil])),
+        (None, None) => return None,
+    };
+
+    match max_distance {
+        Some(max_distance) if nearest_distance > max_distance => None,
+        _ => Some(index),
+    }
+}
+
+/// Calculates the range of the `k` elements of `arr` closest to `target` by absolute difference.
+///
+/// The window grows outwards from the insertion point of `target`, one element at a time, so this takes
+/// O(log n + k) comparisons once the array is validated. When the elements on both sides are equally close, the
+/// smaller one is taken first. If `k` exceeds the length of the array, the whole array is returned.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::closest;
+///
+/// let target = 6;
+/// let arr = [1, 2, 5, 7, 8, 20];
+/// let window = closest::k_closest(&target, &arr, 3);
+///
+/// assert_eq!(&arr[window], &[5, 7, 8]);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn k_closest<T>(target: &T, arr: &[T], k: usize) -> Range<usize>
+where
+    T: Ord + Sub<Output = T> + Copy,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Closest search encountered an array that is note sorted");
+    }
+
+    let k = k.min(arr.len());
+    let mut left = core::lower_bound(target, arr);
+    let mut right = left;
+
+    while right - left < k {
+        // Elements before left are smaller than target, and elements from right on are greater than or equal to it
+        let take_left = if left == 0 {
+            false
+        } else if right == arr.len() {
+            true
+        } else {
+            *target - arr[left - 1] <= arr[right] - *target
+        };
+
+        if take_left {
+            left -= 1;
+        } else {
+            right += 1;
+        }
+    }
+
+    left..right
+}
+
+#[cfg(test)]
+mod closest_tests {
+    use super::{k_closest, nearest, search_closest, TieBreak};
+
+    #[test]
+    #[should_panic(expected = "Closest search encountered an array that is note sorted")]
+    fn search_closest_panics_when_arr_is_not_sorted() {
+        let target = 5;
+        let arr = [1, 3, 2, 5];
+
+        search_closest(&target, &arr);
+    }
+
+    #[test]
+    fn search_closest_returns_none_for_empty_arr() {
+        let target = 5;
+        let arr = [];
+        let found = search_closest(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn search_closest_returns_some_index_if_target_in_arr() {
+        let target = 9;
+        let arr = [1, 5, 9, 14];
+        let found = search_closest(&target, &arr);
+
+        assert_eq!(found, Some(2));
+    }
+
+    #[test]
+    fn search_closest_returns_left_neighbor_if_nearer() {
+        let target = 6;
+        let arr = [1, 5, 9, 14];
+        let found = search_closest(&target, &arr);
+
+        assert_eq!(found, Some(1));
+    }
+
+    #[test]
+    fn search_closest_returns_right_neighbor_if_nearer() {
+        let target = 12;
+        let arr = [1, 5, 9, 14];
+        let found = search_closest(&target, &arr);
+
+        assert_eq!(found, Some(3));
+    }
+
+    #[test]
+    fn search_closest_returns_lower_index_if_exactly_between() {
+        let target = 7;
+        let arr = [1, 5, 9, 14];
+        let found = search_closest(&target, &arr);
+
+        assert_eq!(found, Some(1));
+    }
+
+    #[test]
+    fn search_closest_returns_first_index_if_target_below_minimum() {
+        let target = -3;
+        let arr = [1, 5, 9, 14];
+        let found = search_closest(&target, &arr);
+
+        assert_eq!(found, Some(0));
+    }
+
+    #[test]
+    fn search_closest_returns_last_index_if_target_above_maximum() {
+        let target = 20;
+        let arr = [1, 5, 9, 14];
+        let found = search_closest(&target, &arr);
+
+        assert_eq!(found, Some(3));
+    }
+
+    fn distance(a: &i32, b: &i32) -> i32 {
+        (a - b).abs()
+    }
+
+    #[test]
+    #[should_panic(expected = "Closest search encountered an array that is note sorted")]
+    fn nearest_panics_when_arr_is_not_sorted() {
+        let target = 5;
+        let arr = [1, 3, 2, 5];
+
+        nearest(&target, &arr, distance, TieBreak::PreferLower, None);
+    }
+
+    #[test]
+    fn nearest_returns_none_for_empty_arr() {
+        let target = 5;
+        let arr = [];
+        let found = nearest(&target, &arr, distance, TieBreak::PreferLower, None);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn nearest_returns_first_index_if_target_in_arr() {
+        let target = 5;
+        let arr = [1, 5, 5, 5, 9];
+        let found = nearest(&target, &arr, distance, TieBreak::PreferHigher, None);
+
+        assert_eq!(found, Some(1));
+    }
+
+    #[test]
+    fn nearest_breaks_ties_at_midpoint_using_policy() {
+        let target = 7;
+        let arr = [1, 5, 5, 9, 9, 14];
+
+        assert_eq!(
+            nearest(&target, &arr, distance, TieBreak::PreferLower, None),
+            Some(2)
+        );
+        assert_eq!(
+            nearest(&target, &arr, distance, TieBreak::PreferHigher, None),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn nearest_returns_nearer_neighbor_off_midpoint() {
+        let arr = [1, 5, 9, 14];
+
+        assert_eq!(
+            nearest(&6, &arr, distance, TieBreak::PreferHigher, None),
+            Some(1)
+        );
+        assert_eq!(
+            nearest(&8, &arr, distance, TieBreak::PreferLower, None),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn nearest_returns_none_if_further_than_max_distance() {
+        let arr = [10, 20, 30];
+
+        assert_eq!(
+            nearest(&24, &arr, distance, TieBreak::PreferLower, Some(4)),
+            Some(1)
+        );
+        assert_eq!(
+            nearest(&25, &arr, distance, TieBreak::PreferLower, Some(4)),
+            None
+        );
+        assert_eq!(
+            nearest(&0, &arr, distance, TieBreak::PreferLower, Some(9)),
+            None
+        );
+        assert_eq!(
+            nearest(&35, &arr, distance, TieBreak::PreferLower, Some(5)),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn nearest_supports_non_numeric_types() {
+        let target = "c";
+        let arr = ["a", "b", "x", "y"];
+        let found = nearest(
+            &target,
+            &arr,
+            |a, b| (a.as_bytes()[0] as i32 - b.as_bytes()[0] as i32).abs(),
+            TieBreak::PreferHigher,
+            None,
+        );
+
+        assert_eq!(found, Some(1));
+    }
+
+    #[test]
+    fn k_closest_returns_empty_range_for_empty_arr() {
+        let target = 5;
+        let arr = [];
+        let window = k_closest(&target, &arr, 3);
+
+        assert_eq!(window, 0..0);
+    }
+
+    #[test]
+    fn k_closest_returns_window_around_target() {
+        let target = 6;
+        let arr = [1, 2, 5, 7, 8, 20];
+        let window = k_closest(&target, &arr, 3);
+
+        assert_eq!(window, 2..5);
+    }
+
+    #[test]
+    fn k_closest_includes_target_if_in_arr() {
+        let target = 7;
+        let arr = [1, 2, 5, 7, 8, 20];
+        let window = k_closest(&target, &arr, 1);
+
+        assert_eq!(window, 3..4);
+    }
+
+    #[test]
+    fn k_closest_prefers_smaller_element_on_ties() {
+        let target = 6;
+        let arr = [1, 4, 5, 7, 8, 20];
+        let window = k_closest(&target, &arr, 3);
+
+        assert_eq!(window, 1..4);
+    }
+
+    #[test]
+    fn k_closest_returns_whole_arr_if_k_exceeds_len() {
+        let target = 6;
+        let arr = [1, 2, 5, 7];
+        let window = k_closest(&target, &arr, 10);
+
+        assert_eq!(window, 0..4);
+    }
+
+    #[test]
+    fn k_closest_handles_targets_outside_arr() {
+        let arr = [1, 2, 5, 7, 8, 20];
+
+        assert_eq!(k_closest(&-10, &arr, 2), 0..2);
+        assert_eq!(k_closest(&50, &arr, 2), 4..6);
+    }
+
+    #[test]
+    fn k_closest_returns_empty_range_at_insertion_point_if_k_is_zero() {
+        let target = 6;
+        let arr = [1, 2, 5, 7, 8, 20];
+        let window = k_closest(&target, &arr, 0);
+
+        assert_eq!(window, 3..3);
+    }
+}
@@ -0,0 +1,233 @@
+use crate::{core, utils};
+use std::collections::VecDeque;
+
+/// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) on the sorted `deque` in order to
+/// find the index of `target`, without making it contiguous.
+///
+/// The search runs over the two slices returned by [`VecDeque::as_slices`], and the index is the logical one, as used
+/// by `deque[index]`.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::collections;
+/// use std::collections::VecDeque;
+///
+/// let mut deque: VecDeque<u64> = (3..8).collect();
+///
+/// deque.push_front(1);
+///
+/// assert_eq!(collections::binary_search_deque(&5, &deque), Some(3));
+/// assert_eq!(collections::binary_search_deque(&2, &deque), None);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the deque is not sorted.
+pub fn binary_search_deque<T>(target: &T, deque: &VecDeque<T>) -> Option<usize>
+where
+    T: Ord,
+{
+    let (front, back) = sorted_slices(deque);
+
+    match front.last() {
+        Some(last) if target <= last => core::binary_search(target, front),
+        _ => core::binary_search(target, back).map(|index| front.len() + index),
+    }
+}
+
+/// Calculates the leftmost rank of `target` in the sorted `deque`, i.e. the number of elements smaller than it, like
+/// [`leftmost_rank`](crate::leftmost_rank) on a slice.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::collections;
+/// use std::collections::VecDeque;
+///
+/// let mut deque: VecDeque<u64> = vec![4, 4, 5].into();
+///
+/// deque.push_front(2);
+///
+/// assert_eq!(collections::leftmost_rank_deque(&4, &deque), 1);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the deque is not sorted.
+pub fn leftmost_rank_deque<T>(target: &T, deque: &VecDeque<T>) -> usize
+where
+    T: Ord,
+{
+    let (front, back) = sorted_slices(deque);
+
+    core::lower_bound(target, front) + core::lower_bound(target, back)
+}
+
+/// Calculates the rightmost rank of `target` in the sorted `deque`, like [`rightmost_rank`](crate::rightmost_rank) on
+/// a slice.
+///
+/// Returns 0 if no element is smaller than or equal to `target`.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::collections;
+/// use std::collections::VecDeque;
+///
+/// let mut deque: VecDeque<u64> = vec![4, 4, 5].into();
+///
+/// deque.push_front(2);
+///
+/// assert_eq!(collections::rightmost_rank_deque(&4, &deque), 2);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the deque is not sorted.
+pub fn rightmost_rank_deque<T>(target: &T, deque: &VecDeque<T>) -> usize
+where
+    T: Ord,
+{
+    let (front, back) = sorted_slices(deque);
+
+    (core::upper_bound(target, front) + core::upper_bound(target, back)).saturating_sub(1)
+}
+
+/// Returns the two slices of `deque`, panicking if they don't form a sorted sequence
+fn sorted_slices<T>(deque: &VecDeque<T>) -> (&[T], &[T])
+where
+    T: Ord,
+{
+    let (front, back) = deque.as_slices();
+    let seam_is_sorted = match (front.last(), back.first()) {
+        (Some(last), Some(first)) => last <= first,
+        _ => true,
+    };
+
+    if !seam_is_sorted || !utils::is_sorted(front) || !utils::is_sorted(back) {
+        panic!("Deque search encountered an array that is note sorted");
+    }
+
+    (front, back)
+}
+
+#[cfg(test)]
+mod deque_tests {
+    use super::{binary_search_deque, leftmost_rank_deque, rightmost_rank_deque};
+    use crate::test_rng::Rng;
+    use crate::{leftmost_rank, rightmost_rank};
+    use std::collections::VecDeque;
+
+    /// Builds a deque holding `front` followed by `back`, with the elements of `front` pushed to the front so that
+    /// the deque wraps around its buffer
+    fn wrapped(front: &[u64], back: &[u64]) -> VecDeque<u64> {
+        let mut deque: VecDeque<u64> = back.iter().copied().collect();
+
+        for &element in front.iter().rev() {
+            deque.push_front(element);
+        }
+
+        deque
+    }
+
+    #[test]
+    #[should_panic(expected = "Deque search encountered an array that is note sorted")]
+    fn binary_search_deque_panics_when_seam_is_not_sorted() {
+        let deque = wrapped(&[5, 6], &[1, 2]);
+
+        binary_search_deque(&5, &deque);
+    }
+
+    #[test]
+    #[should_panic(expected = "Deque search encountered an array that is note sorted")]
+    fn leftmost_rank_deque_panics_when_a_slice_is_not_sorted() {
+        let deque = wrapped(&[1, 2], &[4, 3]);
+
+        leftmost_rank_deque(&3, &deque);
+    }
+
+    #[test]
+    fn binary_search_deque_returns_none_for_empty_deque() {
+        let deque = VecDeque::new();
+
+        assert_eq!(binary_search_deque(&5, &deque), None);
+        assert_eq!(leftmost_rank_deque(&5, &deque), 0);
+        assert_eq!(rightmost_rank_deque(&5, &deque), 0);
+    }
+
+    #[test]
+    fn binary_search_deque_returns_some_index_if_target_in_contiguous_deque() {
+        let deque: VecDeque<u64> = (1..=10).collect();
+
+        assert!(deque.as_slices().1.is_empty());
+
+        for target in 1..=10 {
+            assert_eq!(
+                binary_search_deque(&target, &deque),
+                Some(target as usize - 1)
+            );
+        }
+
+        assert_eq!(binary_search_deque(&0, &deque), None);
+        assert_eq!(binary_search_deque(&11, &deque), None);
+    }
+
+    #[test]
+    fn binary_search_deque_returns_logical_index_if_deque_wraps() {
+        let deque = wrapped(&[1, 3, 5], &[7, 9, 11, 13, 15, 17, 19]);
+        let (front, back) = deque.as_slices();
+
+        assert!(!back.is_empty());
+        assert!(front.len() < back.len());
+
+        for (index, &target) in deque.iter().enumerate() {
+            assert_eq!(binary_search_deque(&target, &deque), Some(index));
+            assert_eq!(binary_search_deque(&(target + 1), &deque), None);
+        }
+    }
+
+    #[test]
+    fn rank_deque_counts_equal_elements_straddling_the_seam() {
+        let deque = wrapped(&[1, 4, 4], &[4, 4, 6]);
+
+        assert!(!deque.as_slices().1.is_empty());
+        assert_eq!(deque.as_slices().0.last(), Some(&4));
+        assert_eq!(leftmost_rank_deque(&4, &deque), 1);
+        assert_eq!(rightmost_rank_deque(&4, &deque), 4);
+        assert_eq!(deque[binary_search_deque(&4, &deque).unwrap()], 4);
+        assert_eq!(leftmost_rank_deque(&5, &deque), 5);
+        assert_eq!(rightmost_rank_deque(&0, &deque), 0);
+    }
+
+    #[test]
+    fn deque_searches_match_slice_searches_on_random_deques() {
+        let mut rng = Rng::new(0xdec0de);
+
+        for _ in 0..300 {
+            let len = rng.below(60) as usize;
+            let mut elements: Vec<u64> = (0..len).map(|_| rng.below(30)).collect();
+
+            elements.sort_unstable();
+
+            let split = rng.below(len as u64 + 1) as usize;
+            let deque = wrapped(&elements[..split], &elements[split..]);
+
+            for target in 0..31 {
+                assert_eq!(
+                    leftmost_rank_deque(&target, &deque),
+                    leftmost_rank(&target, &elements)
+                );
+                assert_eq!(
+                    rightmost_rank_deque(&target, &deque),
+                    rightmost_rank(&target, &elements)
+                );
+
+                match binary_search_deque(&target, &deque) {
+                    Some(index) => assert_eq!(elements[index], target),
+                    None => assert!(!elements.contains(&target)),
+                }
+            }
+        }
+    }
+}
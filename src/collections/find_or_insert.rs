@@ -0,0 +1,234 @@
+use crate::{core, utils};
+
+/// Finds `value` in the sorted `vec`, inserting it if it is absent.
+///
+/// Returns the index of the leftmost element equal to `value` and `false` if there is one, or the index `value` was
+/// inserted at and `true` otherwise. With n elements:
+///
+/// - checking that `vec` is sorted is O(n) comparisons, on every call,
+/// - a single rank query, O(log n), serves both the search and the insertion,
+/// - the insertion is O(n) moves.
+///
+/// Callers that look up many values can keep them in a [`SortedVec`](super::SortedVec) instead, which keeps its
+/// elements sorted and so never checks them.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::collections;
+///
+/// let mut vec = vec![1, 3, 5];
+///
+/// assert_eq!(collections::find_or_insert(&mut vec, 3), (1, false));
+/// assert_eq!(collections::find_or_insert(&mut vec, 4), (2, true));
+/// assert_eq!(vec, [1, 3, 4, 5]);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn find_or_insert<T>(vec: &mut Vec<T>, value: T) -> (usize, bool)
+where
+    T: Ord,
+{
+    if !utils::is_sorted(vec) {
+        panic!("Find or insert encountered an array that is note sorted");
+    }
+
+    let rank = core::lower_bound(&value, vec);
+
+    if rank < vec.len() && vec[rank] == value {
+        (rank, false)
+    } else {
+        vec.insert(rank, value);
+
+        (rank, true)
+    }
+}
+
+/// Finds the record whose key is `key` in `vec`, sorted by the keys `key_of` extracts, inserting the record built by
+/// `make` if there is none.
+///
+/// `make` is only called on insertion, and must build a record whose key is `key`. Returns the index of the leftmost
+/// record with the key and `false` if there is one, or the index the new record was inserted at and `true` otherwise.
+/// With n records:
+///
+/// - checking that `vec` is sorted by key is O(n) comparisons, extracting every key once, on every call,
+/// - a single rank query, O(log n), serves both the search and the insertion,
+/// - the insertion is O(n) moves.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::collections;
+///
+/// let mut records = vec![(1, "one"), (3, "three")];
+///
+/// assert_eq!(collections::find_or_insert_by_key(&mut records, &2, |record| record.0, || (2, "two")), (1, true));
+/// assert_eq!(collections::find_or_insert_by_key(&mut records, &3, |record| record.0, || unreachable!()), (2, false));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted by key.
+pub fn find_or_insert_by_key<T, K, F, M>(
+    vec: &mut Vec<T>,
+    key: &K,
+    mut key_of: F,
+    make: M,
+) -> (usize, bool)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+    M: FnOnce() -> T,
+{
+    let mut keys = vec.iter().map(&mut key_of);
+    let sorted = keys.next().is_none_or(|first| {
+        keys.try_fold(first, |previous, current| {
+            (previous <= current).then_some(current)
+        })
+        .is_some()
+    });
+
+    if !sorted {
+        panic!("Find or insert encountered an array that is note sorted");
+    }
+
+    let rank = core::partition_point(vec, |record| key_of(record) < *key);
+
+    if rank < vec.len() && key_of(&vec[rank]) == *key {
+        (rank, false)
+    } else {
+        vec.insert(rank, make());
+
+        (rank, true)
+    }
+}
+
+#[cfg(test)]
+mod find_or_insert_tests {
+    use super::{find_or_insert, find_or_insert_by_key};
+    use std::collections::BTreeMap;
+
+    #[test]
+    #[should_panic(expected = "Find or insert encountered an array that is note sorted")]
+    fn find_or_insert_panics_when_arr_is_not_sorted() {
+        let mut vec = vec![1, 3, 2];
+
+        find_or_insert(&mut vec, 2);
+    }
+
+    #[test]
+    fn find_or_insert_inserts_into_empty_vec() {
+        let mut vec = Vec::new();
+        let result = find_or_insert(&mut vec, 5);
+
+        assert_eq!(result, (0, true));
+        assert_eq!(vec, [5]);
+    }
+
+    #[test]
+    fn find_or_insert_returns_leftmost_index_of_duplicates() {
+        let mut vec = vec![1, 4, 4, 4, 7];
+        let result = find_or_insert(&mut vec, 4);
+
+        assert_eq!(result, (1, false));
+        assert_eq!(vec.len(), 5);
+    }
+
+    #[test]
+    fn find_or_insert_inserts_at_both_ends() {
+        let mut vec = vec![3, 5];
+
+        assert_eq!(find_or_insert(&mut vec, 1), (0, true));
+        assert_eq!(find_or_insert(&mut vec, 9), (3, true));
+        assert_eq!(vec, [1, 3, 5, 9]);
+    }
+
+    #[test]
+    fn find_or_insert_by_key_calls_make_only_on_insertion() {
+        let mut records = vec![(1, 'a'), (3, 'c'), (3, 'd')];
+        let mut calls = 0;
+
+        let hit = find_or_insert_by_key(
+            &mut records,
+            &3,
+            |record| record.0,
+            || {
+                calls += 1;
+                (3, 'x')
+            },
+        );
+
+        assert_eq!(hit, (1, false));
+        assert_eq!(calls, 0);
+
+        let miss = find_or_insert_by_key(
+            &mut records,
+            &2,
+            |record| record.0,
+            || {
+                calls += 1;
+                (2, 'b')
+            },
+        );
+
+        assert_eq!(miss, (1, true));
+        assert_eq!(calls, 1);
+        assert_eq!(records, [(1, 'a'), (2, 'b'), (3, 'c'), (3, 'd')]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Find or insert encountered an array that is note sorted")]
+    fn find_or_insert_by_key_panics_when_arr_is_not_sorted_by_key() {
+        let mut records = vec![(1, 'a'), (3, 'c'), (2, 'b')];
+
+        find_or_insert_by_key(&mut records, &2, |record| record.0, || (2, 'x'));
+    }
+
+    #[test]
+    fn find_or_insert_by_key_extracts_every_key_once_when_checking() {
+        let mut records: Vec<(u32, ())> = (0..64).map(|key| (key * 2, ())).collect();
+        let mut extractions = 0;
+
+        let result = find_or_insert_by_key(
+            &mut records,
+            &40,
+            |record| {
+                extractions += 1;
+                record.0
+            },
+            || unreachable!(),
+        );
+
+        assert_eq!(result, (20, false));
+        // 64 keys for the check, at most 7 probes for the rank query and 1 to compare the found record
+        assert!(extractions <= 64 + 7 + 1, "{} extractions", extractions);
+    }
+
+    #[test]
+    fn find_or_insert_by_key_matches_btree_map_oracle() {
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut records: Vec<(u64, u64)> = Vec::new();
+        let mut oracle = BTreeMap::new();
+
+        for step in 0..2_000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+
+            let key = state % 300;
+            let (index, inserted) =
+                find_or_insert_by_key(&mut records, &key, |record| record.0, || (key, step));
+            let expected_inserted = !oracle.contains_key(&key);
+
+            oracle.entry(key).or_insert(step);
+
+            assert_eq!(inserted, expected_inserted);
+            assert_eq!(index, oracle.range(..key).count());
+            assert_eq!(records[index], (key, oracle[&key]));
+        }
+
+        assert_eq!(records, oracle.into_iter().collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,12 @@
+//! Owning collections that keep their elements sorted, read-only views over sorted slices, and helpers mutating
+//! sorted `Vec`s and searching sorted `VecDeque`s
+
+mod deque;
+mod find_or_insert;
+mod sorted_slice_map;
+mod sorted_vec;
+
+pub use deque::{binary_search_deque, leftmost_rank_deque, rightmost_rank_deque};
+pub use find_or_insert::{find_or_insert, find_or_insert_by_key};
+pub use sorted_slice_map::{SortedSliceMap, SortedSliceMapError};
+pub use sorted_vec::SortedVec;
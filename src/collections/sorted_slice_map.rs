@@ -0,0 +1,224 @@
+use crate::{core, error::NotSortedError};
+use std::{error::Error, fmt, ops::RangeBounds};
+
+/// Error returned by [`SortedSliceMap::new`] when the slices do not form a map
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortedSliceMapError {
+    /// There are not as many values as keys
+    LengthMismatch {
+        /// The number of keys
+        keys_len: usize,
+        /// The number of values
+        values_len: usize,
+    },
+    /// The key at `index` is smaller than its predecessor
+    NotSorted {
+        /// The index of the first key smaller than its predecessor
+        index: usize,
+    },
+}
+
+impl fmt::Display for SortedSliceMapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LengthMismatch {
+                keys_len,
+                values_len,
+            } => write!(f, "{} values given for {} keys", values_len, keys_len),
+            Self::NotSorted { index } => {
+                write!(f, "key {} is smaller than its predecessor", index)
+            }
+        }
+    }
+}
+
+impl Error for SortedSliceMapError {}
+
+impl From<NotSortedError> for SortedSliceMapError {
+    fn from(error: NotSortedError) -> Self {
+        Self::NotSorted {
+            index: error.index(),
+        }
+    }
+}
+
+/// Read-only map over two parallel slices of keys and values, sorted by key
+///
+/// Nothing is copied, so the slices may come from anywhere, e.g. a memory-mapped file. Keys may repeat, in which case
+/// [`get_all`](Self::get_all) returns the values of every copy.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::collections::SortedSliceMap;
+///
+/// let keys = [10, 20, 20, 30];
+/// let values = [1.0, 2.0, 2.5, 3.0];
+/// let map = SortedSliceMap::new(&keys, &values).unwrap();
+///
+/// assert_eq!(map.get(&30), Some(&3.0));
+/// assert_eq!(map.get_all(&20), &[2.0, 2.5]);
+/// assert_eq!(map.range(15..30), (&keys[1..3], &values[1..3]));
+/// assert_eq!(map.first_at_or_after(&25), Some((&30, &3.0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SortedSliceMap<'a, K, V> {
+    /// The keys, in ascending order
+    keys: &'a [K],
+    /// The values, in the order of their keys
+    values: &'a [V],
+}
+
+impl<'a, K, V> SortedSliceMap<'a, K, V>
+where
+    K: Ord,
+{
+    /// Returns a new `SortedSliceMap` if `keys` and `values` have the same length and `keys` is sorted.
+    pub fn new(keys: &'a [K], values: &'a [V]) -> Result<Self, SortedSliceMapError> {
+        if keys.len() != values.len() {
+            return Err(SortedSliceMapError::LengthMismatch {
+                keys_len: keys.len(),
+                values_len: values.len(),
+            });
+        }
+
+        NotSortedError::check(keys)?;
+
+        Ok(Self { keys, values })
+    }
+
+    /// Returns the value of a key equal to `key`.
+    pub fn get(&self, key: &K) -> Option<&'a V> {
+        core::binary_search_by(self.keys, |element| element.cmp(key))
+            .map(|index| &self.values[index])
+    }
+
+    /// Returns the values of every key equal to `key`.
+    pub fn get_all(&self, key: &K) -> &'a [V] {
+        &self.values[core::lower_bound(key, self.keys)..core::upper_bound(key, self.keys)]
+    }
+
+    /// Returns the keys within `range`, and their values.
+    pub fn range<R>(&self, range: R) -> (&'a [K], &'a [V])
+    where
+        R: RangeBounds<K>,
+    {
+        let indices = core::range_indices(self.keys, range);
+
+        (&self.keys[indices.clone()], &self.values[indices])
+    }
+
+    /// Returns the first key greater than or equal to `key`, and its value.
+    pub fn first_at_or_after(&self, key: &K) -> Option<(&'a K, &'a V)> {
+        let index = core::lower_bound(key, self.keys);
+
+        self.keys
+            .get(index)
+            .map(|found| (found, &self.values[index]))
+    }
+
+    /// Returns the keys
+    pub fn keys(&self) -> &'a [K] {
+        self.keys
+    }
+
+    /// Returns the values
+    pub fn values(&self) -> &'a [V] {
+        self.values
+    }
+
+    /// Returns the number of entries
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns whether there are no entries
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod sorted_slice_map_tests {
+    use super::{SortedSliceMap, SortedSliceMapError};
+
+    #[test]
+    fn new_rejects_length_mismatch() {
+        let keys = [1u64, 2, 3];
+        let values = [1.0f32, 2.0];
+
+        assert_eq!(
+            SortedSliceMap::new(&keys, &values).unwrap_err(),
+            SortedSliceMapError::LengthMismatch {
+                keys_len: 3,
+                values_len: 2
+            }
+        );
+    }
+
+    #[test]
+    fn new_rejects_unsorted_keys() {
+        let keys = [1u64, 3, 2];
+        let values = [1.0f32, 3.0, 2.0];
+
+        assert_eq!(
+            SortedSliceMap::new(&keys, &values).unwrap_err(),
+            SortedSliceMapError::NotSorted { index: 2 }
+        );
+    }
+
+    #[test]
+    fn get_returns_value_of_key() {
+        let keys = [1u64, 3, 5, 7];
+        let values = [1.0f32, 3.0, 5.0, 7.0];
+        let map = SortedSliceMap::new(&keys, &values).unwrap();
+
+        assert_eq!(map.get(&5), Some(&5.0));
+        assert_eq!(map.get(&0), None);
+        assert_eq!(map.get(&4), None);
+        assert_eq!(map.get(&8), None);
+    }
+
+    #[test]
+    fn get_all_returns_values_of_duplicate_keys() {
+        let keys = [1u64, 3, 3, 3, 7];
+        let values = [1.0f32, 3.0, 3.1, 3.2, 7.0];
+        let map = SortedSliceMap::new(&keys, &values).unwrap();
+
+        assert_eq!(map.get_all(&3), &[3.0, 3.1, 3.2]);
+        assert!(map.get_all(&4).is_empty());
+    }
+
+    #[test]
+    fn range_returns_parallel_sub_slices() {
+        let keys = [1u64, 3, 5, 7, 9];
+        let values = [1.0f32, 3.0, 5.0, 7.0, 9.0];
+        let map = SortedSliceMap::new(&keys, &values).unwrap();
+
+        assert_eq!(map.range(3..7), (&keys[1..3], &values[1..3]));
+        assert_eq!(map.range(3..=7), (&keys[1..4], &values[1..4]));
+        assert_eq!(map.range(..4), (&keys[..2], &values[..2]));
+        assert_eq!(map.range(8..), (&keys[4..], &values[4..]));
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn range_returns_empty_slices_for_reversed_range() {
+        let keys = [1u64, 3, 5, 7, 9];
+        let values = [1.0f32, 3.0, 5.0, 7.0, 9.0];
+        let map = SortedSliceMap::new(&keys, &values).unwrap();
+
+        assert_eq!(map.range(6..2), (&keys[3..3], &values[3..3]));
+    }
+
+    #[test]
+    fn first_at_or_after_returns_next_entry() {
+        let keys = [1u64, 3, 5];
+        let values = [1.0f32, 3.0, 5.0];
+        let map = SortedSliceMap::new(&keys, &values).unwrap();
+
+        assert_eq!(map.first_at_or_after(&3), Some((&3, &3.0)));
+        assert_eq!(map.first_at_or_after(&4), Some((&5, &5.0)));
+        assert_eq!(map.first_at_or_after(&6), None);
+    }
+}
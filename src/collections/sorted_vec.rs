@@ -0,0 +1,283 @@
+use crate::{
+    core,
+    error::NotSortedError,
+    memory::{FieldUsage, MemoryBreakdown, MemoryUsage},
+};
+use std::iter::FromIterator;
+
+/// A `Vec` that keeps its elements sorted
+///
+/// Equal elements are kept in insertion order. With n elements:
+///
+/// - [`insert`](Self::insert) is O(log n) comparisons plus O(n) moves,
+/// - [`contains`](Self::contains) and [`find`](Self::find) are O(log n),
+/// - [`remove_item`](Self::remove_item) is O(log n) comparisons plus O(n) moves,
+/// - [`from_vec`](Self::from_vec) is O(n log n) and [`from_sorted_vec`](Self::from_sorted_vec) is O(n).
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::collections::SortedVec;
+///
+/// let mut sorted: SortedVec<i32> = vec![5, 1, 4].into_iter().collect();
+///
+/// sorted.insert(3);
+///
+/// assert_eq!(sorted.as_slice(), &[1, 3, 4, 5]);
+/// assert_eq!(sorted.find(&4), Some(2));
+/// assert!(sorted.remove_item(&1));
+/// assert!(!sorted.contains(&1));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SortedVec<T> {
+    /// The elements, in ascending order
+    vec: Vec<T>,
+}
+
+impl<T> SortedVec<T>
+where
+    T: Ord,
+{
+    /// Returns a new, empty `SortedVec`
+    pub fn new() -> Self {
+        Self { vec: Vec::new() }
+    }
+
+    /// Returns a new `SortedVec` holding the elements of `vec`, sorting them. O(n log n).
+    pub fn from_vec(mut vec: Vec<T>) -> Self {
+        vec.sort();
+
+        Self { vec }
+    }
+
+    /// Returns a new `SortedVec` holding the elements of `vec` if they are already sorted. O(n).
+    pub fn from_sorted_vec(vec: Vec<T>) -> Result<Self, NotSortedError> {
+        NotSortedError::check(&vec)?;
+
+        Ok(Self { vec })
+    }
+
+    /// Inserts `element` after the elements equal to it, returning its index. O(log n) comparisons plus O(n) moves.
+    pub fn insert(&mut self, element: T) -> usize {
+        // One past the rightmost rank, so that equal elements stay in insertion order
+        let index = core::upper_bound(&element, &self.vec);
+
+        self.vec.insert(index, element);
+
+        index
+    }
+
+    /// Returns whether an element equal to `target` is present. O(log n).
+    pub fn contains(&self, target: &T) -> bool {
+        self.find(target).is_some()
+    }
+
+    /// Returns the index of an element equal to `target`. O(log n).
+    pub fn find(&self, target: &T) -> Option<usize> {
+        core::binary_search_by(&self.vec, |element| element.cmp(target))
+    }
+
+    /// Removes one element equal to `target`, returning whether one was present. O(log n) comparisons plus O(n)
+    /// moves.
+    pub fn remove_item(&mut self, target: &T) -> bool {
+        match self.find(target) {
+            Some(index) => {
+                self.vec.remove(index);
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes and returns the element at `index`. O(n).
+    ///
+    /// # Panics
+    ///
+    /// The function panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        self.vec.remove(index)
+    }
+
+    /// Returns the elements as a sorted slice
+    pub fn as_slice(&self) -> &[T] {
+        &self.vec
+    }
+
+    /// Returns the underlying `Vec`
+    pub fn into_vec(self) -> Vec<T> {
+        self.vec
+    }
+
+    /// Returns the number of elements
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Returns whether there are no elements
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+}
+
+impl<T> Default for SortedVec<T>
+where
+    T: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for SortedVec<T>
+where
+    T: Ord,
+{
+    /// Collects and sorts the elements. O(n log n).
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self::from_vec(iter.into_iter().collect())
+    }
+}
+
+impl<T> Extend<T> for SortedVec<T>
+where
+    T: Ord,
+{
+    /// Appends the elements and sorts the result. O((n + m) log (n + m)) for m new elements, which beats m
+    /// insertions for large batches.
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.vec.extend(iter);
+        // The sort is stable, so equal elements stay in insertion order
+        self.vec.sort();
+    }
+}
+
+impl<T> AsRef<[T]> for SortedVec<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.vec
+    }
+}
+
+impl<T> MemoryUsage for SortedVec<T> {
+    fn memory_bytes(&self) -> MemoryBreakdown {
+        MemoryBreakdown::new(self).with(FieldUsage::of_vec("vec", &self.vec))
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.vec.shrink_to_fit();
+    }
+}
+
+#[cfg(test)]
+mod sorted_vec_tests {
+    use super::SortedVec;
+    use crate::test_rng::Rng;
+    use std::collections::BTreeMap;
+
+    /// Flattens a multiset model into the sorted sequence it represents
+    fn flatten(model: &BTreeMap<u64, usize>) -> Vec<u64> {
+        model
+            .iter()
+            .flat_map(|(&value, &count)| std::iter::repeat_n(value, count))
+            .collect()
+    }
+
+    #[test]
+    fn insert_keeps_equal_elements_in_insertion_order() {
+        let mut sorted = SortedVec::new();
+
+        sorted.insert((2, 'a'));
+        sorted.insert((1, 'b'));
+        sorted.insert((2, 'c'));
+
+        assert_eq!(sorted.as_slice(), &[(1, 'b'), (2, 'a'), (2, 'c')]);
+        assert_eq!(sorted.insert((2, 'b')), 2);
+    }
+
+    #[test]
+    fn from_sorted_vec_rejects_unsorted_vec() {
+        let error = SortedVec::from_sorted_vec(vec![1, 3, 2]).unwrap_err();
+
+        assert_eq!(error.index(), 2);
+        assert!(SortedVec::from_sorted_vec(vec![1, 2, 2]).is_ok());
+    }
+
+    #[test]
+    fn collect_and_extend_sort_elements() {
+        let mut sorted: SortedVec<i32> = vec![3, 1, 2].into_iter().collect();
+
+        sorted.extend([0, 5, 2]);
+
+        assert_eq!(sorted.as_slice(), &[0, 1, 2, 2, 3, 5]);
+    }
+
+    #[test]
+    fn operations_match_multiset_model() {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        let mut sorted = SortedVec::new();
+        let mut model = BTreeMap::new();
+
+        for _ in 0..2_000 {
+            let value = rng.below(64);
+
+            match rng.below(4) {
+                0 | 1 => {
+                    sorted.insert(value);
+                    *model.entry(value).or_insert(0) += 1;
+                }
+                2 => {
+                    let removed = match model.get_mut(&value) {
+                        Some(count) => {
+                            *count -= 1;
+
+                            if *count == 0 {
+                                model.remove(&value);
+                            }
+
+                            true
+                        }
+                        None => false,
+                    };
+
+                    assert_eq!(sorted.remove_item(&value), removed);
+                }
+                _ => {
+                    assert_eq!(sorted.contains(&value), model.contains_key(&value));
+                    assert_eq!(
+                        sorted.find(&value).map(|index| sorted.as_slice()[index]),
+                        model.get(&value).map(|_| value)
+                    );
+                }
+            }
+
+            assert_eq!(sorted.len(), model.values().sum::<usize>());
+        }
+
+        assert_eq!(sorted.as_slice(), flatten(&model).as_slice());
+    }
+
+    #[test]
+    fn extend_matches_multiset_model() {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        let mut sorted = SortedVec::new();
+        let mut model = BTreeMap::new();
+
+        for _ in 0..50 {
+            let batch: Vec<u64> = (0..rng.below(20)).map(|_| rng.below(32)).collect();
+
+            for &value in &batch {
+                *model.entry(value).or_insert(0) += 1;
+            }
+
+            sorted.extend(batch);
+
+            assert_eq!(sorted.as_slice(), flatten(&model).as_slice());
+        }
+    }
+}
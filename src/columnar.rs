@@ -0,0 +1,451 @@
+//! Search over a sorted column split into consecutive chunks, such as the record batches of a columnar table
+
+use crate::{
+    core,
+    memory::{FieldUsage, MemoryBreakdown, MemoryUsage},
+    seam::SeamPolicy,
+};
+use std::{cmp::Ordering, error::Error, fmt, ops::Range};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Error returned when the chunks given to [`ChunkedColumn::new`] are not globally sorted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkedColumnError {
+    /// The chunk is not sorted
+    NotSorted {
+        /// The index of the offending chunk
+        chunk: usize,
+        /// The offset, within the chunk, of the first element smaller than its predecessor
+        offset: usize,
+    },
+    /// The first element of the chunk is smaller than the last element of the previous non-empty chunk
+    NotOrderedAcrossChunks {
+        /// The index of the offending chunk
+        chunk: usize,
+    },
+    /// The first element of the chunk equals the last element of the previous non-empty chunk, which
+    /// [`SeamPolicy::RequireStrictSeams`] forbids
+    EqualAcrossSeam {
+        /// The index of the chunk starting at the seam
+        chunk: usize,
+    },
+}
+
+impl ChunkedColumnError {
+    /// Returns the index of the offending chunk
+    pub fn chunk(&self) -> usize {
+        match self {
+            Self::NotSorted { chunk, .. }
+            | Self::NotOrderedAcrossChunks { chunk }
+            | Self::EqualAcrossSeam { chunk } => *chunk,
+        }
+    }
+}
+
+impl fmt::Display for ChunkedColumnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotSorted { chunk, offset } => write!(
+                f,
+                "chunk {} is not sorted: element {} is smaller than its predecessor",
+                chunk, offset
+            ),
+            Self::NotOrderedAcrossChunks { chunk } => write!(
+                f,
+                "chunk {} starts with an element smaller than the end of the previous chunk",
+                chunk
+            ),
+            Self::EqualAcrossSeam { chunk } => write!(
+                f,
+                "chunk {} starts with an element equal to the end of the previous chunk",
+                chunk
+            ),
+        }
+    }
+}
+
+impl Error for ChunkedColumnError {}
+
+/// A sorted column stored as consecutive chunks, each sorted and ordered after the previous one
+///
+/// Ranks are global, i.e. they count elements across all chunks, and positions are `(chunk_index, offset)` pairs.
+/// Unless [`SeamPolicy::RequireStrictSeams`] is requested, equal keys may straddle the seam between two chunks, and
+/// [`equal_range`](Self::equal_range) then spans every chunk they appear in.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::columnar::ChunkedColumn;
+///
+/// let first = [1, 3, 5];
+/// let second = [5, 7];
+/// let third = [9, 11, 13];
+/// let column = ChunkedColumn::new(vec![&first[..], &second, &third]).unwrap();
+///
+/// assert_eq!(column.search(&7), Some((1, 1)));
+/// assert_eq!(column.rank(&9), 5);
+/// assert_eq!(column.equal_range(&5), 2..4);
+/// assert_eq!(column.slice_between(&4, &10), vec![&first[2..], &second[..], &third[..1]]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChunkedColumn<'a, T> {
+    /// The chunks, in column order
+    chunks: Vec<&'a [T]>,
+    /// The global rank of the first element of each chunk
+    starts: Vec<usize>,
+    /// The indices of the chunks that hold at least one element
+    non_empty: Vec<usize>,
+}
+
+impl<'a, T> ChunkedColumn<'a, T>
+where
+    T: Ord,
+{
+    /// Returns a new `ChunkedColumn` after validating that every chunk is sorted and that each non-empty chunk starts
+    /// at or after the end of the previous non-empty one.
+    pub fn new(chunks: Vec<&'a [T]>) -> Result<Self, ChunkedColumnError> {
+        Self::with_seam_policy(chunks, SeamPolicy::AllowEqualAcrossSeams)
+    }
+
+    /// Returns a new `ChunkedColumn` like [`new`](Self::new), additionally requiring under
+    /// [`SeamPolicy::RequireStrictSeams`] that each non-empty chunk starts strictly after the end of the previous one.
+    pub fn with_seam_policy(
+        chunks: Vec<&'a [T]>,
+        policy: SeamPolicy,
+    ) -> Result<Self, ChunkedColumnError> {
+        let mut starts = Vec::with_capacity(chunks.len());
+        let mut non_empty = Vec::new();
+        let mut len = 0;
+
+        for (chunk, elements) in chunks.iter().enumerate() {
+            if let Some(position) = elements.windows(2).position(|pair| pair[0] > pair[1]) {
+                return Err(ChunkedColumnError::NotSorted {
+                    chunk,
+                    offset: position + 1,
+                });
+            }
+
+            if let (Some(&previous), Some(first)) = (non_empty.last(), elements.first()) {
+                let previous: &[T] = chunks[previous];
+
+                match previous[previous.len() - 1].cmp(first) {
+                    Ordering::Greater => {
+                        return Err(ChunkedColumnError::NotOrderedAcrossChunks { chunk })
+                    }
+                    Ordering::Equal if policy == SeamPolicy::RequireStrictSeams => {
+                        return Err(ChunkedColumnError::EqualAcrossSeam { chunk })
+                    }
+                    _ => {}
+                }
+            }
+
+            if !elements.is_empty() {
+                non_empty.push(chunk);
+            }
+
+            starts.push(len);
+            len += elements.len();
+        }
+
+        Ok(Self {
+            chunks,
+            starts,
+            non_empty,
+        })
+    }
+
+    /// Returns the position of the leftmost occurrence of `target`, if any.
+    pub fn search(&self, target: &T) -> Option<(usize, usize)> {
+        let position = self.position(self.rank(target))?;
+
+        if self.chunks[position.0][position.1] == *target {
+            Some(position)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of elements smaller than `target` across all chunks.
+    pub fn rank(&self, target: &T) -> usize {
+        self.global_bound(target, core::lower_bound)
+    }
+
+    /// Returns the global range of ranks holding elements equal to `target`.
+    pub fn equal_range(&self, target: &T) -> Range<usize> {
+        self.rank(target)..self.global_bound(target, core::upper_bound)
+    }
+
+    /// Returns the `(chunk_index, offset)` position of the element at global rank `rank`, or `None` if `rank` is not
+    /// smaller than the length of the column.
+    pub fn position(&self, rank: usize) -> Option<(usize, usize)> {
+        if rank >= self.len() {
+            return None;
+        }
+
+        let chunk = core::upper_bound(&rank, &self.starts) - 1;
+
+        Some((chunk, rank - self.starts[chunk]))
+    }
+
+    /// Returns the minimal set of non-empty sub-slices covering the elements greater than or equal to `lo` and
+    /// smaller than `hi`, in column order.
+    pub fn slice_between(&self, lo: &T, hi: &T) -> Vec<&'a [T]> {
+        let start = self.rank(lo);
+        let end = self.rank(hi).max(start);
+
+        self.chunks
+            .iter()
+            .zip(&self.starts)
+            .filter_map(|(elements, &chunk_start)| {
+                let from = start.max(chunk_start) - chunk_start;
+                let to = end
+                    .min(chunk_start + elements.len())
+                    .saturating_sub(chunk_start);
+
+                if from < to {
+                    Some(&elements[from..to])
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the chunks, in column order.
+    pub fn chunks(&self) -> &[&'a [T]] {
+        &self.chunks
+    }
+
+    /// Returns the number of elements across all chunks.
+    pub fn len(&self) -> usize {
+        match self.chunks.last() {
+            Some(last) => self.starts[self.starts.len() - 1] + last.len(),
+            None => 0,
+        }
+    }
+
+    /// Returns `true` if the column holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.non_empty.is_empty()
+    }
+
+    /// Returns the global rank `bound` reports for `target`, searching only the first non-empty chunk whose last
+    /// element is not smaller than `target`, or not greater for an upper bound.
+    fn global_bound<B>(&self, target: &T, bound: B) -> usize
+    where
+        B: Fn(&T, &[T]) -> usize,
+    {
+        let preceding = core::partition_point(&self.non_empty, |&chunk| {
+            let elements = self.chunks[chunk];
+
+            bound(target, &elements[elements.len() - 1..]) == 1
+        });
+
+        match self.non_empty.get(preceding) {
+            Some(&chunk) => self.starts[chunk] + bound(target, self.chunks[chunk]),
+            None => self.len(),
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T> ChunkedColumn<'a, T>
+where
+    T: Ord + Sync,
+{
+    /// Returns the [`rank`](Self::rank) of every target, computing them in parallel.
+    pub fn par_rank_many(&self, targets: &[T]) -> Vec<usize> {
+        targets.par_iter().map(|target| self.rank(target)).collect()
+    }
+}
+
+impl<'a, T> MemoryUsage for ChunkedColumn<'a, T> {
+    fn memory_bytes(&self) -> MemoryBreakdown {
+        MemoryBreakdown::new(self)
+            .with(FieldUsage::of_vec("chunks", &self.chunks))
+            .with(FieldUsage::of_vec("starts", &self.starts))
+            .with(FieldUsage::of_vec("non_empty", &self.non_empty))
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.chunks.shrink_to_fit();
+        self.starts.shrink_to_fit();
+        self.non_empty.shrink_to_fit();
+    }
+}
+
+#[cfg(test)]
+mod columnar_tests {
+    use super::{ChunkedColumn, ChunkedColumnError};
+    use crate::{ranks, seam::SeamPolicy};
+
+    #[test]
+    fn new_accepts_equal_keys_straddling_chunk_seam() {
+        let first = [1, 2, 2];
+        let second = [2, 2, 3];
+        let column = ChunkedColumn::new(vec![&first[..], &second]).unwrap();
+
+        assert_eq!(column.equal_range(&2), 1..5);
+        assert_eq!(column.search(&2), Some((0, 1)));
+    }
+
+    #[test]
+    fn new_returns_error_for_unsorted_chunk() {
+        let first = [1, 2];
+        let second = [4, 3];
+        let error = ChunkedColumn::new(vec![&first[..], &second]).unwrap_err();
+
+        assert_eq!(
+            error,
+            ChunkedColumnError::NotSorted {
+                chunk: 1,
+                offset: 1
+            }
+        );
+    }
+
+    #[test]
+    fn new_returns_error_for_chunks_out_of_order_across_empty_chunk() {
+        let first = [1, 5];
+        let empty: [i32; 0] = [];
+        let third = [4, 6];
+        let error = ChunkedColumn::new(vec![&first[..], &empty, &third]).unwrap_err();
+
+        assert_eq!(
+            error,
+            ChunkedColumnError::NotOrderedAcrossChunks { chunk: 2 }
+        );
+        assert_eq!(error.chunk(), 2);
+    }
+
+    #[test]
+    fn search_returns_chunk_and_offset_with_single_element_chunks() {
+        let chunks = [[1], [3], [5], [7]];
+        let column = ChunkedColumn::new(chunks.iter().map(|chunk| &chunk[..]).collect()).unwrap();
+
+        assert_eq!(column.search(&5), Some((2, 0)));
+        assert_eq!(column.search(&4), None);
+        assert_eq!(column.search(&8), None);
+        assert_eq!(column.rank(&6), 3);
+    }
+
+    #[test]
+    fn slice_between_returns_sub_slices_spanning_multiple_chunks() {
+        let first = [1, 3, 5];
+        let empty: [i32; 0] = [];
+        let third = [5, 7];
+        let fourth = [9, 11, 13];
+        let column = ChunkedColumn::new(vec![&first[..], &empty, &third, &fourth]).unwrap();
+
+        assert_eq!(
+            column.slice_between(&3, &11),
+            vec![&first[1..], &third[..], &fourth[..1]]
+        );
+        assert_eq!(column.slice_between(&5, &6), vec![&first[2..], &third[..1]]);
+        assert!(column.slice_between(&6, &7).is_empty());
+        assert!(column.slice_between(&11, &3).is_empty());
+        assert_eq!(column.slice_between(&0, &100).len(), 3);
+    }
+
+    #[test]
+    fn position_returns_none_past_the_end() {
+        let first = [1, 2];
+        let empty: [i32; 0] = [];
+        let third = [3];
+        let column = ChunkedColumn::new(vec![&first[..], &empty, &third]).unwrap();
+
+        assert_eq!(column.position(2), Some((2, 0)));
+        assert_eq!(column.position(3), None);
+        assert_eq!(column.len(), 3);
+    }
+
+    #[test]
+    fn ranks_match_ranks_over_concatenated_chunks() {
+        let arr = [0, 1, 1, 2, 4, 4, 4, 4, 6, 9, 9];
+
+        for split in 0..=arr.len() {
+            let (left, right) = arr.split_at(split);
+            let column = ChunkedColumn::new(vec![left, right]).unwrap();
+
+            for target in -1..11 {
+                assert_eq!(column.rank(&target), ranks::leftmost_rank(&target, &arr));
+                assert_eq!(
+                    column.equal_range(&target),
+                    ranks::equal_range(&target, &arr)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn empty_column_has_no_elements() {
+        let column: ChunkedColumn<i32> = ChunkedColumn::new(Vec::new()).unwrap();
+
+        assert!(column.is_empty());
+        assert_eq!(column.rank(&1), 0);
+        assert_eq!(column.search(&1), None);
+        assert!(column.slice_between(&0, &2).is_empty());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_rank_many_matches_rank() {
+        let first = [1, 3, 5];
+        let second = [5, 7];
+        let column = ChunkedColumn::new(vec![&first[..], &second]).unwrap();
+        let targets = [0, 5, 6, 8];
+
+        assert_eq!(column.par_rank_many(&targets), vec![0, 2, 4, 5]);
+    }
+
+    #[test]
+    fn with_seam_policy_rejects_equal_across_seam_when_strict() {
+        let first = [1, 2];
+        let empty: [i32; 0] = [];
+        let third = [2, 3];
+        let error = ChunkedColumn::with_seam_policy(
+            vec![&first[..], &empty, &third],
+            SeamPolicy::RequireStrictSeams,
+        )
+        .unwrap_err();
+
+        assert_eq!(error, ChunkedColumnError::EqualAcrossSeam { chunk: 2 });
+        assert_eq!(error.chunk(), 2);
+    }
+
+    #[test]
+    fn with_seam_policy_accepts_strict_seams_when_strict() {
+        let first = [1, 2, 2];
+        let second = [3, 3];
+        let column = ChunkedColumn::with_seam_policy(
+            vec![&first[..], &second],
+            SeamPolicy::RequireStrictSeams,
+        )
+        .unwrap();
+
+        assert_eq!(column.equal_range(&2), 1..3);
+        assert_eq!(column.equal_range(&3), 3..5);
+    }
+
+    #[test]
+    fn equal_range_merges_run_spanning_three_chunks_when_lenient() {
+        let arr = [1, 2, 2, 2, 2, 2, 3];
+        let column = ChunkedColumn::with_seam_policy(
+            vec![&arr[..3], &arr[3..4], &arr[4..]],
+            SeamPolicy::AllowEqualAcrossSeams,
+        )
+        .unwrap();
+
+        assert_eq!(column.search(&2), Some((0, 1)));
+
+        for target in 0..5 {
+            assert_eq!(column.rank(&target), ranks::leftmost_rank(&target, &arr));
+            assert_eq!(
+                column.equal_range(&target),
+                ranks::equal_range(&target, &arr)
+            );
+        }
+    }
+}
@@ -0,0 +1,238 @@
+//! Migration shim mirroring the search methods of `std` slices
+//!
+//! [`StdLikeSearch`] has the same method names, signatures and semantics as the slice methods, but runs on this
+//! crate's kernels. Like the `std` methods, it never checks that the slice is sorted: on an unsorted slice the result
+//! is unspecified, but the call never panics.
+//!
+//! Inherent methods take precedence over trait methods, so `data.binary_search(&x)` keeps calling `std` even with
+//! the trait in scope. Call sites switch over by naming the trait instead of the type:
+//!
+//! ```
+//! use binary_search::compat::StdLikeSearch;
+//!
+//! let data = vec![1, 2, 4, 4, 4, 5, 6, 7];
+//!
+//! assert_eq!(StdLikeSearch::binary_search(&data[..], &5), Ok(5));
+//! assert_eq!(StdLikeSearch::binary_search(&data[..], &3), Err(2));
+//! assert_eq!(StdLikeSearch::partition_point(&data[..], |&x| x < 5), 5);
+//! ```
+//!
+//! With the `compat-delegate` feature, every method calls the matching `std` method instead, so the two can be
+//! compared without touching the call sites.
+
+#[cfg(not(feature = "compat-delegate"))]
+use crate::core;
+use ::core::cmp::Ordering;
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl<T> Sealed for [T] {}
+}
+
+/// The search methods of `std` slices, implemented by this crate
+///
+/// The trait is sealed: it is only implemented for `[T]` and cannot be implemented outside this crate.
+pub trait StdLikeSearch<T>: sealed::Sealed {
+    /// Binary searches the slice for `x`, like [`slice::binary_search`].
+    ///
+    /// Returns `Ok` with the index of a matching element, or `Err` with the index where `x` could be inserted while
+    /// keeping the slice sorted. When several elements match, any one of their indices may be returned.
+    fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord;
+
+    /// Binary searches the slice with a comparator function, like [`slice::binary_search_by`].
+    ///
+    /// `f` returns the ordering of an element relative to the target. Returns `Ok` with the index of an element
+    /// ordered `Equal`, or `Err` with the index of the first element ordered `Greater`.
+    fn binary_search_by<'a, F>(&'a self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&'a T) -> Ordering,
+        T: 'a;
+
+    /// Binary searches the slice with a key extraction function, like [`slice::binary_search_by_key`].
+    ///
+    /// The slice must be sorted by the key, e.g. with `sort_by_key` using the same function.
+    fn binary_search_by_key<'a, B, F>(&'a self, b: &B, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&'a T) -> B,
+        B: Ord,
+        T: 'a;
+
+    /// Returns the index of the partition point according to `pred`, like [`slice::partition_point`].
+    ///
+    /// The slice must be partitioned so that every element for which `pred` holds comes before every element for
+    /// which it does not. The result is the index of the first element for which `pred` does not hold.
+    fn partition_point<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool;
+}
+
+impl<T> StdLikeSearch<T> for [T] {
+    fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        StdLikeSearch::binary_search_by(self, |element| element.cmp(x))
+    }
+
+    #[cfg(not(feature = "compat-delegate"))]
+    fn binary_search_by<'a, F>(&'a self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&'a T) -> Ordering,
+        T: 'a,
+    {
+        let index = core::partition_point(self, |element| f(element) == Ordering::Less);
+
+        match self.get(index) {
+            Some(element) if f(element) == Ordering::Equal => Ok(index),
+            _ => Err(index),
+        }
+    }
+
+    #[cfg(feature = "compat-delegate")]
+    fn binary_search_by<'a, F>(&'a self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&'a T) -> Ordering,
+        T: 'a,
+    {
+        <[T]>::binary_search_by(self, f)
+    }
+
+    fn binary_search_by_key<'a, B, F>(&'a self, b: &B, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&'a T) -> B,
+        B: Ord,
+        T: 'a,
+    {
+        StdLikeSearch::binary_search_by(self, |element| f(element).cmp(b))
+    }
+
+    #[cfg(not(feature = "compat-delegate"))]
+    fn partition_point<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        core::partition_point(self, pred)
+    }
+
+    #[cfg(feature = "compat-delegate")]
+    fn partition_point<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        <[T]>::partition_point(self, pred)
+    }
+}
+
+#[cfg(test)]
+mod compat_tests {
+    use super::StdLikeSearch;
+    #[cfg(feature = "std")]
+    use crate::test_rng::Rng;
+
+    #[cfg(feature = "std")]
+    fn random_sorted(rng: &mut Rng) -> Vec<u32> {
+        let len = rng.below(50) as usize;
+        let range = 1 + rng.below(80);
+        let mut arr: Vec<u32> = (0..len).map(|_| rng.below(range) as u32).collect();
+
+        arr.sort_unstable();
+        arr
+    }
+
+    /// `std` may return the index of any matching element, so only the matched element is compared on `Ok`
+    #[cfg(feature = "std")]
+    fn assert_same_outcome(
+        arr: &[u32],
+        found: Result<usize, usize>,
+        expected: Result<usize, usize>,
+    ) {
+        match (found, expected) {
+            (Ok(index), Ok(expected_index)) => assert_eq!(arr[index], arr[expected_index]),
+            _ => assert_eq!(found, expected),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn binary_search_matches_std() {
+        let mut rng = Rng::new(0xc0a7);
+
+        for _ in 0..300 {
+            let arr = random_sorted(&mut rng);
+
+            for target in 0..82 {
+                assert_same_outcome(
+                    &arr,
+                    StdLikeSearch::binary_search(&arr[..], &target),
+                    arr.binary_search(&target),
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn binary_search_by_matches_std() {
+        let mut rng = Rng::new(0xb1b1);
+
+        for _ in 0..300 {
+            let arr = random_sorted(&mut rng);
+
+            for target in 0..82 {
+                assert_same_outcome(
+                    &arr,
+                    StdLikeSearch::binary_search_by(&arr[..], |element| element.cmp(&target)),
+                    arr.binary_search_by(|element| element.cmp(&target)),
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn binary_search_by_key_matches_std() {
+        let mut rng = Rng::new(0x4e75);
+
+        for _ in 0..300 {
+            let keys = random_sorted(&mut rng);
+            let pairs: Vec<(u32, usize)> = keys.iter().copied().zip(0..).collect();
+
+            for key in 0..82 {
+                let found = StdLikeSearch::binary_search_by_key(&pairs[..], &key, |&(key, _)| key);
+                let expected = pairs.binary_search_by_key(&key, |&(key, _)| key);
+
+                assert_same_outcome(&keys, found, expected);
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn partition_point_matches_std() {
+        let mut rng = Rng::new(0x9a27);
+
+        for _ in 0..300 {
+            let arr = random_sorted(&mut rng);
+
+            for cutoff in 0..82 {
+                assert_eq!(
+                    StdLikeSearch::partition_point(&arr[..], |&element| element < cutoff),
+                    arr.partition_point(|&element| element < cutoff)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn methods_do_not_panic_on_unsorted_slices() {
+        let arr = [5, 1, 4, 2, 3];
+
+        for target in 0..7 {
+            let _ = StdLikeSearch::binary_search(&arr[..], &target);
+            let _ = StdLikeSearch::partition_point(&arr[..], |&element| element < target);
+        }
+    }
+}
@@ -0,0 +1,153 @@
+//! Searches usable in const contexts, e.g. for resolving indices into compile-time lookup tables
+//!
+//! Trait methods cannot be called in const functions on stable Rust, so these functions are written for concrete
+//! integer types and compare with the primitive operators. Their sortedness checks are const as well, so an unsorted
+//! table fails the build when searched in a const item.
+
+use crate::utils;
+
+macro_rules! const_search {
+    ($int:ident, $is_sorted:ident, $binary_search:ident) => {
+        /// Checks if `arr` is sorted, in a const context.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("use binary_search::consts::", stringify!($is_sorted), ";")]
+        ///
+        #[doc = concat!("const SORTED: bool = ", stringify!($is_sorted), "(&[1, 2, 2, 5]);")]
+        ///
+        /// assert!(SORTED);
+        #[doc = concat!("assert!(!", stringify!($is_sorted), "(&[2, 1]));")]
+        /// ```
+        pub const fn $is_sorted(arr: &[$int]) -> bool {
+            let mut index = 1;
+
+            while index < arr.len() {
+                if arr[index - 1] > arr[index] {
+                    return false;
+                }
+
+                index += 1;
+            }
+
+            true
+        }
+
+        /// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) on `arr` in order to find
+        /// the index of `target`, in a const context.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("use binary_search::consts::", stringify!($binary_search), ";")]
+        ///
+        #[doc = concat!("const TABLE: [", stringify!($int), "; 6] = [2, 3, 5, 7, 11, 13];")]
+        #[doc = concat!("const SEVEN: Option<usize> = ", stringify!($binary_search), "(7, &TABLE);")]
+        #[doc = concat!("const EIGHT: Option<usize> = ", stringify!($binary_search), "(8, &TABLE);")]
+        ///
+        /// assert_eq!(SEVEN, Some(3));
+        /// assert_eq!(EIGHT, None);
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// The function panics if the array is not sorted. In a const item, this is a compile error.
+        pub const fn $binary_search(target: $int, arr: &[$int]) -> Option<usize> {
+            if !$is_sorted(arr) {
+                panic!("Binary search encountered an array that is note sorted");
+            }
+
+            let mut left = 0;
+            let mut right = arr.len();
+
+            while left < right {
+                let middle = utils::midpoint(left, right);
+
+                if arr[middle] == target {
+                    return Some(middle);
+                } else if arr[middle] > target {
+                    right = middle;
+                } else {
+                    left = middle + 1;
+                }
+            }
+
+            None
+        }
+    };
+}
+
+const_search!(u32, is_sorted_u32, binary_search_u32);
+const_search!(i32, is_sorted_i32, binary_search_i32);
+const_search!(u64, is_sorted_u64, binary_search_u64);
+
+#[cfg(test)]
+mod consts_tests {
+    use super::{
+        binary_search_i32, binary_search_u32, binary_search_u64, is_sorted_i32, is_sorted_u32,
+        is_sorted_u64,
+    };
+    #[cfg(feature = "std")]
+    use crate::binary_search;
+
+    const TABLE: [u32; 128] = {
+        let mut table = [0; 128];
+        let mut index = 0;
+
+        while index < table.len() {
+            table[index] = index as u32 * 3;
+            index += 1;
+        }
+
+        table
+    };
+    const FOUND: Option<usize> = binary_search_u32(300, &TABLE);
+
+    #[test]
+    fn binary_search_u32_resolves_in_const_item() {
+        assert_eq!(FOUND, Some(100));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn const_searches_match_binary_search() {
+        let unsigned: Vec<u64> = (0..40).map(|element| element * 2).collect();
+        let signed: Vec<i32> = (-20..20).map(|element| element * 2).collect();
+
+        for target in 0..82 {
+            assert_eq!(
+                binary_search_u32(target as u32, &TABLE[..40]),
+                binary_search(&(target as u32), &TABLE[..40])
+            );
+            assert_eq!(
+                binary_search_u64(target, &unsigned),
+                binary_search(&target, &unsigned)
+            );
+            assert_eq!(
+                binary_search_i32(target as i32 - 41, &signed),
+                binary_search(&(target as i32 - 41), &signed)
+            );
+        }
+    }
+
+    #[test]
+    fn const_searches_return_none_for_empty_arr() {
+        assert_eq!(binary_search_u32(1, &[]), None);
+        assert_eq!(binary_search_i32(1, &[]), None);
+        assert_eq!(binary_search_u64(1, &[]), None);
+    }
+
+    #[test]
+    fn is_sorted_detects_unsorted_pairs() {
+        assert!(is_sorted_u32(&[]));
+        assert!(is_sorted_i32(&[-3, -3, 0]));
+        assert!(!is_sorted_u64(&[1, 3, 2]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Binary search encountered an array that is note sorted")]
+    fn binary_search_u32_panics_when_arr_is_not_sorted() {
+        binary_search_u32(5, &[1, 3, 2, 5]);
+    }
+}
@@ -1,22 +1,357 @@
-use std::cmp::Ordering;
+use crate::utils;
+use core::{
+    cmp::{min, Ordering},
+    ops::{Bound, Range, RangeBounds},
+};
 
 /// Core implementation of binary search with no additional checks
+///
+/// The search narrows the half-open window `left..right`, so it is defined for empty arrays and for targets outside
+/// the range of the elements.
 pub fn binary_search<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    binary_search_by(arr, |element| element.cmp(target))
+}
+
+/// Core implementation of exponential search with no additional checks
+pub fn exponential_search<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    let arr_size = arr.len();
+    let mut bound = 1;
+
+    while bound < arr_size && &arr[bound] < target {
+        bound = bound.saturating_mul(2);
+    }
+
+    let left_bound = bound / 2;
+    let right_bound = min(bound.saturating_add(1), arr_size);
+    let slice_index = binary_search(target, &arr[left_bound..right_bound])?;
+    let index = slice_index + left_bound;
+
+    Some(index)
+}
+
+/// Core implementation of binary search driven by a comparator, with no additional checks
+///
+/// `compare` returns the ordering of an element relative to the searched one.
+pub fn binary_search_by<T, F>(arr: &[T], mut compare: F) -> Option<usize>
+where
+    F: FnMut(&T) -> Ordering,
+{
+    let mut left = 0;
+    let mut right = arr.len();
+
+    while left < right {
+        let middle = utils::midpoint(left, right);
+        // SAFETY: `left < right <= arr.len()` holds on every iteration, and `midpoint` rounds down, so `middle < right`
+        let element = unsafe { element(arr, middle) };
+
+        match compare(element) {
+            Ordering::Equal => return Some(middle),
+            Ordering::Greater => right = middle,
+            Ordering::Less => left = middle + 1,
+        }
+    }
+
+    None
+}
+
+/// Core implementation of the leftmost rank with no additional checks
+///
+/// Returns the number of elements smaller than `target`.
+pub fn lower_bound<T>(target: &T, arr: &[T]) -> usize
+where
+    T: Ord,
+{
+    partition_point(arr, |element| element < target)
+}
+
+/// Core implementation of the rightmost rank with no additional checks
+///
+/// Returns the number of elements smaller than or equal to `target`.
+pub fn upper_bound<T>(target: &T, arr: &[T]) -> usize
+where
+    T: Ord,
+{
+    partition_point(arr, |element| element <= target)
+}
+
+/// Core implementation of the floor with no additional checks
+///
+/// Returns the index of the last element smaller than or equal to `target`.
+pub fn floor<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    upper_bound(target, arr).checked_sub(1)
+}
+
+/// Core implementation of the ceiling with no additional checks
+///
+/// Returns the index of the first element greater than or equal to `target`.
+pub fn ceil<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    let rank = lower_bound(target, arr);
+
+    if rank < arr.len() {
+        Some(rank)
+    } else {
+        None
+    }
+}
+
+/// Core implementation of range queries with no additional checks
+///
+/// Returns the indices of the elements within `range`, or an empty range if `range` is reversed.
+pub fn range_indices<T, R>(arr: &[T], range: R) -> Range<usize>
+where
+    T: Ord,
+    R: RangeBounds<T>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(bound) => lower_bound(bound, arr),
+        Bound::Excluded(bound) => upper_bound(bound, arr),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(bound) => upper_bound(bound, arr),
+        Bound::Excluded(bound) => lower_bound(bound, arr),
+        Bound::Unbounded => arr.len(),
+    };
+
+    start..end.max(start)
+}
+
+/// Core implementation of the partition point with no additional checks
+///
+/// Returns the index of the first element for which `pred` is false, assuming all the elements for which it is true
+/// come first.
+pub fn partition_point<'a, T, P>(arr: &'a [T], mut pred: P) -> usize
+where
+    P: FnMut(&'a T) -> bool,
+{
+    let mut left = 0;
+    let mut right = arr.len();
+
+    while left < right {
+        let middle = utils::midpoint(left, right);
+        // SAFETY: `left < right <= arr.len()` holds on every iteration, and `midpoint` rounds down, so `middle < right`
+        let element = unsafe { element(arr, middle) };
+
+        if pred(element) {
+            left = middle + 1;
+        } else {
+            right = middle;
+        }
+    }
+
+    left
+}
+
+/// Core implementation of binary search issuing prefetches for both possible next probes, with no additional checks
+///
+/// The probes and the result are the same as [`binary_search`]. On x86 and x86_64 both candidate midpoints of the
+/// next iteration are prefetched before the comparison, so whichever half is kept has its next probe on the way from
+/// memory. On other architectures this is a plain binary search.
+#[cfg(feature = "prefetch")]
+pub fn binary_search_prefetch<T>(target: &T, arr: &[T]) -> Option<usize>
 where
     T: Ord,
 {
     let mut left = 0;
-    let mut right = arr.len() - 1;
+    let mut right = arr.len();
+
+    while left < right {
+        let middle = utils::midpoint(left, right);
 
-    while left <= right {
-        let middle = (left + right) / 2;
+        prefetch(arr, utils::midpoint(left, middle));
+        prefetch(arr, utils::midpoint(middle + 1, right));
 
-        match arr[middle].cmp(target) {
+        // SAFETY: `left < right <= arr.len()` holds on every iteration, and `midpoint` rounds down, so `middle < right`
+        let element = unsafe { element(arr, middle) };
+
+        match element.cmp(target) {
             Ordering::Equal => return Some(middle),
-            Ordering::Greater => right = middle - 1,
+            Ordering::Greater => right = middle,
             Ordering::Less => left = middle + 1,
         }
     }
 
     None
 }
+
+/// Hints the processor to start loading the element of `arr` at `index` into the cache
+///
+/// `index` may be out of bounds: the address is only computed, never dereferenced.
+#[cfg(feature = "prefetch")]
+#[inline(always)]
+fn prefetch<T>(arr: &[T], index: usize) {
+    let address = arr.as_ptr().wrapping_add(index) as *const i8;
+
+    #[cfg(target_arch = "x86_64")]
+    // SAFETY: prefetching never faults, whatever the address, and SSE is part of the x86_64 baseline
+    unsafe {
+        ::core::arch::x86_64::_mm_prefetch(address, ::core::arch::x86_64::_MM_HINT_T0)
+    };
+
+    #[cfg(all(target_arch = "x86", target_feature = "sse"))]
+    // SAFETY: prefetching never faults, whatever the address, and SSE is enabled
+    unsafe {
+        ::core::arch::x86::_mm_prefetch(address, ::core::arch::x86::_MM_HINT_T0)
+    };
+
+    #[cfg(not(any(
+        target_arch = "x86_64",
+        all(target_arch = "x86", target_feature = "sse")
+    )))]
+    let _ = address;
+}
+
+/// Returns the element of `arr` at `index` without a bounds check, so that the search loops above do not pay for one
+/// on every probe
+///
+/// With the `checked-indexing` feature the access is bounds checked instead, for comparing against the unchecked
+/// build.
+///
+/// # Safety
+///
+/// `index` must be smaller than `arr.len()`.
+#[inline(always)]
+unsafe fn element<T>(arr: &[T], index: usize) -> &T {
+    debug_assert!(
+        index < arr.len(),
+        "Search probed index {} of an array of length {}",
+        index,
+        arr.len()
+    );
+
+    #[cfg(not(feature = "checked-indexing"))]
+    {
+        // SAFETY: the caller guarantees that `index` is in bounds
+        unsafe { arr.get_unchecked(index) }
+    }
+
+    #[cfg(feature = "checked-indexing")]
+    {
+        &arr[index]
+    }
+}
+
+#[cfg(test)]
+mod core_tests {
+    use super::{binary_search, binary_search_by, exponential_search, lower_bound, upper_bound};
+
+    /// Arrays of up to two elements, each searched below, at and between every element, and above
+    const SMALL_ARRS: [&[i32]; 5] = [&[], &[2], &[2, 4], &[2, 2], &[4, 4]];
+
+    #[test]
+    fn searches_are_total_over_small_arrs() {
+        for arr in SMALL_ARRS.iter() {
+            for target in 1..=5 {
+                let expected = arr.iter().position(|&element| element == target);
+
+                assert_eq!(binary_search(&target, arr).is_some(), expected.is_some());
+                assert_eq!(
+                    exponential_search(&target, arr).is_some(),
+                    expected.is_some()
+                );
+                assert_eq!(
+                    binary_search_by(arr, |element| element.cmp(&target)).is_some(),
+                    expected.is_some()
+                );
+
+                if let Some(found) = binary_search(&target, arr) {
+                    assert_eq!(arr[found], target);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bounds_are_total_over_small_arrs() {
+        for arr in SMALL_ARRS.iter() {
+            for target in 1..=5 {
+                let below = arr.iter().filter(|&&element| element < target).count();
+                let at_or_below = arr.iter().filter(|&&element| element <= target).count();
+
+                assert_eq!(lower_bound(&target, arr), below);
+                assert_eq!(upper_bound(&target, arr), at_or_below);
+            }
+        }
+    }
+
+    #[test]
+    fn binary_search_returns_none_if_target_below_minimum() {
+        let target = 0;
+        let arr = [1, 2, 3];
+        let found = binary_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn exponential_search_returns_none_if_target_below_minimum() {
+        let target = 0;
+        let arr = [1, 2, 3];
+        let found = exponential_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn searches_probe_only_in_bounds_indices() {
+        // Small enough to run under `cargo +nightly miri test`, which checks the unchecked accesses for UB
+        for len in 0..12 {
+            let arr: Vec<Box<i32>> = (0..len).map(|element| Box::new(element * 2)).collect();
+
+            for target in -1..len * 2 + 1 {
+                let target = Box::new(target);
+                let expected = arr.iter().position(|element| *element == target);
+
+                assert_eq!(binary_search(&target, &arr), expected);
+                assert_eq!(
+                    lower_bound(&target, &arr),
+                    arr.partition_point(|element| *element < target)
+                );
+                assert_eq!(
+                    upper_bound(&target, &arr),
+                    arr.partition_point(|element| *element <= target)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn exponential_search_does_not_overflow_the_bound_on_maximal_lengths() {
+        use core::{cmp::Ordering, ptr::NonNull, slice};
+
+        /// Zero-sized element ordered below every target, so that the gallop runs until the bound passes the length
+        #[derive(Debug, PartialEq, Eq)]
+        struct Below;
+
+        impl PartialOrd for Below {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Below {
+            fn cmp(&self, _: &Self) -> Ordering {
+                Ordering::Less
+            }
+        }
+
+        // SAFETY: a slice of zero-sized elements may have any length, and a dangling pointer is valid for it
+        let arr: &[Below] =
+            unsafe { slice::from_raw_parts(NonNull::dangling().as_ptr(), usize::MAX) };
+
+        assert_eq!(exponential_search(&Below, arr), None);
+    }
+}
@@ -0,0 +1,414 @@
+//! Copy-on-write sorted data for many concurrent readers and rare writers
+
+use crate::{
+    core,
+    memory::{FieldUsage, MemoryBreakdown, MemoryUsage},
+};
+use std::{ops::Range, sync::Arc};
+
+/// The overlay size past which a [`CowSorted`] compacts itself when no threshold is given
+pub const DEFAULT_OVERLAY_THRESHOLD: usize = 1024;
+
+/// A shared sorted base and a small sorted overlay of pending changes
+///
+/// The logical contents are the base plus `inserts` minus `deletes`, as multisets. Every element of `deletes` has a
+/// matching element in the base, so ranks can be computed layer by layer.
+#[derive(Debug, Clone)]
+struct Layers<T> {
+    /// The sorted elements as of the last compaction
+    base: Arc<Vec<T>>,
+    /// The sorted elements inserted since the last compaction
+    inserts: Vec<T>,
+    /// The sorted elements of the base removed since the last compaction
+    deletes: Vec<T>,
+}
+
+impl<T> Layers<T>
+where
+    T: Ord,
+{
+    /// Returns the number of elements smaller than `target`
+    fn leftmost_rank(&self, target: &T) -> usize {
+        core::lower_bound(target, &self.base) + core::lower_bound(target, &self.inserts)
+            - core::lower_bound(target, &self.deletes)
+    }
+
+    /// Returns the number of elements smaller than or equal to `target`
+    fn upper_bound(&self, target: &T) -> usize {
+        core::upper_bound(target, &self.base) + core::upper_bound(target, &self.inserts)
+            - core::upper_bound(target, &self.deletes)
+    }
+
+    /// Returns the ranks of the elements equal to `target`
+    fn equal_range(&self, target: &T) -> Range<usize> {
+        self.leftmost_rank(target)..self.upper_bound(target)
+    }
+
+    /// Returns the rank of the first element equal to `target`
+    fn search(&self, target: &T) -> Option<usize> {
+        let range = self.equal_range(target);
+
+        if range.is_empty() {
+            None
+        } else {
+            Some(range.start)
+        }
+    }
+
+    /// Returns the number of elements
+    fn len(&self) -> usize {
+        self.base.len() + self.inserts.len() - self.deletes.len()
+    }
+}
+
+impl<T> Layers<T>
+where
+    T: Ord + Clone,
+{
+    /// Merges the overlay into a fresh base
+    fn compact(&mut self) {
+        if self.inserts.is_empty() && self.deletes.is_empty() {
+            return;
+        }
+
+        let mut merged = Vec::with_capacity(self.len());
+        let mut inserts = self.inserts.drain(..).peekable();
+        let mut deletes = self.deletes.drain(..).peekable();
+
+        for element in self.base.iter() {
+            if deletes.peek() == Some(element) {
+                deletes.next();
+                continue;
+            }
+
+            while let Some(insert) = inserts.next_if(|insert| insert < element) {
+                merged.push(insert);
+            }
+
+            merged.push(element.clone());
+        }
+
+        merged.extend(inserts);
+        self.base = Arc::new(merged);
+    }
+}
+
+/// Sorted multiset whose readers take cheap, immutable [`Snapshot`]s
+///
+/// The elements live in a sorted base behind an `Arc`, which is shared with every snapshot and never mutated. Inserts
+/// and removals go to a small sorted overlay instead, and searches combine both layers in O(log n). Once the overlay
+/// holds more than `threshold` changes, it is merged into a fresh base in O(n); snapshots taken earlier keep the base
+/// they were taken with.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::cow::CowSorted;
+///
+/// let mut cow = CowSorted::new(vec![5, 1, 3]);
+/// let snapshot = cow.snapshot();
+///
+/// cow.insert(2);
+/// cow.remove(&5);
+///
+/// assert_eq!(cow.search(&2), Some(1));
+/// assert_eq!(cow.leftmost_rank(&4), 3);
+/// assert!(!cow.contains(&5));
+/// assert!(snapshot.contains(&5));
+/// assert_eq!(snapshot.len(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CowSorted<T> {
+    /// The elements
+    layers: Layers<T>,
+    /// The overlay size past which the overlay is compacted
+    threshold: usize,
+}
+
+impl<T> CowSorted<T>
+where
+    T: Ord + Clone,
+{
+    /// Returns a new `CowSorted` holding the elements of `vec`, compacting past [`DEFAULT_OVERLAY_THRESHOLD`]
+    /// changes.
+    pub fn new(vec: Vec<T>) -> Self {
+        Self::with_threshold(vec, DEFAULT_OVERLAY_THRESHOLD)
+    }
+
+    /// Returns a new `CowSorted` holding the elements of `vec`, compacting past `threshold` changes.
+    pub fn with_threshold(mut vec: Vec<T>, threshold: usize) -> Self {
+        vec.sort();
+
+        Self {
+            layers: Layers {
+                base: Arc::new(vec),
+                inserts: Vec::new(),
+                deletes: Vec::new(),
+            },
+            threshold,
+        }
+    }
+
+    /// Inserts `element`, compacting if the overlay grows past the threshold. O(log n + k) for k pending changes.
+    pub fn insert(&mut self, element: T) {
+        let deleted = core::binary_search_by(&self.layers.deletes, |deleted| deleted.cmp(&element));
+
+        match deleted {
+            Some(index) => {
+                self.layers.deletes.remove(index);
+            }
+            None => {
+                let index = core::upper_bound(&element, &self.layers.inserts);
+
+                self.layers.inserts.insert(index, element);
+            }
+        }
+
+        self.compact_past_threshold();
+    }
+
+    /// Removes one element equal to `target`, returning whether one was present. Compacts if the overlay grows past
+    /// the threshold.
+    pub fn remove(&mut self, target: &T) -> bool {
+        let layers = &mut self.layers;
+
+        if let Some(index) = core::binary_search_by(&layers.inserts, |insert| insert.cmp(target)) {
+            layers.inserts.remove(index);
+
+            return true;
+        }
+
+        let in_base =
+            core::upper_bound(target, &layers.base) - core::lower_bound(target, &layers.base);
+        let start = core::lower_bound(target, &layers.deletes);
+        let end = core::upper_bound(target, &layers.deletes);
+
+        if end - start == in_base {
+            return false;
+        }
+
+        layers.deletes.insert(end, target.clone());
+        self.compact_past_threshold();
+
+        true
+    }
+
+    /// Merges the pending changes into a fresh base. O(n).
+    pub fn compact(&mut self) {
+        self.layers.compact();
+    }
+
+    /// Returns an immutable view of the current elements, sharing the base and copying the overlay.
+    pub fn snapshot(&self) -> Snapshot<T> {
+        Snapshot {
+            layers: self.layers.clone(),
+        }
+    }
+
+    /// Returns the number of pending changes
+    pub fn overlay_len(&self) -> usize {
+        self.layers.inserts.len() + self.layers.deletes.len()
+    }
+
+    /// Returns the leftmost rank of `target`.
+    pub fn leftmost_rank(&self, target: &T) -> usize {
+        self.layers.leftmost_rank(target)
+    }
+
+    /// Returns the rightmost rank of `target`, or 0 if no element is smaller than or equal to `target`.
+    pub fn rightmost_rank(&self, target: &T) -> usize {
+        self.layers.upper_bound(target).saturating_sub(1)
+    }
+
+    /// Returns the half-open range of ranks of the elements equal to `target`.
+    pub fn equal_range(&self, target: &T) -> Range<usize> {
+        self.layers.equal_range(target)
+    }
+
+    /// Returns the rank of the first element equal to `target`.
+    pub fn search(&self, target: &T) -> Option<usize> {
+        self.layers.search(target)
+    }
+
+    /// Returns whether an element equal to `target` is present.
+    pub fn contains(&self, target: &T) -> bool {
+        self.layers.search(target).is_some()
+    }
+
+    /// Returns the number of elements
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Returns whether there are no elements
+    pub fn is_empty(&self) -> bool {
+        self.layers.len() == 0
+    }
+
+    /// Compacts the overlay if it holds more than `threshold` changes
+    fn compact_past_threshold(&mut self) {
+        if self.overlay_len() > self.threshold {
+            self.compact();
+        }
+    }
+}
+
+impl<T> MemoryUsage for CowSorted<T> {
+    /// Reports the base in full, even though it may be shared with snapshots
+    fn memory_bytes(&self) -> MemoryBreakdown {
+        MemoryBreakdown::new(self)
+            .with(FieldUsage::of_vec("base", &self.layers.base))
+            .with(FieldUsage::of_vec("inserts", &self.layers.inserts))
+            .with(FieldUsage::of_vec("deletes", &self.layers.deletes))
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.layers.inserts.shrink_to_fit();
+        self.layers.deletes.shrink_to_fit();
+    }
+}
+
+/// Immutable view of a [`CowSorted`] at the time it was taken
+///
+/// Later changes and compactions of the `CowSorted` are not visible.
+#[derive(Debug, Clone)]
+pub struct Snapshot<T> {
+    /// The elements at the time of the snapshot
+    layers: Layers<T>,
+}
+
+impl<T> Snapshot<T>
+where
+    T: Ord,
+{
+    /// Returns the leftmost rank of `target`.
+    pub fn leftmost_rank(&self, target: &T) -> usize {
+        self.layers.leftmost_rank(target)
+    }
+
+    /// Returns the rightmost rank of `target`, or 0 if no element is smaller than or equal to `target`.
+    pub fn rightmost_rank(&self, target: &T) -> usize {
+        self.layers.upper_bound(target).saturating_sub(1)
+    }
+
+    /// Returns the half-open range of ranks of the elements equal to `target`.
+    pub fn equal_range(&self, target: &T) -> Range<usize> {
+        self.layers.equal_range(target)
+    }
+
+    /// Returns the rank of the first element equal to `target`.
+    pub fn search(&self, target: &T) -> Option<usize> {
+        self.layers.search(target)
+    }
+
+    /// Returns whether an element equal to `target` is present.
+    pub fn contains(&self, target: &T) -> bool {
+        self.layers.search(target).is_some()
+    }
+
+    /// Returns the number of elements
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Returns whether there are no elements
+    pub fn is_empty(&self) -> bool {
+        self.layers.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod cow_tests {
+    use super::CowSorted;
+    use crate::core;
+    use crate::test_rng::Rng;
+
+    #[test]
+    fn snapshots_are_unaffected_by_later_changes_and_compaction() {
+        let mut cow = CowSorted::new(vec![10, 20, 30, 40]);
+
+        cow.insert(25);
+
+        let snapshot = cow.snapshot();
+
+        cow.remove(&10);
+        cow.insert(35);
+        cow.compact();
+        cow.remove(&25);
+
+        assert_eq!(snapshot.len(), 5);
+        assert_eq!(snapshot.search(&10), Some(0));
+        assert_eq!(snapshot.leftmost_rank(&30), 3);
+        assert!(snapshot.contains(&25));
+        assert!(!snapshot.contains(&35));
+
+        assert_eq!(cow.len(), 4);
+        assert_eq!(cow.leftmost_rank(&30), 1);
+        assert!(!cow.contains(&25));
+        assert!(cow.contains(&35));
+    }
+
+    #[test]
+    fn overlay_past_threshold_is_compacted() {
+        let mut cow = CowSorted::with_threshold(vec![1, 2, 3], 3);
+
+        cow.insert(4);
+        cow.insert(5);
+        cow.remove(&1);
+
+        assert_eq!(cow.overlay_len(), 3);
+
+        cow.insert(6);
+
+        assert_eq!(cow.overlay_len(), 0);
+        assert_eq!(cow.len(), 5);
+        assert_eq!(cow.equal_range(&4), 2..3);
+    }
+
+    #[test]
+    fn remove_returns_false_if_every_copy_already_removed() {
+        let mut cow = CowSorted::new(vec![1, 2, 2, 3]);
+
+        assert!(cow.remove(&2));
+        assert!(cow.remove(&2));
+        assert!(!cow.remove(&2));
+        assert!(!cow.remove(&4));
+        assert_eq!(cow.equal_range(&2), 1..1);
+    }
+
+    #[test]
+    fn ranks_match_model_with_interleaved_inserts_and_removals() {
+        let mut rng = Rng::new(0x853c_49e6_748f_ea9b);
+        let initial: Vec<u64> = (0..200).map(|_| rng.below(100)).collect();
+        let mut model = initial.clone();
+        let mut cow = CowSorted::with_threshold(initial, 16);
+
+        model.sort();
+
+        for _ in 0..1_000 {
+            let value = rng.below(100);
+
+            if rng.below(2) == 0 {
+                cow.insert(value);
+                model.insert(core::upper_bound(&value, &model), value);
+            } else {
+                let position = core::binary_search_by(&model, |element| element.cmp(&value));
+
+                if let Some(index) = position {
+                    model.remove(index);
+                }
+
+                assert_eq!(cow.remove(&value), position.is_some());
+            }
+
+            let probe = rng.below(101);
+
+            assert_eq!(cow.len(), model.len());
+            assert_eq!(cow.leftmost_rank(&probe), core::lower_bound(&probe, &model));
+            assert_eq!(
+                cow.equal_range(&probe),
+                core::lower_bound(&probe, &model)..core::upper_bound(&probe, &model)
+            );
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! Hinted search resuming near the previous result, for temporally correlated queries
+
+use crate::{core, error::NotSortedError};
+
+/// A sorted slice remembering the position of the last search
+///
+/// Each search gallops outward from the last position, doubling its step until it brackets the target, before
+/// finishing with a binary search inside the bracket. A target `d` positions away from the previous result costs
+/// O(log d) comparisons instead of O(log n), in either direction.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::cursor::SearchCursor;
+///
+/// let arr = [1, 3, 5, 7, 9, 11, 13];
+/// let mut cursor = SearchCursor::new(&arr).unwrap();
+///
+/// assert_eq!(cursor.find(&9), Some(4));
+/// assert_eq!(cursor.find(&11), Some(5));
+/// assert_eq!(cursor.find(&4), None);
+/// assert_eq!(cursor.find(&3), Some(1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SearchCursor<'a, T> {
+    arr: &'a [T],
+    /// The position the next search starts from
+    hint: Option<usize>,
+}
+
+impl<'a, T> SearchCursor<'a, T>
+where
+    T: Ord,
+{
+    /// Returns a new `SearchCursor` with no hint if `arr` is sorted.
+    pub fn new(arr: &'a [T]) -> Result<Self, NotSortedError> {
+        NotSortedError::check(arr)?;
+
+        Ok(Self { arr, hint: None })
+    }
+
+    /// Returns the index of the leftmost occurrence of `target`, searching outward from the previous result.
+    ///
+    /// The hint moves to the position of `target`, or to where it would be inserted when it is missing.
+    pub fn find(&mut self, target: &T) -> Option<usize> {
+        let index = match self.hint {
+            Some(hint) if self.arr[hint] < *target => self.gallop_right(target, hint),
+            Some(hint) => self.gallop_left(target, hint),
+            None => core::lower_bound(target, self.arr),
+        };
+
+        if !self.arr.is_empty() {
+            self.hint = Some(index.min(self.arr.len() - 1));
+        }
+
+        if index < self.arr.len() && self.arr[index] == *target {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    /// Forgets the hint, so that the next search starts from the middle of the slice.
+    pub fn reset(&mut self) {
+        self.hint = None;
+    }
+
+    /// Returns the position the next search starts from, if any.
+    pub fn hint(&self) -> Option<usize> {
+        self.hint
+    }
+
+    /// Returns the underlying slice
+    pub fn as_slice(&self) -> &'a [T] {
+        self.arr
+    }
+
+    /// Returns the lower bound of `target`, knowing that `arr[hint] < target`.
+    fn gallop_right(&self, target: &T, hint: usize) -> usize {
+        let mut below = hint;
+        let mut step = 1;
+
+        loop {
+            let probe = hint.saturating_add(step);
+
+            if probe >= self.arr.len() {
+                return below + 1 + core::lower_bound(target, &self.arr[below + 1..]);
+            }
+
+            if self.arr[probe] >= *target {
+                return below + 1 + core::lower_bound(target, &self.arr[below + 1..probe]);
+            }
+
+            below = probe;
+            step *= 2;
+        }
+    }
+
+    /// Returns the lower bound of `target`, knowing that `arr[hint] >= target`.
+    fn gallop_left(&self, target: &T, hint: usize) -> usize {
+        let mut at_or_above = hint;
+        let mut step = 1;
+
+        loop {
+            if step > hint {
+                return core::lower_bound(target, &self.arr[..at_or_above]);
+            }
+
+            let probe = hint - step;
+
+            if self.arr[probe] < *target {
+                return probe + 1 + core::lower_bound(target, &self.arr[probe + 1..at_or_above]);
+            }
+
+            at_or_above = probe;
+            step *= 2;
+        }
+    }
+}
+
+#[cfg(test)]
+mod cursor_tests {
+    use super::SearchCursor;
+    use crate::binary_search_first;
+    #[cfg(feature = "std")]
+    use core::cell::Cell;
+    #[cfg(feature = "std")]
+    use core::cmp::Ordering;
+
+    /// Value counting every comparison made between values sharing the same counter
+    #[cfg(feature = "std")]
+    #[derive(Debug)]
+    struct Counted<'c> {
+        value: i32,
+        comparisons: &'c Cell<usize>,
+    }
+
+    #[cfg(feature = "std")]
+    impl PartialEq for Counted<'_> {
+        fn eq(&self, other: &Self) -> bool {
+            self.cmp(other) == Ordering::Equal
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl Eq for Counted<'_> {}
+
+    #[cfg(feature = "std")]
+    impl PartialOrd for Counted<'_> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl Ord for Counted<'_> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.comparisons.set(self.comparisons.get() + 1);
+            self.value.cmp(&other.value)
+        }
+    }
+
+    #[test]
+    fn new_rejects_unsorted_arr() {
+        let arr = [1, 3, 2];
+        let error = SearchCursor::new(&arr).unwrap_err();
+
+        assert_eq!(error.index(), 2);
+    }
+
+    #[test]
+    fn find_matches_binary_search_first_for_increasing_queries() {
+        let arr = [1, 2, 2, 4, 4, 4, 4, 7, 9, 9, 12];
+        let mut cursor = SearchCursor::new(&arr).unwrap();
+
+        for target in 0..14 {
+            assert_eq!(cursor.find(&target), binary_search_first(&target, &arr));
+        }
+    }
+
+    #[test]
+    fn find_matches_binary_search_first_for_decreasing_queries() {
+        let arr = [1, 2, 2, 4, 4, 4, 4, 7, 9, 9, 12];
+        let mut cursor = SearchCursor::new(&arr).unwrap();
+
+        for target in (0..14).rev() {
+            assert_eq!(cursor.find(&target), binary_search_first(&target, &arr));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn find_matches_binary_search_first_for_jumping_queries() {
+        let arr: Vec<i32> = (0..200).map(|element| element / 3 * 2).collect();
+        let mut cursor = SearchCursor::new(&arr).unwrap();
+
+        for target in [5, 130, 0, 131, 132, 1, 140, -1, 200, 60, 61, 59].iter() {
+            assert_eq!(cursor.find(target), binary_search_first(target, &arr));
+        }
+    }
+
+    #[test]
+    fn find_moves_hint_to_insertion_point_of_missing_target() {
+        let arr = [1, 3, 5, 7];
+        let mut cursor = SearchCursor::new(&arr).unwrap();
+
+        assert_eq!(cursor.find(&4), None);
+        assert_eq!(cursor.hint(), Some(2));
+        assert_eq!(cursor.find(&8), None);
+        assert_eq!(cursor.hint(), Some(3));
+    }
+
+    #[test]
+    fn reset_forgets_hint() {
+        let arr = [1, 3, 5, 7];
+        let mut cursor = SearchCursor::new(&arr).unwrap();
+
+        cursor.find(&3);
+        cursor.reset();
+
+        assert_eq!(cursor.hint(), None);
+        assert_eq!(cursor.find(&7), Some(3));
+    }
+
+    #[test]
+    fn find_returns_none_for_empty_arr() {
+        let arr: [i32; 0] = [];
+        let mut cursor = SearchCursor::new(&arr).unwrap();
+
+        assert_eq!(cursor.find(&1), None);
+        assert_eq!(cursor.hint(), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn find_makes_fewer_comparisons_than_cold_searches_on_correlated_queries() {
+        let comparisons = Cell::new(0);
+        let counted = |value| Counted {
+            value,
+            comparisons: &comparisons,
+        };
+        let arr: Vec<Counted> = (0..4096).map(counted).collect();
+        let targets: Vec<Counted> = (0..400)
+            .map(|step| counted(2000 + (step % 7) * 3 - step / 2))
+            .collect();
+        let mut cursor = SearchCursor::new(&arr).unwrap();
+
+        comparisons.set(0);
+
+        for target in &targets {
+            cursor.find(target);
+        }
+
+        let hinted = comparisons.replace(0);
+
+        for target in &targets {
+            cursor.reset();
+            cursor.find(target);
+        }
+
+        let cold = comparisons.get();
+
+        assert!(hinted * 2 < cold, "hinted {} vs cold {}", hinted, cold);
+    }
+}
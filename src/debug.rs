@@ -0,0 +1,323 @@
+//! Diagnostics for searches that miss unexpectedly
+
+use crate::{core, utils};
+use std::fmt::{self, Debug, Display};
+
+/// An element of the searched array referenced by a [`MissExplanation`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Neighbor {
+    /// The index of the element in the array
+    pub index: usize,
+    /// The `Debug` rendering of the element
+    pub rendering: String,
+}
+
+/// The outcome of looking for an element similar to the target
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Similarity {
+    /// No similarity closure was provided
+    NotChecked,
+    /// No element of the array is similar to the target
+    NoneSimilar,
+    /// The similar element closest to the insertion point
+    Closest {
+        /// The similar element
+        neighbor: Neighbor,
+        /// How many ranks away from the insertion point the element is, the immediate neighbors being 1 rank away
+        rank_distance: usize,
+    },
+}
+
+/// Structured explanation of why `target` was not found in an array
+///
+/// The `Display` implementation renders a multi-line report meant to be logged directly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissExplanation {
+    /// The `Debug` rendering of the target
+    pub target: String,
+    /// The index at which the target would have to be inserted to keep the array sorted
+    pub insertion_point: usize,
+    /// The greatest element smaller than the target, if any
+    pub left_neighbor: Option<Neighbor>,
+    /// The smallest element greater than the target, if any
+    pub right_neighbor: Option<Neighbor>,
+    /// The closest element similar to the target
+    pub similarity: Similarity,
+}
+
+impl MissExplanation {
+    /// Returns whether the array contains an element similar to the target
+    pub fn contains_similar(&self) -> bool {
+        matches!(self.similarity, Similarity::Closest { .. })
+    }
+}
+
+impl Display for MissExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "target {} not found, insertion point {}",
+            self.target, self.insertion_point
+        )?;
+
+        match &self.left_neighbor {
+            Some(neighbor) => writeln!(
+                f,
+                "  left neighbor: [{}] {}",
+                neighbor.index, neighbor.rendering
+            )?,
+            None => writeln!(f, "  left neighbor: none")?,
+        }
+
+        match &self.right_neighbor {
+            Some(neighbor) => writeln!(
+                f,
+                "  right neighbor: [{}] {}",
+                neighbor.index, neighbor.rendering
+            )?,
+            None => writeln!(f, "  right neighbor: none")?,
+        }
+
+        match &self.similarity {
+            Similarity::NotChecked => write!(f, "  similar elements: not checked"),
+            Similarity::NoneSimilar => write!(f, "  similar elements: none"),
+            Similarity::Closest {
+                neighbor,
+                rank_distance,
+            } => write!(
+                f,
+                "  closest similar element: [{}] {} ({} ranks away)",
+                neighbor.index, neighbor.rendering, rank_distance
+            ),
+        }
+    }
+}
+
+/// Explains why `target` is not in `arr`.
+///
+/// Returns `None` if `target` is actually found.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::debug;
+///
+/// let target = 5;
+/// let arr = [1, 2, 4, 6, 8];
+/// let explanation = debug::explain_miss(&target, &arr).unwrap();
+///
+/// assert_eq!(explanation.insertion_point, 3);
+/// assert_eq!(explanation.left_neighbor.unwrap().rendering, "4");
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn explain_miss<T>(target: &T, arr: &[T]) -> Option<MissExplanation>
+where
+    T: Ord + Debug,
+{
+    explain(target, arr, None::<fn(&T, &T) -> bool>)
+}
+
+/// Explains why `target` is not in `arr`, also looking for the element closest to the insertion point for which
+/// `similar(target, element)` holds, e.g. a case-insensitive match.
+///
+/// Returns `None` if `target` is actually found.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::debug;
+///
+/// let target = "Banana";
+/// let arr = ["apple", "banana", "cherry"];
+/// let explanation = debug::explain_miss_with(&target, &arr, |t, e| t.eq_ignore_ascii_case(e)).unwrap();
+///
+/// assert!(explanation.contains_similar());
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn explain_miss_with<T, SimilarFn>(
+    target: &T,
+    arr: &[T],
+    similar: SimilarFn,
+) -> Option<MissExplanation>
+where
+    T: Ord + Debug,
+    SimilarFn: FnMut(&T, &T) -> bool,
+{
+    explain(target, arr, Some(similar))
+}
+
+/// Builds the explanation, checking similarity only if a closure is given
+fn explain<T, SimilarFn>(
+    target: &T,
+    arr: &[T],
+    similar: Option<SimilarFn>,
+) -> Option<MissExplanation>
+where
+    T: Ord + Debug,
+    SimilarFn: FnMut(&T, &T) -> bool,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Explain miss encountered an array that is note sorted");
+    }
+
+    let insertion_point = core::lower_bound(target, arr);
+
+    if insertion_point < arr.len() && arr[insertion_point] == *target {
+        return None;
+    }
+
+    let neighbor = |index: usize| Neighbor {
+        index,
+        rendering: format!("{:?}", arr[index]),
+    };
+    let similarity = match similar {
+        None => Similarity::NotChecked,
+        Some(similar) => match closest_similar(target, arr, insertion_point, similar) {
+            None => Similarity::NoneSimilar,
+            Some((index, rank_distance)) => Similarity::Closest {
+                neighbor: neighbor(index),
+                rank_distance,
+            },
+        },
+    };
+
+    Some(MissExplanation {
+        target: format!("{:?}", target),
+        insertion_point,
+        left_neighbor: insertion_point.checked_sub(1).map(neighbor),
+        right_neighbor: if insertion_point < arr.len() {
+            Some(neighbor(insertion_point))
+        } else {
+            None
+        },
+        similarity,
+    })
+}
+
+/// Walks outwards from `insertion_point`, returning the index and rank distance of the first similar element.
+/// Ties are broken towards the left.
+fn closest_similar<T, SimilarFn>(
+    target: &T,
+    arr: &[T],
+    insertion_point: usize,
+    mut similar: SimilarFn,
+) -> Option<(usize, usize)>
+where
+    SimilarFn: FnMut(&T, &T) -> bool,
+{
+    let max_distance = insertion_point.max(arr.len() - insertion_point);
+
+    for distance in 1..=max_distance {
+        if let Some(left) = insertion_point.checked_sub(distance) {
+            if similar(target, &arr[left]) {
+                return Some((left, distance));
+            }
+        }
+
+        let right = insertion_point + distance - 1;
+
+        if right < arr.len() && similar(target, &arr[right]) {
+            return Some((right, distance));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod debug_tests {
+    use super::{explain_miss, explain_miss_with, Neighbor, Similarity};
+
+    #[test]
+    #[should_panic(expected = "Explain miss encountered an array that is note sorted")]
+    fn explain_miss_panics_if_arr_is_not_sorted() {
+        let target = 5;
+        let arr = [1, 3, 2, 6];
+
+        explain_miss(&target, &arr);
+    }
+
+    #[test]
+    fn explain_miss_returns_none_if_target_in_arr() {
+        let target = 4;
+        let arr = [1, 2, 4, 6, 8];
+        let explanation = explain_miss(&target, &arr);
+
+        assert_eq!(explanation, None);
+    }
+
+    #[test]
+    fn explain_miss_reports_case_mismatch() {
+        let target = "Banana";
+        let arr = ["apple", "banana", "cherry", "date"];
+        let explanation =
+            explain_miss_with(&target, &arr, |t, e| t.eq_ignore_ascii_case(e)).unwrap();
+
+        assert_eq!(explanation.insertion_point, 0);
+        assert_eq!(explanation.left_neighbor, None);
+        assert_eq!(
+            explanation.similarity,
+            Similarity::Closest {
+                neighbor: Neighbor {
+                    index: 1,
+                    rendering: "\"banana\"".to_string(),
+                },
+                rank_distance: 2,
+            }
+        );
+        assert!(explanation.contains_similar());
+    }
+
+    #[test]
+    fn explain_miss_reports_off_by_one_value() {
+        let target = 5;
+        let arr = [1, 2, 4, 6, 8];
+        let explanation =
+            explain_miss_with(&target, &arr, |t: &i32, e: &i32| (t - e).abs() <= 1).unwrap();
+
+        assert_eq!(explanation.insertion_point, 3);
+        assert_eq!(explanation.left_neighbor.unwrap().index, 2);
+        assert_eq!(explanation.right_neighbor.unwrap().index, 3);
+        assert_eq!(
+            explanation.similarity,
+            Similarity::Closest {
+                neighbor: Neighbor {
+                    index: 2,
+                    rendering: "4".to_string(),
+                },
+                rank_distance: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn explain_miss_reports_genuinely_absent_value() {
+        let target = 20;
+        let arr = [1, 2, 4, 6, 8];
+        let explanation =
+            explain_miss_with(&target, &arr, |t: &i32, e: &i32| (t - e).abs() <= 1).unwrap();
+
+        assert_eq!(explanation.insertion_point, 5);
+        assert_eq!(explanation.right_neighbor, None);
+        assert_eq!(explanation.similarity, Similarity::NoneSimilar);
+        assert!(!explanation.contains_similar());
+    }
+
+    #[test]
+    fn explain_miss_display_renders_report() {
+        let target = 5;
+        let arr = [4, 6];
+        let explanation = explain_miss(&target, &arr).unwrap();
+
+        assert_eq!(
+            explanation.to_string(),
+            "target 5 not found, insertion point 1\n  left neighbor: [0] 4\n  right neighbor: [1] 6\n  similar elements: not checked"
+        );
+    }
+}
@@ -0,0 +1,56 @@
+//! Error types shared across the crate
+
+use ::core::fmt;
+
+/// Error returned when a searched target is not present in the array
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NotFound;
+
+impl fmt::Display for NotFound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "target not found")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NotFound {}
+
+/// Error returned when an array that must be sorted is not
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotSortedError {
+    /// The index of the first element smaller than its predecessor
+    index: usize,
+}
+
+impl NotSortedError {
+    /// Checks that `arr` is sorted, returning an error pointing at the first element smaller than its predecessor
+    pub(crate) fn check<T>(arr: &[T]) -> Result<(), Self>
+    where
+        T: Ord,
+    {
+        match arr.windows(2).position(|pair| pair[0] > pair[1]) {
+            Some(position) => Err(Self {
+                index: position + 1,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the index of the first element smaller than its predecessor
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for NotSortedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "array is not sorted: element {} is smaller than its predecessor",
+            self.index
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NotSortedError {}
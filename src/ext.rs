@@ -0,0 +1,160 @@
+//! Extension trait exposing the crate's searches as slice methods
+
+use crate::{ranks, variations};
+use ::core::cmp::Ordering;
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl<T> Sealed for [T] {}
+}
+
+/// Extension trait exposing the crate's searches as methods on slices
+///
+/// The trait is sealed: it is only implemented for `[T]` and cannot be implemented outside this crate, so new methods
+/// may be added without breaking changes.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::SliceSearchExt;
+///
+/// let data = [1, 2, 4, 4, 4, 5, 6, 7];
+/// let x = 4;
+///
+/// assert_eq!(data.bsearch(&5), Some(5));
+/// assert_eq!(&data[data.leftmost_rank(&x)..=data.rightmost_rank(&x)], &[4, 4, 4]);
+/// ```
+pub trait SliceSearchExt<T>: sealed::Sealed {
+    /// Performs [`binary_search`](crate::binary_search) on the slice.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the slice is not sorted.
+    fn bsearch(&self, target: &T) -> Option<usize>
+    where
+        T: Ord;
+
+    /// Performs [`binary_search_by`](crate::binary_search_by) on the slice.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the slice is not sorted with respect to `compare`.
+    fn bsearch_by<F>(&self, compare: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> Ordering;
+
+    /// Performs [`binary_search_by_key`](crate::binary_search_by_key) on the slice.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the slice is not sorted by key.
+    fn bsearch_by_key<B, F>(&self, key: &B, f: F) -> Option<usize>
+    where
+        B: Ord,
+        F: FnMut(&T) -> B;
+
+    /// Calculates the [`leftmost_rank`](ranks::leftmost_rank) of `target` in the slice.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the slice is not sorted.
+    fn leftmost_rank(&self, target: &T) -> usize
+    where
+        T: Ord;
+
+    /// Calculates the [`rightmost_rank`](ranks::rightmost_rank) of `target` in the slice.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the slice is not sorted.
+    fn rightmost_rank(&self, target: &T) -> usize
+    where
+        T: Ord;
+
+    /// Performs [`exponential_search`](variations::exponential_search) on the slice.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the slice is not sorted.
+    fn exponential_search(&self, target: &T) -> Option<usize>
+    where
+        T: Ord;
+}
+
+impl<T> SliceSearchExt<T> for [T] {
+    fn bsearch(&self, target: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        crate::binary_search(target, self)
+    }
+
+    fn bsearch_by<F>(&self, compare: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        crate::binary_search_by(self, compare)
+    }
+
+    fn bsearch_by_key<B, F>(&self, key: &B, f: F) -> Option<usize>
+    where
+        B: Ord,
+        F: FnMut(&T) -> B,
+    {
+        crate::binary_search_by_key(key, self, f)
+    }
+
+    fn leftmost_rank(&self, target: &T) -> usize
+    where
+        T: Ord,
+    {
+        ranks::leftmost_rank(target, self)
+    }
+
+    fn rightmost_rank(&self, target: &T) -> usize
+    where
+        T: Ord,
+    {
+        ranks::rightmost_rank(target, self)
+    }
+
+    fn exponential_search(&self, target: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        variations::exponential_search(target, self)
+    }
+}
+
+#[cfg(test)]
+mod ext_tests {
+    use super::SliceSearchExt;
+
+    #[test]
+    fn methods_match_free_functions() {
+        let arr = [1, 2, 4, 4, 4, 5, 6, 7];
+
+        assert_eq!(arr.bsearch(&5), Some(5));
+        assert_eq!(arr.bsearch(&3), None);
+        assert_eq!(arr.leftmost_rank(&4), 2);
+        assert_eq!(arr.rightmost_rank(&4), 4);
+        assert_eq!(arr.exponential_search(&7), Some(7));
+    }
+
+    #[test]
+    fn key_and_comparator_methods_match_free_functions() {
+        let arr = [(1, 'a'), (3, 'b'), (5, 'c')];
+
+        assert_eq!(arr.bsearch_by(|(key, _)| key.cmp(&3)), Some(1));
+        assert_eq!(arr.bsearch_by_key(&'c', |&(_, value)| value), Some(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Binary search encountered an array that is note sorted")]
+    fn bsearch_panics_when_slice_is_not_sorted() {
+        let arr = [1, 3, 2, 5];
+
+        arr.bsearch(&5);
+    }
+}
@@ -0,0 +1,298 @@
+//! Search over sorted fixed-size records in a file or any other seekable reader, without loading it into memory
+
+use ::core::cmp::Ordering;
+use std::{
+    fmt,
+    io::{self, Read, Seek, SeekFrom},
+};
+
+/// Binary search over the sorted records of a reader
+///
+/// The data is a sequence of records of `record_size` bytes, sorted by the keys that `decode` reads from them. Each
+/// probe seeks to a record and reads it, so a search reads O(log n) records. The order of the records is not checked,
+/// as that would read the whole data.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::external::ExternalSearch;
+/// use std::{convert::TryInto, io::Cursor};
+///
+/// // Records of a big-endian `u32` key followed by a `u32` payload
+/// let data: Vec<u8> = [(1u32, 10u32), (3, 30), (3, 31), (8, 80)]
+///     .iter()
+///     .flat_map(|(key, payload)| [key.to_be_bytes(), payload.to_be_bytes()].concat())
+///     .collect();
+/// let len = data.len() as u64;
+/// let decode = |record: &[u8]| u32::from_be_bytes(record[..4].try_into().unwrap());
+/// let mut search = ExternalSearch::new(Cursor::new(data), 8, len, decode)?;
+///
+/// assert_eq!(search.search(&8)?, Some(3));
+/// assert_eq!(search.search(&2)?, None);
+/// assert_eq!(search.leftmost_rank(&3)?, 1);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct ExternalSearch<R, F> {
+    /// The reader holding the records
+    reader: R,
+    /// The size of every record, in bytes
+    record_size: usize,
+    /// The number of records
+    len: u64,
+    /// Reads the key of a record
+    decode: F,
+    /// The bytes of the record being probed
+    buffer: Vec<u8>,
+}
+
+impl<R, F> ExternalSearch<R, F>
+where
+    R: Read + Seek,
+{
+    /// Returns a new `ExternalSearch` over the `file_len` bytes of `reader`, read as records of `record_size` bytes
+    /// whose keys are read by `decode`.
+    ///
+    /// # Errors
+    ///
+    /// The function returns an [`io::ErrorKind::InvalidInput`] error if `record_size` is 0, and an
+    /// [`io::ErrorKind::InvalidData`] error if `file_len` is not a multiple of `record_size`, i.e. if the data ends
+    /// with a partial record.
+    pub fn new(reader: R, record_size: usize, file_len: u64, decode: F) -> io::Result<Self> {
+        if record_size == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the record size must not be 0",
+            ));
+        }
+
+        let remainder = file_len % record_size as u64;
+
+        if remainder != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "the data ends with a partial record: {} bytes is {} bytes past a multiple of the record size {}",
+                    file_len, remainder, record_size
+                ),
+            ));
+        }
+
+        Ok(Self {
+            reader,
+            record_size,
+            len: file_len / record_size as u64,
+            decode,
+            buffer: vec![0; record_size],
+        })
+    }
+
+    /// Returns the index of a record whose key is `target`.
+    ///
+    /// # Errors
+    ///
+    /// The function returns the errors of seeking and reading the records.
+    pub fn search<K>(&mut self, target: &K) -> io::Result<Option<u64>>
+    where
+        F: Fn(&[u8]) -> K,
+        K: Ord,
+    {
+        let mut left = 0;
+        let mut right = self.len;
+
+        while left < right {
+            let middle = left + (right - left) / 2;
+
+            match self.key(middle)?.cmp(target) {
+                Ordering::Equal => return Ok(Some(middle)),
+                Ordering::Greater => right = middle,
+                Ordering::Less => left = middle + 1,
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Calculates the leftmost rank of `target`, i.e. the number of records whose key is smaller than it.
+    ///
+    /// # Errors
+    ///
+    /// The function returns the errors of seeking and reading the records.
+    pub fn leftmost_rank<K>(&mut self, target: &K) -> io::Result<u64>
+    where
+        F: Fn(&[u8]) -> K,
+        K: Ord,
+    {
+        let mut left = 0;
+        let mut right = self.len;
+
+        while left < right {
+            let middle = left + (right - left) / 2;
+
+            if self.key(middle)? < *target {
+                left = middle + 1;
+            } else {
+                right = middle;
+            }
+        }
+
+        Ok(left)
+    }
+
+    /// Returns the number of records.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns `true` if there are no records.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the underlying reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Reads the record at `index` and returns its key
+    fn key<K>(&mut self, index: u64) -> io::Result<K>
+    where
+        F: Fn(&[u8]) -> K,
+    {
+        self.reader
+            .seek(SeekFrom::Start(index * self.record_size as u64))?;
+        self.reader.read_exact(&mut self.buffer)?;
+
+        Ok((self.decode)(&self.buffer))
+    }
+}
+
+impl<R, F> fmt::Debug for ExternalSearch<R, F>
+where
+    R: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExternalSearch")
+            .field("reader", &self.reader)
+            .field("record_size", &self.record_size)
+            .field("len", &self.len)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod external_tests {
+    use super::ExternalSearch;
+    use std::{
+        convert::TryInto,
+        io::{self, Cursor, Read, Seek, SeekFrom},
+    };
+
+    /// Encodes `keys` as 16-byte records of a big-endian `u64` key followed by its index as a payload
+    fn records(keys: &[u64]) -> Vec<u8> {
+        keys.iter()
+            .enumerate()
+            .flat_map(|(index, key)| [key.to_be_bytes(), (index as u64).to_be_bytes()].concat())
+            .collect()
+    }
+
+    fn decode(record: &[u8]) -> u64 {
+        u64::from_be_bytes(record[..8].try_into().unwrap())
+    }
+
+    /// A reader whose reads always fail
+    struct Failing;
+
+    impl Read for Failing {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("disk on fire"))
+        }
+    }
+
+    impl Seek for Failing {
+        fn seek(&mut self, _: SeekFrom) -> io::Result<u64> {
+            Ok(0)
+        }
+    }
+
+    #[test]
+    fn search_returns_index_of_every_record() {
+        let keys = [3, 5, 7, 11, 13, 17, 19, 23, 29];
+        let data = records(&keys);
+        let len = data.len() as u64;
+        let mut search = ExternalSearch::new(Cursor::new(data), 16, len, decode).unwrap();
+
+        assert_eq!(search.len(), 9);
+
+        for (index, key) in keys.iter().enumerate() {
+            assert_eq!(search.search(key).unwrap(), Some(index as u64));
+            assert_eq!(search.search(&(key + 1)).unwrap(), None);
+        }
+
+        assert_eq!(search.search(&0).unwrap(), None);
+    }
+
+    #[test]
+    fn leftmost_rank_matches_lower_bound_with_duplicates() {
+        let keys = [1, 4, 4, 4, 6, 9, 9];
+        let data = records(&keys);
+        let len = data.len() as u64;
+        let mut search = ExternalSearch::new(Cursor::new(data), 16, len, decode).unwrap();
+
+        for target in 0..11 {
+            let expected = keys.iter().filter(|&&key| key < target).count() as u64;
+
+            assert_eq!(search.leftmost_rank(&target).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn search_returns_none_for_empty_data() {
+        let mut search = ExternalSearch::new(Cursor::new(Vec::new()), 16, 0, decode).unwrap();
+
+        assert!(search.is_empty());
+        assert_eq!(search.search(&5).unwrap(), None);
+        assert_eq!(search.leftmost_rank(&5).unwrap(), 0);
+    }
+
+    #[test]
+    fn new_returns_error_for_trailing_partial_record() {
+        let mut data = records(&[1, 2, 3]);
+
+        data.extend_from_slice(&[0; 5]);
+
+        let len = data.len() as u64;
+        let error = ExternalSearch::new(Cursor::new(data), 16, len, decode).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("partial record"), "{}", error);
+    }
+
+    #[test]
+    fn new_returns_error_for_zero_record_size() {
+        let error = ExternalSearch::new(Cursor::new(Vec::new()), 0, 0, decode).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn search_propagates_read_errors() {
+        let mut search = ExternalSearch::new(Failing, 16, 64, decode).unwrap();
+
+        assert_eq!(search.search(&5).unwrap_err().to_string(), "disk on fire");
+        assert_eq!(
+            search.leftmost_rank(&5).unwrap_err().to_string(),
+            "disk on fire"
+        );
+    }
+
+    #[test]
+    fn search_propagates_unexpected_end_of_data() {
+        let data = records(&[1, 2]);
+        let mut search = ExternalSearch::new(Cursor::new(data), 16, 64, decode).unwrap();
+
+        assert_eq!(
+            search.search(&5).unwrap_err().kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+    }
+}
@@ -0,0 +1,324 @@
+//! C functions searching primitive arrays, for calling the crate from C and C++
+//!
+//! Every function takes a pointer to the first element of a sorted array, its length, the target and a pointer to
+//! where the result is written, and returns one of the `BSEARCH_*` codes. The result is only written when the code is
+//! [`BSEARCH_OK`]. Panics are caught at the boundary and reported as [`BSEARCH_PANIC`], so they never unwind into C;
+//! this needs the crate to be built with `panic = "unwind"`, the default.
+//!
+//! The functions are annotated for [cbindgen](https://github.com/mozilla/cbindgen), which generates the matching
+//! header with `cbindgen --config cbindgen.toml --output binary_search.h`. The functions are generated by a macro, so
+//! cbindgen expands the crate first, which needs a nightly toolchain. Link against the crate by building it as
+//! a static or dynamic library, e.g. with `cargo rustc --release --features ffi --crate-type staticlib`.
+//!
+//! `f64` arrays are ordered by [`f64::total_cmp`], like in [`floats`](crate::floats).
+
+use crate::{core, floats::TotalF64, utils};
+use std::{
+    convert,
+    panic::{self, AssertUnwindSafe},
+    slice,
+};
+
+/// The search succeeded and its result was written
+pub const BSEARCH_OK: i32 = 0;
+/// The target is not in the array, and nothing was written
+pub const BSEARCH_NOT_FOUND: i32 = 1;
+/// The array is not sorted
+pub const BSEARCH_NOT_SORTED: i32 = -1;
+/// The result pointer is null, or the array pointer is null while the length is not 0
+pub const BSEARCH_NULL_POINTER: i32 = -2;
+/// The search panicked
+pub const BSEARCH_PANIC: i32 = -3;
+
+macro_rules! ffi_search {
+    ($int:ident, $key:ty, $to_key:expr, $search:ident, $leftmost_rank:ident, $rightmost_rank:ident) => {
+        #[doc = concat!(
+            "Performs binary search on the sorted `", stringify!($int), "` array of `len` elements at `ptr` in order ",
+            "to find the index of `target`, writing it to `out_index`."
+        )]
+        ///
+        /// Returns [`BSEARCH_OK`] if `target` was found, [`BSEARCH_NOT_FOUND`] if not, or an error code.
+        ///
+        /// # Safety
+        ///
+        /// `ptr` must be valid for reading `len` elements, unless `len` is 0, and `out_index` must be valid for
+        /// writing. Either may be null, which is reported with [`BSEARCH_NULL_POINTER`].
+        #[no_mangle]
+        pub unsafe extern "C" fn $search(
+            ptr: *const $int,
+            len: usize,
+            target: $int,
+            out_index: *mut usize,
+        ) -> i32 {
+            let target = $to_key(target);
+
+            guard(ptr as *const $key, len, out_index, |arr| {
+                core::binary_search(&target, arr)
+            })
+        }
+
+        #[doc = concat!(
+            "Calculates the leftmost rank of `target` in the sorted `", stringify!($int), "` array of `len` elements ",
+            "at `ptr`, i.e. the number of elements smaller than it, writing it to `out_rank`."
+        )]
+        ///
+        /// Returns [`BSEARCH_OK`], or an error code.
+        ///
+        /// # Safety
+        ///
+        /// `ptr` must be valid for reading `len` elements, unless `len` is 0, and `out_rank` must be valid for
+        /// writing. Either may be null, which is reported with [`BSEARCH_NULL_POINTER`].
+        #[no_mangle]
+        pub unsafe extern "C" fn $leftmost_rank(
+            ptr: *const $int,
+            len: usize,
+            target: $int,
+            out_rank: *mut usize,
+        ) -> i32 {
+            let target = $to_key(target);
+
+            guard(ptr as *const $key, len, out_rank, |arr| {
+                Some(core::lower_bound(&target, arr))
+            })
+        }
+
+        #[doc = concat!(
+            "Calculates the rightmost rank of `target` in the sorted `", stringify!($int), "` array of `len` ",
+            "elements at `ptr`, like [`rightmost_rank`](crate::rightmost_rank), writing it to `out_rank`."
+        )]
+        ///
+        /// Returns [`BSEARCH_OK`], or an error code.
+        ///
+        /// # Safety
+        ///
+        /// `ptr` must be valid for reading `len` elements, unless `len` is 0, and `out_rank` must be valid for
+        /// writing. Either may be null, which is reported with [`BSEARCH_NULL_POINTER`].
+        #[no_mangle]
+        pub unsafe extern "C" fn $rightmost_rank(
+            ptr: *const $int,
+            len: usize,
+            target: $int,
+            out_rank: *mut usize,
+        ) -> i32 {
+            let target = $to_key(target);
+
+            guard(ptr as *const $key, len, out_rank, |arr| {
+                Some(core::upper_bound(&target, arr).saturating_sub(1))
+            })
+        }
+    };
+}
+
+ffi_search!(
+    i32,
+    i32,
+    convert::identity,
+    bsearch_i32,
+    bsearch_leftmost_rank_i32,
+    bsearch_rightmost_rank_i32
+);
+ffi_search!(
+    i64,
+    i64,
+    convert::identity,
+    bsearch_i64,
+    bsearch_leftmost_rank_i64,
+    bsearch_rightmost_rank_i64
+);
+ffi_search!(
+    u64,
+    u64,
+    convert::identity,
+    bsearch_u64,
+    bsearch_leftmost_rank_u64,
+    bsearch_rightmost_rank_u64
+);
+ffi_search!(
+    f64,
+    TotalF64,
+    TotalF64,
+    bsearch_f64,
+    bsearch_leftmost_rank_f64,
+    bsearch_rightmost_rank_f64
+);
+
+/// Checks the pointers and the order of the array at `ptr`, then runs `search` on it, catching its panics, and
+/// writes its result to `out`
+///
+/// # Safety
+///
+/// `ptr` must be null or valid for reading `len` elements, and `out` must be null or valid for writing.
+unsafe fn guard<T, F>(ptr: *const T, len: usize, out: *mut usize, search: F) -> i32
+where
+    T: Ord,
+    F: FnOnce(&[T]) -> Option<usize>,
+{
+    if out.is_null() || (ptr.is_null() && len != 0) {
+        return BSEARCH_NULL_POINTER;
+    }
+
+    let arr = if len == 0 {
+        &[]
+    } else {
+        // SAFETY: `ptr` is not null, and the caller guarantees that it is valid for reading `len` elements
+        unsafe { slice::from_raw_parts(ptr, len) }
+    };
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        if utils::is_sorted(arr) {
+            Ok(search(arr))
+        } else {
+            Err(BSEARCH_NOT_SORTED)
+        }
+    }));
+
+    match result {
+        Ok(Ok(Some(index))) => {
+            // SAFETY: `out` is not null, and the caller guarantees that it is valid for writing
+            unsafe { out.write(index) };
+
+            BSEARCH_OK
+        }
+        Ok(Ok(None)) => BSEARCH_NOT_FOUND,
+        Ok(Err(code)) => code,
+        Err(_) => BSEARCH_PANIC,
+    }
+}
+
+#[cfg(test)]
+mod ffi_tests {
+    use super::{
+        bsearch_f64, bsearch_i32, bsearch_i64, bsearch_leftmost_rank_f64,
+        bsearch_leftmost_rank_i32, bsearch_leftmost_rank_u64, bsearch_rightmost_rank_i64,
+        bsearch_rightmost_rank_u64, bsearch_u64, guard, BSEARCH_NOT_FOUND, BSEARCH_NOT_SORTED,
+        BSEARCH_NULL_POINTER, BSEARCH_OK, BSEARCH_PANIC,
+    };
+    use crate::{leftmost_rank, rightmost_rank};
+    use std::ptr;
+
+    #[test]
+    fn bsearch_i32_writes_index_if_target_in_arr() {
+        let arr = [1, 3, 5, 7, 9];
+        let mut index = usize::MAX;
+
+        for (expected, &target) in arr.iter().enumerate() {
+            let code = unsafe { bsearch_i32(arr.as_ptr(), arr.len(), target, &mut index) };
+
+            assert_eq!(code, BSEARCH_OK);
+            assert_eq!(index, expected);
+        }
+    }
+
+    #[test]
+    fn bsearch_i32_returns_not_found_without_writing() {
+        let arr = [1, 3, 5];
+        let mut index = 42;
+        let code = unsafe { bsearch_i32(arr.as_ptr(), arr.len(), 4, &mut index) };
+
+        assert_eq!(code, BSEARCH_NOT_FOUND);
+        assert_eq!(index, 42);
+    }
+
+    #[test]
+    fn bsearch_returns_not_sorted_for_unsorted_arr() {
+        let arr = [3i64, 1, 2];
+        let mut index = 0;
+
+        assert_eq!(
+            unsafe { bsearch_i64(arr.as_ptr(), arr.len(), 1, &mut index) },
+            BSEARCH_NOT_SORTED
+        );
+        assert_eq!(
+            unsafe { bsearch_rightmost_rank_i64(arr.as_ptr(), arr.len(), 1, &mut index) },
+            BSEARCH_NOT_SORTED
+        );
+    }
+
+    #[test]
+    fn bsearch_returns_null_pointer_for_null_pointers() {
+        let arr = [1u64, 2];
+        let mut index = 0;
+
+        assert_eq!(
+            unsafe { bsearch_u64(ptr::null(), 2, 1, &mut index) },
+            BSEARCH_NULL_POINTER
+        );
+        assert_eq!(
+            unsafe { bsearch_u64(arr.as_ptr(), arr.len(), 1, ptr::null_mut()) },
+            BSEARCH_NULL_POINTER
+        );
+    }
+
+    #[test]
+    fn bsearch_accepts_null_pointer_for_empty_arr() {
+        let mut rank = usize::MAX;
+
+        assert_eq!(
+            unsafe { bsearch_u64(ptr::null(), 0, 1, &mut rank) },
+            BSEARCH_NOT_FOUND
+        );
+        assert_eq!(
+            unsafe { bsearch_leftmost_rank_u64(ptr::null(), 0, 1, &mut rank) },
+            BSEARCH_OK
+        );
+        assert_eq!(rank, 0);
+    }
+
+    #[test]
+    fn rank_functions_match_slice_functions() {
+        let arr = [1u64, 2, 4, 4, 4, 5, 6, 7];
+        let signed = [-4i32, -4, 0, 3];
+        let mut rank = 0;
+
+        for target in 0..9 {
+            unsafe { bsearch_leftmost_rank_u64(arr.as_ptr(), arr.len(), target, &mut rank) };
+            assert_eq!(rank, leftmost_rank(&target, &arr));
+
+            unsafe { bsearch_rightmost_rank_u64(arr.as_ptr(), arr.len(), target, &mut rank) };
+            assert_eq!(rank, rightmost_rank(&target, &arr));
+        }
+
+        unsafe { bsearch_leftmost_rank_i32(signed.as_ptr(), signed.len(), 0, &mut rank) };
+        assert_eq!(rank, 2);
+    }
+
+    #[test]
+    fn bsearch_f64_orders_by_total_cmp() {
+        let arr = [-1.5, -0.0, 0.0, 2.5, f64::NAN];
+        let mut index = 0;
+
+        assert_eq!(
+            unsafe { bsearch_f64(arr.as_ptr(), arr.len(), 0.0, &mut index) },
+            BSEARCH_OK
+        );
+        assert_eq!(index, 2);
+        assert_eq!(
+            unsafe { bsearch_leftmost_rank_f64(arr.as_ptr(), arr.len(), 1.0, &mut index) },
+            BSEARCH_OK
+        );
+        assert_eq!(index, 3);
+        assert_eq!(
+            unsafe { bsearch_f64([1.0, -1.0].as_ptr(), 2, 1.0, &mut index) },
+            BSEARCH_NOT_SORTED
+        );
+    }
+
+    #[test]
+    fn guard_converts_panics_to_error_code() {
+        let arr = [1, 2, 3];
+        let mut index = 7;
+        let hook = std::panic::take_hook();
+
+        std::panic::set_hook(Box::new(|_| {}));
+
+        let code = unsafe {
+            guard(arr.as_ptr(), arr.len(), &mut index, |_| {
+                panic!("search failed")
+            })
+        };
+
+        std::panic::set_hook(hook);
+
+        assert_eq!(code, BSEARCH_PANIC);
+        assert_eq!(index, 7);
+    }
+}
@@ -0,0 +1,555 @@
+//! Shared sorted arrays and the results every public search function is expected to give on them
+//!
+//! This module is only available with the `fixtures` feature. Its contents are part of the semver contract: the
+//! fixtures never change, and the expectations only change when the documented semantics of a function do, which is
+//! a breaking change. The crate's own conformance tests run against the same tables.
+//!
+//! # Examples
+//!
+//! ```
+//! use binary_search::fixtures::{self, FixtureId, FnId, Outcome};
+//!
+//! for (target, outcome) in fixtures::expected(FnId::BinarySearch, FixtureId::Duplicates).cases() {
+//!     let found = binary_search::binary_search(&target, fixtures::FIXTURE_DUPLICATES);
+//!
+//!     assert!(outcome.matches(&Outcome::Index(found)));
+//! }
+//! ```
+
+use std::ops::Range;
+use Outcome::{AnyIndexIn, Bool, Index, Number, Span};
+
+/// Ten consecutive integers, without duplicates
+pub const FIXTURE_BASIC: &[i64] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+/// A short array holding a run of three equal elements
+pub const FIXTURE_DUPLICATES: &[i64] = &[1, 2, 4, 4, 4, 5, 6, 7];
+
+/// An array whose elements are all equal
+pub const FIXTURE_ALL_EQUAL: &[i64] = &[7, 7, 7, 7, 7];
+
+/// The length of the arrays returned by [`fixture_large_seeded`]
+pub const FIXTURE_LARGE_LEN: usize = 10_000;
+
+/// Returns a sorted array of [`FIXTURE_LARGE_LEN`] elements generated from `seed`, with runs of duplicates.
+///
+/// The same seed gives the same array in every version of the crate.
+pub fn fixture_large_seeded(seed: u64) -> Vec<i64> {
+    // xorshift64*, whose state must not be zero
+    let mut state = seed ^ 0x9e37_79b9_7f4a_7c15;
+    let mut next = move || {
+        state ^= state >> 12;
+        state ^= state << 25;
+        state ^= state >> 27;
+        state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    };
+    let mut value = -((FIXTURE_LARGE_LEN as i64) * 3 / 2);
+
+    (0..FIXTURE_LARGE_LEN)
+        .map(|_| {
+            value += (next() >> 62) as i64;
+            value
+        })
+        .collect()
+}
+
+/// Identifies one of the fixed fixtures
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FixtureId {
+    /// [`FIXTURE_BASIC`]
+    Basic,
+    /// [`FIXTURE_DUPLICATES`]
+    Duplicates,
+    /// [`FIXTURE_ALL_EQUAL`]
+    AllEqual,
+}
+
+impl FixtureId {
+    /// Every fixture
+    pub const ALL: &'static [FixtureId] = &[Self::Basic, Self::Duplicates, Self::AllEqual];
+
+    /// Returns the elements of the fixture
+    pub fn data(self) -> &'static [i64] {
+        match self {
+            Self::Basic => FIXTURE_BASIC,
+            Self::Duplicates => FIXTURE_DUPLICATES,
+            Self::AllEqual => FIXTURE_ALL_EQUAL,
+        }
+    }
+}
+
+/// Identifies a public search function of the crate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FnId {
+    /// [`binary_search`](crate::binary_search)
+    BinarySearch,
+    /// [`binary_search_first`](crate::binary_search_first)
+    BinarySearchFirst,
+    /// [`binary_search_last`](crate::binary_search_last)
+    BinarySearchLast,
+    /// [`contains`](crate::contains)
+    Contains,
+    /// [`ranks::leftmost_rank`](crate::ranks::leftmost_rank)
+    LeftmostRank,
+    /// [`ranks::rightmost_rank`](crate::ranks::rightmost_rank)
+    RightmostRank,
+    /// [`ranks::count`](crate::ranks::count)
+    Count,
+    /// [`ranks::equal_range`](crate::ranks::equal_range)
+    EqualRange,
+    /// [`ranks::floor`](crate::ranks::floor)
+    Floor,
+    /// [`ranks::ceil`](crate::ranks::ceil)
+    Ceil,
+    /// [`ranks::lower`](crate::ranks::lower)
+    Lower,
+    /// [`ranks::higher`](crate::ranks::higher)
+    Higher,
+}
+
+impl FnId {
+    /// Every function
+    pub const ALL: &'static [FnId] = &[
+        Self::BinarySearch,
+        Self::BinarySearchFirst,
+        Self::BinarySearchLast,
+        Self::Contains,
+        Self::LeftmostRank,
+        Self::RightmostRank,
+        Self::Count,
+        Self::EqualRange,
+        Self::Floor,
+        Self::Ceil,
+        Self::Lower,
+        Self::Higher,
+    ];
+}
+
+/// The result of a function, or the set of acceptable results
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    /// An optional index
+    Index(Option<usize>),
+    /// Any index in the range, for searches that may return any of several equal elements
+    AnyIndexIn(Range<usize>),
+    /// A rank or a count
+    Number(usize),
+    /// A range of indices
+    Span(Range<usize>),
+    /// A flag
+    Bool(bool),
+}
+
+impl Outcome {
+    /// Returns whether `actual`, the outcome of a function call, is acceptable given this expected outcome.
+    pub fn matches(&self, actual: &Outcome) -> bool {
+        match (self, actual) {
+            (Self::AnyIndexIn(range), Self::Index(Some(index))) => range.contains(index),
+            _ => self == actual,
+        }
+    }
+}
+
+/// The expected outcomes of a function over a fixture
+#[derive(Debug, Clone, Copy)]
+pub struct Expected {
+    /// The function
+    function: FnId,
+    /// The fixture
+    fixture: FixtureId,
+}
+
+impl Expected {
+    /// Returns the targets searched, each with its expected outcome.
+    pub fn cases(self) -> impl Iterator<Item = (i64, &'static Outcome)> {
+        EXPECTED
+            .iter()
+            .filter(move |(function, fixture, _, _)| {
+                *function == self.function && *fixture == self.fixture
+            })
+            .map(|(_, _, target, outcome)| (*target, outcome))
+    }
+}
+
+/// Returns the expected outcomes of `function` over `fixture`.
+pub fn expected(function: FnId, fixture: FixtureId) -> Expected {
+    Expected { function, fixture }
+}
+
+/// Every expectation, as (function, fixture, target, outcome)
+///
+/// `RightmostRank` has no case for targets below the first element, whose semantics are not settled yet.
+const EXPECTED: &[(FnId, FixtureId, i64, Outcome)] = &[
+    (FnId::BinarySearch, FixtureId::Basic, 0, Index(None)),
+    (FnId::BinarySearch, FixtureId::Basic, 1, Index(Some(0))),
+    (FnId::BinarySearch, FixtureId::Basic, 5, Index(Some(4))),
+    (FnId::BinarySearch, FixtureId::Basic, 10, Index(Some(9))),
+    (FnId::BinarySearch, FixtureId::Basic, 11, Index(None)),
+    (FnId::BinarySearch, FixtureId::Duplicates, 0, Index(None)),
+    (FnId::BinarySearch, FixtureId::Duplicates, 1, Index(Some(0))),
+    (FnId::BinarySearch, FixtureId::Duplicates, 3, Index(None)),
+    (
+        FnId::BinarySearch,
+        FixtureId::Duplicates,
+        4,
+        AnyIndexIn(2..5),
+    ),
+    (FnId::BinarySearch, FixtureId::Duplicates, 7, Index(Some(7))),
+    (FnId::BinarySearch, FixtureId::Duplicates, 8, Index(None)),
+    (FnId::BinarySearch, FixtureId::AllEqual, 6, Index(None)),
+    (FnId::BinarySearch, FixtureId::AllEqual, 7, AnyIndexIn(0..5)),
+    (FnId::BinarySearch, FixtureId::AllEqual, 8, Index(None)),
+    (FnId::BinarySearchFirst, FixtureId::Basic, 0, Index(None)),
+    (FnId::BinarySearchFirst, FixtureId::Basic, 1, Index(Some(0))),
+    (FnId::BinarySearchFirst, FixtureId::Basic, 5, Index(Some(4))),
+    (
+        FnId::BinarySearchFirst,
+        FixtureId::Basic,
+        10,
+        Index(Some(9)),
+    ),
+    (FnId::BinarySearchFirst, FixtureId::Basic, 11, Index(None)),
+    (
+        FnId::BinarySearchFirst,
+        FixtureId::Duplicates,
+        0,
+        Index(None),
+    ),
+    (
+        FnId::BinarySearchFirst,
+        FixtureId::Duplicates,
+        1,
+        Index(Some(0)),
+    ),
+    (
+        FnId::BinarySearchFirst,
+        FixtureId::Duplicates,
+        3,
+        Index(None),
+    ),
+    (
+        FnId::BinarySearchFirst,
+        FixtureId::Duplicates,
+        4,
+        Index(Some(2)),
+    ),
+    (
+        FnId::BinarySearchFirst,
+        FixtureId::Duplicates,
+        7,
+        Index(Some(7)),
+    ),
+    (
+        FnId::BinarySearchFirst,
+        FixtureId::Duplicates,
+        8,
+        Index(None),
+    ),
+    (FnId::BinarySearchFirst, FixtureId::AllEqual, 6, Index(None)),
+    (
+        FnId::BinarySearchFirst,
+        FixtureId::AllEqual,
+        7,
+        Index(Some(0)),
+    ),
+    (FnId::BinarySearchFirst, FixtureId::AllEqual, 8, Index(None)),
+    (FnId::BinarySearchLast, FixtureId::Basic, 0, Index(None)),
+    (FnId::BinarySearchLast, FixtureId::Basic, 1, Index(Some(0))),
+    (FnId::BinarySearchLast, FixtureId::Basic, 5, Index(Some(4))),
+    (FnId::BinarySearchLast, FixtureId::Basic, 10, Index(Some(9))),
+    (FnId::BinarySearchLast, FixtureId::Basic, 11, Index(None)),
+    (
+        FnId::BinarySearchLast,
+        FixtureId::Duplicates,
+        0,
+        Index(None),
+    ),
+    (
+        FnId::BinarySearchLast,
+        FixtureId::Duplicates,
+        1,
+        Index(Some(0)),
+    ),
+    (
+        FnId::BinarySearchLast,
+        FixtureId::Duplicates,
+        3,
+        Index(None),
+    ),
+    (
+        FnId::BinarySearchLast,
+        FixtureId::Duplicates,
+        4,
+        Index(Some(4)),
+    ),
+    (
+        FnId::BinarySearchLast,
+        FixtureId::Duplicates,
+        7,
+        Index(Some(7)),
+    ),
+    (
+        FnId::BinarySearchLast,
+        FixtureId::Duplicates,
+        8,
+        Index(None),
+    ),
+    (FnId::BinarySearchLast, FixtureId::AllEqual, 6, Index(None)),
+    (
+        FnId::BinarySearchLast,
+        FixtureId::AllEqual,
+        7,
+        Index(Some(4)),
+    ),
+    (FnId::BinarySearchLast, FixtureId::AllEqual, 8, Index(None)),
+    (FnId::Contains, FixtureId::Basic, 0, Bool(false)),
+    (FnId::Contains, FixtureId::Basic, 1, Bool(true)),
+    (FnId::Contains, FixtureId::Basic, 5, Bool(true)),
+    (FnId::Contains, FixtureId::Basic, 10, Bool(true)),
+    (FnId::Contains, FixtureId::Basic, 11, Bool(false)),
+    (FnId::Contains, FixtureId::Duplicates, 0, Bool(false)),
+    (FnId::Contains, FixtureId::Duplicates, 1, Bool(true)),
+    (FnId::Contains, FixtureId::Duplicates, 3, Bool(false)),
+    (FnId::Contains, FixtureId::Duplicates, 4, Bool(true)),
+    (FnId::Contains, FixtureId::Duplicates, 7, Bool(true)),
+    (FnId::Contains, FixtureId::Duplicates, 8, Bool(false)),
+    (FnId::Contains, FixtureId::AllEqual, 6, Bool(false)),
+    (FnId::Contains, FixtureId::AllEqual, 7, Bool(true)),
+    (FnId::Contains, FixtureId::AllEqual, 8, Bool(false)),
+    (FnId::LeftmostRank, FixtureId::Basic, 0, Number(0)),
+    (FnId::LeftmostRank, FixtureId::Basic, 1, Number(0)),
+    (FnId::LeftmostRank, FixtureId::Basic, 5, Number(4)),
+    (FnId::LeftmostRank, FixtureId::Basic, 10, Number(9)),
+    (FnId::LeftmostRank, FixtureId::Basic, 11, Number(10)),
+    (FnId::LeftmostRank, FixtureId::Duplicates, 0, Number(0)),
+    (FnId::LeftmostRank, FixtureId::Duplicates, 1, Number(0)),
+    (FnId::LeftmostRank, FixtureId::Duplicates, 3, Number(2)),
+    (FnId::LeftmostRank, FixtureId::Duplicates, 4, Number(2)),
+    (FnId::LeftmostRank, FixtureId::Duplicates, 7, Number(7)),
+    (FnId::LeftmostRank, FixtureId::Duplicates, 8, Number(8)),
+    (FnId::LeftmostRank, FixtureId::AllEqual, 6, Number(0)),
+    (FnId::LeftmostRank, FixtureId::AllEqual, 7, Number(0)),
+    (FnId::LeftmostRank, FixtureId::AllEqual, 8, Number(5)),
+    (FnId::RightmostRank, FixtureId::Basic, 1, Number(0)),
+    (FnId::RightmostRank, FixtureId::Basic, 5, Number(4)),
+    (FnId::RightmostRank, FixtureId::Basic, 10, Number(9)),
+    (FnId::RightmostRank, FixtureId::Basic, 11, Number(9)),
+    (FnId::RightmostRank, FixtureId::Duplicates, 1, Number(0)),
+    (FnId::RightmostRank, FixtureId::Duplicates, 3, Number(1)),
+    (FnId::RightmostRank, FixtureId::Duplicates, 4, Number(4)),
+    (FnId::RightmostRank, FixtureId::Duplicates, 7, Number(7)),
+    (FnId::RightmostRank, FixtureId::Duplicates, 8, Number(7)),
+    (FnId::RightmostRank, FixtureId::AllEqual, 7, Number(4)),
+    (FnId::RightmostRank, FixtureId::AllEqual, 8, Number(4)),
+    (FnId::Count, FixtureId::Basic, 0, Number(0)),
+    (FnId::Count, FixtureId::Basic, 1, Number(1)),
+    (FnId::Count, FixtureId::Basic, 5, Number(1)),
+    (FnId::Count, FixtureId::Basic, 10, Number(1)),
+    (FnId::Count, FixtureId::Basic, 11, Number(0)),
+    (FnId::Count, FixtureId::Duplicates, 0, Number(0)),
+    (FnId::Count, FixtureId::Duplicates, 1, Number(1)),
+    (FnId::Count, FixtureId::Duplicates, 3, Number(0)),
+    (FnId::Count, FixtureId::Duplicates, 4, Number(3)),
+    (FnId::Count, FixtureId::Duplicates, 7, Number(1)),
+    (FnId::Count, FixtureId::Duplicates, 8, Number(0)),
+    (FnId::Count, FixtureId::AllEqual, 6, Number(0)),
+    (FnId::Count, FixtureId::AllEqual, 7, Number(5)),
+    (FnId::Count, FixtureId::AllEqual, 8, Number(0)),
+    (FnId::EqualRange, FixtureId::Basic, 0, Span(0..0)),
+    (FnId::EqualRange, FixtureId::Basic, 1, Span(0..1)),
+    (FnId::EqualRange, FixtureId::Basic, 5, Span(4..5)),
+    (FnId::EqualRange, FixtureId::Basic, 10, Span(9..10)),
+    (FnId::EqualRange, FixtureId::Basic, 11, Span(10..10)),
+    (FnId::EqualRange, FixtureId::Duplicates, 0, Span(0..0)),
+    (FnId::EqualRange, FixtureId::Duplicates, 1, Span(0..1)),
+    (FnId::EqualRange, FixtureId::Duplicates, 3, Span(2..2)),
+    (FnId::EqualRange, FixtureId::Duplicates, 4, Span(2..5)),
+    (FnId::EqualRange, FixtureId::Duplicates, 7, Span(7..8)),
+    (FnId::EqualRange, FixtureId::Duplicates, 8, Span(8..8)),
+    (FnId::EqualRange, FixtureId::AllEqual, 6, Span(0..0)),
+    (FnId::EqualRange, FixtureId::AllEqual, 7, Span(0..5)),
+    (FnId::EqualRange, FixtureId::AllEqual, 8, Span(5..5)),
+    (FnId::Floor, FixtureId::Basic, 0, Index(None)),
+    (FnId::Floor, FixtureId::Basic, 1, Index(Some(0))),
+    (FnId::Floor, FixtureId::Basic, 5, Index(Some(4))),
+    (FnId::Floor, FixtureId::Basic, 10, Index(Some(9))),
+    (FnId::Floor, FixtureId::Basic, 11, Index(Some(9))),
+    (FnId::Floor, FixtureId::Duplicates, 0, Index(None)),
+    (FnId::Floor, FixtureId::Duplicates, 1, Index(Some(0))),
+    (FnId::Floor, FixtureId::Duplicates, 3, Index(Some(1))),
+    (FnId::Floor, FixtureId::Duplicates, 4, Index(Some(4))),
+    (FnId::Floor, FixtureId::Duplicates, 7, Index(Some(7))),
+    (FnId::Floor, FixtureId::Duplicates, 8, Index(Some(7))),
+    (FnId::Floor, FixtureId::AllEqual, 6, Index(None)),
+    (FnId::Floor, FixtureId::AllEqual, 7, Index(Some(4))),
+    (FnId::Floor, FixtureId::AllEqual, 8, Index(Some(4))),
+    (FnId::Ceil, FixtureId::Basic, 0, Index(Some(0))),
+    (FnId::Ceil, FixtureId::Basic, 1, Index(Some(0))),
+    (FnId::Ceil, FixtureId::Basic, 5, Index(Some(4))),
+    (FnId::Ceil, FixtureId::Basic, 10, Index(Some(9))),
+    (FnId::Ceil, FixtureId::Basic, 11, Index(None)),
+    (FnId::Ceil, FixtureId::Duplicates, 0, Index(Some(0))),
+    (FnId::Ceil, FixtureId::Duplicates, 1, Index(Some(0))),
+    (FnId::Ceil, FixtureId::Duplicates, 3, Index(Some(2))),
+    (FnId::Ceil, FixtureId::Duplicates, 4, Index(Some(2))),
+    (FnId::Ceil, FixtureId::Duplicates, 7, Index(Some(7))),
+    (FnId::Ceil, FixtureId::Duplicates, 8, Index(None)),
+    (FnId::Ceil, FixtureId::AllEqual, 6, Index(Some(0))),
+    (FnId::Ceil, FixtureId::AllEqual, 7, Index(Some(0))),
+    (FnId::Ceil, FixtureId::AllEqual, 8, Index(None)),
+    (FnId::Lower, FixtureId::Basic, 0, Index(None)),
+    (FnId::Lower, FixtureId::Basic, 1, Index(None)),
+    (FnId::Lower, FixtureId::Basic, 5, Index(Some(3))),
+    (FnId::Lower, FixtureId::Basic, 10, Index(Some(8))),
+    (FnId::Lower, FixtureId::Basic, 11, Index(Some(9))),
+    (FnId::Lower, FixtureId::Duplicates, 0, Index(None)),
+    (FnId::Lower, FixtureId::Duplicates, 1, Index(None)),
+    (FnId::Lower, FixtureId::Duplicates, 3, Index(Some(1))),
+    (FnId::Lower, FixtureId::Duplicates, 4, Index(Some(1))),
+    (FnId::Lower, FixtureId::Duplicates, 7, Index(Some(6))),
+    (FnId::Lower, FixtureId::Duplicates, 8, Index(Some(7))),
+    (FnId::Lower, FixtureId::AllEqual, 6, Index(None)),
+    (FnId::Lower, FixtureId::AllEqual, 7, Index(None)),
+    (FnId::Lower, FixtureId::AllEqual, 8, Index(Some(4))),
+    (FnId::Higher, FixtureId::Basic, 0, Index(Some(0))),
+    (FnId::Higher, FixtureId::Basic, 1, Index(Some(1))),
+    (FnId::Higher, FixtureId::Basic, 5, Index(Some(5))),
+    (FnId::Higher, FixtureId::Basic, 10, Index(None)),
+    (FnId::Higher, FixtureId::Basic, 11, Index(None)),
+    (FnId::Higher, FixtureId::Duplicates, 0, Index(Some(0))),
+    (FnId::Higher, FixtureId::Duplicates, 1, Index(Some(1))),
+    (FnId::Higher, FixtureId::Duplicates, 3, Index(Some(2))),
+    (FnId::Higher, FixtureId::Duplicates, 4, Index(Some(5))),
+    (FnId::Higher, FixtureId::Duplicates, 7, Index(None)),
+    (FnId::Higher, FixtureId::Duplicates, 8, Index(None)),
+    (FnId::Higher, FixtureId::AllEqual, 6, Index(Some(0))),
+    (FnId::Higher, FixtureId::AllEqual, 7, Index(None)),
+    (FnId::Higher, FixtureId::AllEqual, 8, Index(None)),
+];
+
+#[cfg(test)]
+mod fixtures_tests {
+    use super::{expected, fixture_large_seeded, FixtureId, FnId, Outcome, FIXTURE_LARGE_LEN};
+    use crate::{ranks, utils};
+
+    /// Calls `function` with `target` and `arr`
+    fn run(function: FnId, target: i64, arr: &[i64]) -> Outcome {
+        match function {
+            FnId::BinarySearch => Outcome::Index(crate::binary_search(&target, arr)),
+            FnId::BinarySearchFirst => Outcome::Index(crate::binary_search_first(&target, arr)),
+            FnId::BinarySearchLast => Outcome::Index(crate::binary_search_last(&target, arr)),
+            FnId::Contains => Outcome::Bool(crate::contains(&target, arr)),
+            FnId::LeftmostRank => Outcome::Number(ranks::leftmost_rank(&target, arr)),
+            FnId::RightmostRank => Outcome::Number(ranks::rightmost_rank(&target, arr)),
+            FnId::Count => Outcome::Number(ranks::count(&target, arr)),
+            FnId::EqualRange => Outcome::Span(ranks::equal_range(&target, arr)),
+            FnId::Floor => Outcome::Index(ranks::floor(&target, arr)),
+            FnId::Ceil => Outcome::Index(ranks::ceil(&target, arr)),
+            FnId::Lower => Outcome::Index(ranks::lower(&target, arr)),
+            FnId::Higher => Outcome::Index(ranks::higher(&target, arr)),
+        }
+    }
+
+    /// Computes the outcome of `function` with a linear scan
+    fn reference(function: FnId, target: i64, arr: &[i64]) -> Outcome {
+        let below = arr.iter().filter(|&&element| element < target).count();
+        let at_or_below = arr.iter().filter(|&&element| element <= target).count();
+        let present = below < at_or_below;
+        let index =
+            |found: bool, index: usize| Outcome::Index(if found { Some(index) } else { None });
+
+        match function {
+            FnId::BinarySearch if at_or_below - below > 1 => {
+                Outcome::AnyIndexIn(below..at_or_below)
+            }
+            FnId::BinarySearch => index(present, below),
+            FnId::BinarySearchFirst => index(present, below),
+            FnId::BinarySearchLast => index(present, at_or_below.wrapping_sub(1)),
+            FnId::Contains => Outcome::Bool(present),
+            FnId::LeftmostRank => Outcome::Number(below),
+            FnId::RightmostRank => Outcome::Number(at_or_below - 1),
+            FnId::Count => Outcome::Number(at_or_below - below),
+            FnId::EqualRange => Outcome::Span(below..at_or_below),
+            FnId::Floor => index(at_or_below > 0, at_or_below.wrapping_sub(1)),
+            FnId::Ceil => index(below < arr.len(), below),
+            FnId::Lower => index(below > 0, below.wrapping_sub(1)),
+            FnId::Higher => index(at_or_below < arr.len(), at_or_below),
+        }
+    }
+
+    #[test]
+    fn every_function_conforms_to_expectations() {
+        for &function in FnId::ALL {
+            for &fixture in FixtureId::ALL {
+                let mut cases = expected(function, fixture).cases().peekable();
+
+                assert!(
+                    cases.peek().is_some(),
+                    "{:?} has no case over {:?}",
+                    function,
+                    fixture
+                );
+
+                for (target, outcome) in cases {
+                    let actual = run(function, target, fixture.data());
+
+                    assert!(
+                        outcome.matches(&actual),
+                        "{:?}({}) over {:?} gave {:?}, expected {:?}",
+                        function,
+                        target,
+                        fixture,
+                        actual,
+                        outcome
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn expectations_agree_with_linear_scan() {
+        for &function in FnId::ALL {
+            for &fixture in FixtureId::ALL {
+                for (target, outcome) in expected(function, fixture).cases() {
+                    assert_eq!(*outcome, reference(function, target, fixture.data()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fixtures_are_sorted() {
+        for &fixture in FixtureId::ALL {
+            assert!(utils::is_sorted(fixture.data()));
+        }
+    }
+
+    #[test]
+    fn fixture_large_seeded_is_sorted_deterministic_and_has_duplicates() {
+        let arr = fixture_large_seeded(42);
+
+        assert_eq!(arr.len(), FIXTURE_LARGE_LEN);
+        assert!(utils::is_sorted(&arr));
+        assert!(arr.windows(2).any(|pair| pair[0] == pair[1]));
+        assert_eq!(arr, fixture_large_seeded(42));
+        assert_ne!(arr, fixture_large_seeded(43));
+    }
+
+    #[test]
+    fn every_function_conforms_on_large_seeded_fixture() {
+        let arr = fixture_large_seeded(7);
+        let targets = (arr[0]..=arr[arr.len() - 1] + 1).step_by(97);
+
+        for target in targets {
+            for &function in FnId::ALL {
+                let actual = run(function, target, &arr);
+                let outcome = reference(function, target, &arr);
+
+                assert!(outcome.matches(&actual), "{:?}({})", function, target);
+            }
+        }
+    }
+}
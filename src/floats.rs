@@ -0,0 +1,308 @@
+//! Searches over `f32` and `f64` arrays
+//!
+//! Floats are not `Ord`, so these functions order elements with `total_cmp` instead, both for comparisons and for
+//! the sortedness check. Under this total order negative NaNs sort before every other value and positive NaNs sort
+//! after every other value, and `-0.0` sorts before `0.0`. An array containing NaNs is therefore valid as long as it
+//! is sorted according to `total_cmp`, and searching for a NaN finds a NaN with the same bit pattern.
+
+use crate::{core, utils};
+use ::core::cmp::Ordering;
+
+macro_rules! float_search {
+    (
+        $float:ident,
+        $wrapper:ident,
+        $is_sorted:ident,
+        $binary_search:ident,
+        $binary_search_floats:ident,
+        $leftmost_rank:ident,
+        $rightmost_rank:ident
+    ) => {
+        #[doc = concat!("`", stringify!($float), "` ordered by [`", stringify!($float), "::total_cmp`], so that it implements `Ord`")]
+        ///
+        /// This allows using floats with every generic search of the crate.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("use binary_search::floats::", stringify!($wrapper), ";")]
+        ///
+        #[doc = concat!("let arr = [", stringify!($wrapper), "(-0.0), ", stringify!($wrapper), "(0.0), ", stringify!($wrapper), "(1.5)];")]
+        #[doc = concat!("let found = binary_search::binary_search(&", stringify!($wrapper), "(0.0), &arr);")]
+        ///
+        /// assert_eq!(found, Some(1));
+        /// ```
+        #[derive(Debug, Clone, Copy, Default)]
+        #[repr(transparent)]
+        pub struct $wrapper(pub $float);
+
+        impl PartialEq for $wrapper {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == Ordering::Equal
+            }
+        }
+
+        impl Eq for $wrapper {}
+
+        impl PartialOrd for $wrapper {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $wrapper {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+
+        #[doc = concat!("Checks if `arr` is sorted according to [`", stringify!($float), "::total_cmp`].")]
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("use binary_search::floats::", stringify!($is_sorted), ";")]
+        ///
+        #[doc = concat!("assert!(", stringify!($is_sorted), "(&[-1.0, -0.0, 0.0, 2.5, ", stringify!($float), "::NAN]));")]
+        #[doc = concat!("assert!(!", stringify!($is_sorted), "(&[0.0, -0.0]));")]
+        /// ```
+        pub fn $is_sorted(arr: &[$float]) -> bool {
+            utils::is_sorted_by(arr, $float::total_cmp)
+        }
+
+        /// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) on `arr` in order to find
+        #[doc = concat!("the index of `target`, comparing elements with [`", stringify!($float), "::total_cmp`].")]
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("use binary_search::floats::", stringify!($binary_search), ";")]
+        ///
+        /// let arr = [0.5, 1.5, 2.5, 3.5];
+        #[doc = concat!("let found = ", stringify!($binary_search), "(2.5, &arr);")]
+        ///
+        /// assert_eq!(found, Some(2));
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// The function panics if the array is not sorted.
+        pub fn $binary_search(target: $float, arr: &[$float]) -> Option<usize> {
+            if !$is_sorted(arr) {
+                panic!("Binary search encountered an array that is note sorted");
+            }
+
+            core::binary_search_by(arr, |element| element.total_cmp(&target))
+        }
+
+        /// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) on `arr` in order to find
+        #[doc = concat!("the index of `target`. This is the same as [`", stringify!($binary_search), "`]: NaN ordering follows [`", stringify!($float), "::total_cmp`].")]
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("use binary_search::floats::", stringify!($binary_search_floats), ";")]
+        ///
+        #[doc = concat!("let arr = [", stringify!($float), "::NEG_INFINITY, -0.0, 0.0, ", stringify!($float), "::INFINITY];")]
+        ///
+        #[doc = concat!("assert_eq!(", stringify!($binary_search_floats), "(0.0, &arr), Some(2));")]
+        #[doc = concat!("assert_eq!(", stringify!($binary_search_floats), "(-0.0, &arr), Some(1));")]
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// The function panics if the array is not sorted.
+        pub fn $binary_search_floats(target: $float, arr: &[$float]) -> Option<usize> {
+            $binary_search(target, arr)
+        }
+
+        #[doc = concat!("Calculates the leftmost rank of the given target in the array, comparing elements with [`", stringify!($float), "::total_cmp`].")]
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("use binary_search::floats::", stringify!($leftmost_rank), ";")]
+        ///
+        /// let arr = [0.5, 1.5, 1.5, 1.5, 3.5];
+        #[doc = concat!("let rank = ", stringify!($leftmost_rank), "(1.5, &arr);")]
+        ///
+        /// assert_eq!(rank, 1);
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// The function panics if the array is not sorted.
+        pub fn $leftmost_rank(target: $float, arr: &[$float]) -> usize {
+            if !$is_sorted(arr) {
+                panic!("Binary search encountered an array that is note sorted");
+            }
+
+            core::partition_point(arr, |element| {
+                element.total_cmp(&target) == Ordering::Less
+            })
+        }
+
+        #[doc = concat!("Calculates the rightmost rank of the given target in the array, comparing elements with [`", stringify!($float), "::total_cmp`].")]
+        ///
+        /// Returns 0 if no element is smaller than or equal to `target`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("use binary_search::floats::", stringify!($rightmost_rank), ";")]
+        ///
+        /// let arr = [0.5, 1.5, 1.5, 1.5, 3.5];
+        #[doc = concat!("let rank = ", stringify!($rightmost_rank), "(1.5, &arr);")]
+        ///
+        /// assert_eq!(rank, 3);
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// The function panics if the array is not sorted.
+        pub fn $rightmost_rank(target: $float, arr: &[$float]) -> usize {
+            if !$is_sorted(arr) {
+                panic!("Binary search encountered an array that is note sorted");
+            }
+
+            core::partition_point(arr, |element| {
+                element.total_cmp(&target) != Ordering::Greater
+            })
+            .saturating_sub(1)
+        }
+    };
+}
+
+float_search!(
+    f64,
+    TotalF64,
+    is_sorted_f64,
+    binary_search_f64,
+    binary_search_floats,
+    leftmost_rank_f64,
+    rightmost_rank_f64
+);
+float_search!(
+    f32,
+    TotalF32,
+    is_sorted_f32,
+    binary_search_f32,
+    binary_search_floats_f32,
+    leftmost_rank_f32,
+    rightmost_rank_f32
+);
+
+#[cfg(test)]
+mod floats_tests {
+    use super::{
+        binary_search_f32, binary_search_f64, binary_search_floats, binary_search_floats_f32,
+        is_sorted_f32, is_sorted_f64, leftmost_rank_f32, leftmost_rank_f64, rightmost_rank_f32,
+        rightmost_rank_f64, TotalF32, TotalF64,
+    };
+    use crate::ranks;
+
+    #[test]
+    fn is_sorted_orders_nan_last() {
+        assert!(is_sorted_f64(&[1.0, 2.0, f64::INFINITY, f64::NAN]));
+        assert!(!is_sorted_f64(&[f64::NAN, 1.0]));
+        assert!(is_sorted_f32(&[-f32::NAN, -1.0, 1.0, f32::NAN]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Binary search encountered an array that is note sorted")]
+    fn binary_search_f64_panics_when_arr_is_not_sorted() {
+        let arr = [1.0, 3.0, 2.0];
+
+        binary_search_f64(2.0, &arr);
+    }
+
+    #[test]
+    fn binary_search_f64_returns_none_for_empty_arr() {
+        let arr: [f64; 0] = [];
+        let found = binary_search_f64(1.0, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn binary_search_f64_returns_some_index_if_target_in_arr() {
+        let arr = [-2.5, -1.0, 0.0, 0.5, 1.5, 7.25];
+        let found = binary_search_f64(0.5, &arr);
+
+        assert_eq!(found, Some(3));
+        assert_eq!(binary_search_f64(-3.0, &arr), None);
+    }
+
+    #[test]
+    fn binary_search_f64_finds_nan_at_the_end() {
+        let arr = [1.0, 2.0, f64::NAN];
+        let found = binary_search_f64(f64::NAN, &arr);
+
+        assert_eq!(found, Some(2));
+    }
+
+    #[test]
+    fn binary_search_f32_returns_some_index_if_target_in_arr() {
+        let arr = [0.25f32, 0.5, 0.75, 1.0];
+        let found = binary_search_f32(0.75, &arr);
+
+        assert_eq!(found, Some(2));
+    }
+
+    #[test]
+    fn ranks_return_the_correct_ranks_for_sensor_readings() {
+        let arr = [10.5, 11.0, 11.0, 11.0, 12.25];
+
+        assert_eq!(leftmost_rank_f64(11.0, &arr), 1);
+        assert_eq!(rightmost_rank_f64(11.0, &arr), 3);
+        assert_eq!(leftmost_rank_f64(11.5, &arr), 4);
+        assert_eq!(rightmost_rank_f64(11.5, &arr), 3);
+        assert_eq!(rightmost_rank_f64(1.0, &arr), 0);
+        assert_eq!(leftmost_rank_f32(11.0, &[10.5, 11.0, 11.0]), 1);
+        assert_eq!(rightmost_rank_f32(11.0, &[10.5, 11.0, 11.0]), 2);
+    }
+
+    #[test]
+    fn binary_search_floats_returns_some_index_for_normal_values() {
+        let arr = [-3.5, -1.25, 0.5, 2.0, 1e10];
+
+        assert_eq!(binary_search_floats(2.0, &arr), Some(3));
+        assert_eq!(binary_search_floats(1.0, &arr), None);
+        assert_eq!(binary_search_floats_f32(0.5, &[-1.0, 0.5, 2.0]), Some(1));
+    }
+
+    #[test]
+    fn binary_search_floats_distinguishes_negative_and_positive_zero() {
+        let arr = [-1.0, -0.0, 1.0];
+
+        assert_eq!(binary_search_floats(-0.0, &arr), Some(1));
+        assert_eq!(binary_search_floats(0.0, &arr), None);
+        assert_eq!(binary_search_floats_f32(0.0, &[-0.0f32, 0.0]), Some(1));
+    }
+
+    #[test]
+    fn binary_search_floats_finds_infinities() {
+        let arr = [f64::NEG_INFINITY, -1.0, 0.0, f64::MAX, f64::INFINITY];
+
+        assert_eq!(binary_search_floats(f64::NEG_INFINITY, &arr), Some(0));
+        assert_eq!(binary_search_floats(f64::INFINITY, &arr), Some(4));
+        assert_eq!(
+            binary_search_floats_f32(f32::INFINITY, &[0.0, f32::INFINITY]),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn total_wrappers_work_with_generic_searches() {
+        let arr = [
+            TotalF64(-0.0),
+            TotalF64(0.0),
+            TotalF64(0.0),
+            TotalF64(f64::NAN),
+        ];
+
+        assert_eq!(ranks::equal_range(&TotalF64(0.0), &arr), 1..3);
+        assert_eq!(crate::binary_search(&TotalF64(f64::NAN), &arr), Some(3));
+        assert!(TotalF32(-0.0) < TotalF32(0.0));
+    }
+}
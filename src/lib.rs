@@ -1,11 +1,86 @@
 //! Crate containing implementations of [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm)
+//!
+//! The most used items are re-exported at the crate root and in the [`prelude`], but every item keeps its canonical
+//! path in its own module, e.g. [`ranks::lower_bound`].
+//!
+//! # Features
+//!
+//! - `std` (enabled by default): links against the standard library. Disabling it makes the crate
+//!   `no_std`; the search routines only need slices and `Ord` and do not allocate. Modules that
+//!   allocate, such as [`debug`] and [`memory`], are only available with `std`.
+//! - `fixtures` (disabled by default): exposes the `fixtures` module, shared test arrays and the results every search
+//!   function is expected to give on them, for downstream tests. Implies `std`.
+//! - `rayon` (disabled by default): adds parallel batch queries, such as `ChunkedColumn::par_rank_many` in
+//!   [`columnar`], and runs [`variations::parallel_search`] on the rayon thread pool. Implies `std`.
+//! - `compat-delegate` (disabled by default): makes every [`compat::StdLikeSearch`] method call the matching `std`
+//!   slice method, for comparing the two without changing call sites.
+//! - `prefetch` (disabled by default): adds [`variations::prefetch_search`], a binary search prefetching both possible
+//!   next probes, for arrays larger than the cache.
+//! - `async` (disabled by default): adds [`async_search`], searches over sources whose elements
+//!   are read asynchronously. It works with any executor and adds no dependency.
+//! - `ffi` (disabled by default): adds [`ffi`], `extern "C"` functions searching primitive arrays, for calling the
+//!   crate from C and C++. Implies `std`.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(missing_docs)]
+
+#[cfg(feature = "async")]
+pub mod async_search;
+#[cfg(feature = "std")]
+pub mod batch;
+pub mod bytes;
+pub mod closest;
+#[cfg(feature = "std")]
+pub mod collections;
+#[cfg(feature = "std")]
+pub mod columnar;
+pub mod compat;
+pub mod consts;
+#[cfg(feature = "std")]
+pub mod cow;
+pub mod cursor;
+#[cfg(feature = "std")]
+pub mod debug;
+pub mod error;
+pub mod ext;
+#[cfg(feature = "std")]
+pub mod external;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(all(feature = "std", any(test, feature = "fixtures")))]
+pub mod fixtures;
+pub mod floats;
+#[cfg(feature = "std")]
+pub mod memory;
+pub mod monotone;
+#[cfg(feature = "std")]
+pub mod permuted;
+#[cfg(feature = "std")]
+pub mod piecewise;
+pub mod prelude;
+pub mod random_access;
 pub mod ranks;
+pub mod seam;
+pub mod sorted;
+#[cfg(feature = "std")]
+pub mod staged;
+pub mod stats;
+pub mod trace;
 pub mod variations;
+pub mod verify;
 
 mod core;
+#[cfg(test)]
+mod test_rng;
 mod utils;
 
+use ::core::{borrow::Borrow, cmp::Ordering, ops::Range};
+
+pub use error::{NotFound, NotSortedError};
+pub use ext::SliceSearchExt;
+pub use ranks::{equal_range, leftmost_rank, lower_bound, rightmost_rank, upper_bound};
+pub use sorted::{Sorted, SortedSlice};
+
 /// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) on `arr` in order to find the index of `target`
 ///
 /// # Examples
@@ -29,16 +104,620 @@ where
         panic!("Binary search encountered an array that is note sorted");
     }
 
-    if arr.is_empty() {
-        return None;
-    }
+    core::binary_search(target, arr)
+}
+
+/// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) on `arr` in order to find the index of
+/// `target`, without checking that `arr` is sorted.
+///
+/// [`binary_search`] scans the whole array to check its order before searching, which makes every call `O(n)`. This
+/// skips the scan, for hot paths whose callers already know that `arr` is sorted; [`SortedSlice`] does the same after
+/// checking once. Passing an unsorted array is not memory unsafety, it only gives a meaningless result.
+///
+/// # Examples
+///
+/// ```
+/// let target = 5;
+/// let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9 ,10];
+/// let found = binary_search::binary_search_unchecked(&target, &arr);
+///
+/// assert_eq!(found, Some(4));
+/// ```
+pub fn binary_search_unchecked<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    core::binary_search(target, arr)
+}
+
+/// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) on `arr` in order to find the index of
+/// an element whose borrowed form equals `target`. This allows searching a `&[String]` with a `&str` without allocating.
+///
+/// # Examples
+///
+/// ```
+/// let arr = vec![String::from("apple"), String::from("banana"), String::from("cherry")];
+/// let found = binary_search::binary_search_borrowed("banana", &arr);
+///
+/// assert_eq!(found, Some(1));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn binary_search_borrowed<T, Q>(target: &Q, arr: &[T]) -> Option<usize>
+where
+    T: Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    if !utils::is_sorted_by(arr, |a, b| a.borrow().cmp(b.borrow())) {
+        panic!("Binary search encountered an array that is note sorted");
+    }
+
+    core::binary_search_by(arr, |element| element.borrow().cmp(target))
+}
+
+/// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) on `seq` in order to find the index
+/// of `target`. This accepts arrays, `Vec`s, boxed slices and anything else implementing `AsRef<[T]>`, by value or by
+/// reference.
+///
+/// A `VecDeque` does not implement `AsRef<[T]>`, since its elements may wrap around; call `make_contiguous` on it
+/// first.
+///
+/// # Examples
+///
+/// ```
+/// let arr = vec![1, 2, 3, 4, 5];
+///
+/// assert_eq!(binary_search::binary_search_seq(&4, &arr), Some(3));
+/// assert_eq!(binary_search::binary_search_seq(&4, [1, 4, 9]), Some(1));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the sequence is not sorted.
+pub fn binary_search_seq<T, S>(target: &T, seq: S) -> Option<usize>
+where
+    T: Ord,
+    S: AsRef<[T]>,
+{
+    binary_search(target, seq.as_ref())
+}
+
+/// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) on `arr` using `compare`, which
+/// returns the ordering of an element relative to the searched one.
+///
+/// # Examples
+///
+/// ```
+/// let arr = [(1, 'a'), (3, 'b'), (5, 'c')];
+/// let found = binary_search::binary_search_by(&arr, |(key, _)| key.cmp(&3));
+///
+/// assert_eq!(found, Some(1));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted with respect to `compare`, i.e. if an element ordered `Less`
+/// comes after one ordered `Equal` or `Greater`, or an element ordered `Equal` comes after one ordered `Greater`.
+pub fn binary_search_by<T, F>(arr: &[T], mut compare: F) -> Option<usize>
+where
+    F: FnMut(&T) -> Ordering,
+{
+    if !utils::is_sorted_relative_to(arr, &mut compare) {
+        panic!("Binary search encountered an array that is note sorted");
+    }
+
+    core::binary_search_by(arr, compare)
+}
+
+/// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) on `arr` in order to find the index of
+/// an element whose key, as extracted by `f`, equals `key`.
+///
+/// # Examples
+///
+/// ```
+/// let arr = [(1, 'a'), (3, 'b'), (5, 'c')];
+/// let found = binary_search::binary_search_by_key(&'c', &arr, |&(_, value)| value);
+///
+/// assert_eq!(found, Some(2));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted by key.
+pub fn binary_search_by_key<T, B, F>(key: &B, arr: &[T], mut f: F) -> Option<usize>
+where
+    B: Ord,
+    F: FnMut(&T) -> B,
+{
+    if !utils::is_sorted_by(arr, |a, b| f(a).cmp(&f(b))) {
+        panic!("Binary search encountered an array that is note sorted");
+    }
+
+    core::binary_search_by(arr, |element| f(element).cmp(key))
+}
+
+/// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) on `arr` in order to find the index of
+/// `target`, returning the error produced by `err` if it is not found. `err` is only called on a miss.
+///
+/// # Examples
+///
+/// ```
+/// #[derive(Debug)]
+/// enum MyError {
+///     MissingKey,
+/// }
+///
+/// fn lookup(target: i32) -> Result<usize, MyError> {
+///     let arr = [1, 2, 3, 4, 5];
+///     let index = binary_search::binary_search_or_err(&target, &arr, || MyError::MissingKey)?;
+///
+///     Ok(index * 2)
+/// }
+///
+/// assert_eq!(lookup(4).unwrap(), 6);
+/// assert!(lookup(7).is_err());
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn binary_search_or_err<T, E, ErrFn>(target: &T, arr: &[T], err: ErrFn) -> Result<usize, E>
+where
+    T: Ord,
+    ErrFn: FnOnce() -> E,
+{
+    found_or(binary_search(target, arr), err)
+}
+
+/// Performs [`binary_search_by`] on `arr`, returning the error produced by `err` if nothing is found.
+/// `err` is only called on a miss.
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted with respect to `compare`.
+pub fn binary_search_by_or_err<T, F, E, ErrFn>(
+    arr: &[T],
+    compare: F,
+    err: ErrFn,
+) -> Result<usize, E>
+where
+    F: FnMut(&T) -> Ordering,
+    ErrFn: FnOnce() -> E,
+{
+    found_or(binary_search_by(arr, compare), err)
+}
+
+/// Performs [`binary_search_by_key`] on `arr`, returning the error produced by `err` if nothing is found.
+/// `err` is only called on a miss.
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted by key.
+pub fn binary_search_by_key_or_err<T, B, F, E, ErrFn>(
+    key: &B,
+    arr: &[T],
+    f: F,
+    err: ErrFn,
+) -> Result<usize, E>
+where
+    B: Ord,
+    F: FnMut(&T) -> B,
+    ErrFn: FnOnce() -> E,
+{
+    found_or(binary_search_by_key(key, arr, f), err)
+}
+
+/// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) on `arr` in order to find the index of
+/// `target`, returning [`NotFound`] if it is not found.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::NotFound;
+///
+/// fn second_index(arr: &[i32]) -> Result<usize, NotFound> {
+///     let index = binary_search::binary_search_required(&2, arr)?;
+///
+///     Ok(index)
+/// }
+///
+/// assert_eq!(second_index(&[1, 2, 3]), Ok(1));
+/// assert_eq!(second_index(&[1, 3]), Err(NotFound));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn binary_search_required<T>(target: &T, arr: &[T]) -> Result<usize, NotFound>
+where
+    T: Ord,
+{
+    binary_search_or_err(target, arr, || NotFound)
+}
+
+/// Converts the result of a search into a `Result`, calling `err` only if nothing was found.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::found_or;
+///
+/// let arr = [1, 2, 4, 4, 5];
+/// let found = found_or(binary_search::binary_search(&3, &arr), || "missing");
+///
+/// assert_eq!(found, Err("missing"));
+/// ```
+pub fn found_or<E, ErrFn>(found: Option<usize>, err: ErrFn) -> Result<usize, E>
+where
+    ErrFn: FnOnce() -> E,
+{
+    found.ok_or_else(err)
+}
+
+/// Finds the index of the first occurrence of `target` in `arr`.
+///
+/// Unlike [`ranks::leftmost_rank`], this returns `None` when `target` is absent instead of an insertion rank.
+///
+/// # Examples
+///
+/// ```
+/// let target = 4;
+/// let arr = [1, 2, 4, 4, 4, 5, 6, 7];
+/// let found = binary_search::binary_search_first(&target, &arr);
+///
+/// assert_eq!(found, Some(2));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn binary_search_first<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Binary search encountered an array that is note sorted");
+    }
+
+    let rank = core::lower_bound(target, arr);
+
+    if rank < arr.len() && arr[rank] == *target {
+        Some(rank)
+    } else {
+        None
+    }
+}
+
+/// Finds the index of the last occurrence of `target` in `arr`.
+///
+/// Unlike [`ranks::rightmost_rank`], this returns `None` when `target` is absent instead of a rank.
+///
+/// # Examples
+///
+/// ```
+/// let target = 4;
+/// let arr = [1, 2, 4, 4, 4, 5, 6, 7];
+/// let found = binary_search::binary_search_last(&target, &arr);
+///
+/// assert_eq!(found, Some(4));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn binary_search_last<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Binary search encountered an array that is note sorted");
+    }
+
+    let rank = core::upper_bound(target, arr);
+
+    if rank > 0 && arr[rank - 1] == *target {
+        Some(rank - 1)
+    } else {
+        None
+    }
+}
+
+/// Checks whether `target` is present in `arr` using [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm).
+///
+/// # Examples
+///
+/// ```
+/// let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9 ,10];
+///
+/// assert!(binary_search::contains(&5, &arr));
+/// assert!(!binary_search::contains(&11, &arr));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn contains<T>(target: &T, arr: &[T]) -> bool
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Binary search encountered an array that is note sorted");
+    }
+
+    core::binary_search_by(arr, |element| element.cmp(target)).is_some()
+}
+
+/// Calculates the index of the first element of `arr` for which `pred` is false, assuming every element for which it
+/// is true comes first. This mirrors `slice::partition_point`.
+///
+/// This is the building block of the other searches: the leftmost rank of a target is the partition point of
+/// `|element| element < target`, and one past its rightmost rank is the partition point of
+/// `|element| element <= target`.
+///
+/// `arr` must be partitioned by `pred`, i.e. `pred` must never return true for an element after one for which it
+/// returned false. Otherwise the result is some index of `arr`, but not a meaningful one.
+///
+/// In debug builds, the elements on both sides of the result and at both ends of `arr` are checked to be consistent
+/// with `pred`. This is cheap, though it cannot catch every predicate that is not partitioned.
+///
+/// # Examples
+///
+/// ```
+/// let events = [(1, 10), (2, 20), (3, 5), (4, 40)];
+/// let cutoff = 2;
+/// let index = binary_search::partition_point(&events, |&(time, _)| time <= cutoff);
+///
+/// assert_eq!(index, 2);
+/// ```
+///
+/// # Panics
+///
+/// In debug builds, the function panics if `pred` is not partitioned around the result.
+pub fn partition_point<T, P>(arr: &[T], mut pred: P) -> usize
+where
+    P: FnMut(&T) -> bool,
+{
+    let index = core::partition_point(arr, &mut pred);
+
+    debug_assert!(
+        (index == 0 || (pred(&arr[0]) && pred(&arr[index - 1])))
+            && (index == arr.len() || (!pred(&arr[index]) && !pred(&arr[arr.len() - 1]))),
+        "Partition point encountered a predicate that is not partitioned around index {}",
+        index
+    );
+
+    index
+}
+
+/// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) on the `range` window of `arr` in order
+/// to find the index of `target`. The returned index is absolute, i.e. relative to the start of `arr` rather than to
+/// the start of the window. Only the window needs to be sorted.
+///
+/// # Examples
+///
+/// ```
+/// let target = 7;
+/// let arr = [9, 8, 1, 3, 5, 7, 9, 0];
+/// let found = binary_search::binary_search_range(&target, &arr, 2..7);
+///
+/// assert_eq!(found, Some(5));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the range is reversed, if it is out of bounds or if the window is not sorted.
+pub fn binary_search_range<T>(target: &T, arr: &[T], range: Range<usize>) -> Option<usize>
+where
+    T: Ord,
+{
+    if range.start > range.end {
+        panic!(
+            "Binary search encountered a reversed range {}..{}",
+            range.start, range.end
+        );
+    }
+
+    if range.end > arr.len() {
+        panic!(
+            "Binary search range {}..{} is out of bounds for an array of length {}",
+            range.start,
+            range.end,
+            arr.len()
+        );
+    }
+
+    let window = &arr[range.clone()];
+
+    if !utils::is_sorted(window) {
+        panic!("Binary search encountered an array that is note sorted");
+    }
+
+    let index = range.start + core::lower_bound(target, window);
+
+    if index < range.end && arr[index] == *target {
+        Some(index)
+    } else {
+        None
+    }
+}
+
+/// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) on `arr[range]` in order to find the
+/// index of `target` in `arr`. This is the same as [`binary_search_range`].
+///
+/// # Examples
+///
+/// ```
+/// let target = 3;
+/// let arr = [9, 1, 3, 5, 0];
+/// let found = binary_search::binary_search_in(&target, &arr, 1..4);
+///
+/// assert_eq!(found, Some(2));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the range is reversed, if it is out of bounds or if the window is not sorted.
+pub fn binary_search_in<T>(target: &T, arr: &[T], range: Range<usize>) -> Option<usize>
+where
+    T: Ord,
+{
+    binary_search_range(target, arr, range)
+}
+
+/// Finds the element equal to `target` in `arr` using [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm).
+///
+/// # Examples
+///
+/// ```
+/// let arr = [(1, 'a'), (3, 'b'), (5, 'c')];
+/// let found = binary_search::find(&(3, 'b'), &arr);
+///
+/// assert_eq!(found, Some(&(3, 'b')));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn find<'a, T>(target: &T, arr: &'a [T]) -> Option<&'a T>
+where
+    T: Ord,
+{
+    binary_search(target, arr).map(|index| &arr[index])
+}
+
+/// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) on `arr` in order to find `target`,
+/// returning both its index and a reference to the matched element.
+///
+/// # Examples
+///
+/// ```
+/// let arr = ["apple", "banana", "cherry"];
+/// let found = binary_search::binary_search_entry(&"banana", &arr);
+///
+/// assert_eq!(found, Some((1, &"banana")));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn binary_search_entry<'a, T>(target: &T, arr: &'a [T]) -> Option<(usize, &'a T)>
+where
+    T: Ord,
+{
+    binary_search(target, arr).map(|index| (index, &arr[index]))
+}
+
+/// Applies `f` to the elements of `arr` greater than or equal to `lo` and smaller than `hi`, in order, and returns the
+/// first `Some` it produces. Elements after the first `Some` are not visited.
+///
+/// # Examples
+///
+/// ```
+/// let arr = [1, 4, 6, 7, 9, 12];
+/// let found = binary_search::find_map_range(&2, &10, &arr, |element| {
+///     if element % 3 == 0 {
+///         Some(element * 10)
+///     } else {
+///         None
+///     }
+/// });
+///
+/// assert_eq!(found, Some(60));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn find_map_range<'a, T, R, F>(lo: &T, hi: &T, arr: &'a [T], f: F) -> Option<R>
+where
+    T: Ord,
+    F: FnMut(&'a T) -> Option<R>,
+{
+    key_range(lo, hi, arr).iter().find_map(f)
+}
+
+/// Folds the elements of `arr` greater than or equal to `lo` and smaller than `hi`, in order, into an accumulator
+/// starting at `init`.
+///
+/// # Examples
+///
+/// ```
+/// let arr = [1, 4, 6, 7, 9, 12];
+/// let sum = binary_search::fold_range(&4, &9, &arr, 0, |sum, element| sum + element);
+///
+/// assert_eq!(sum, 17);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn fold_range<'a, T, B, F>(lo: &T, hi: &T, arr: &'a [T], init: B, f: F) -> B
+where
+    T: Ord,
+    F: FnMut(B, &'a T) -> B,
+{
+    key_range(lo, hi, arr).iter().fold(init, f)
+}
+
+/// Returns the element of `arr` greater than or equal to `lo` and smaller than `hi` with the smallest key, or `None`
+/// if there is no such element. When several elements have the smallest key, the first one is returned.
+///
+/// # Examples
+///
+/// ```
+/// let arr = [(1, 'z'), (2, 'c'), (3, 'a'), (4, 'a'), (5, 'b')];
+/// let found = binary_search::min_by_key_in_range(&(2, ' '), &(5, ' '), &arr, |element| element.1);
+///
+/// assert_eq!(found, Some(&(3, 'a')));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn min_by_key_in_range<'a, T, K, F>(lo: &T, hi: &T, arr: &'a [T], mut f: F) -> Option<&'a T>
+where
+    T: Ord,
+    K: Ord,
+    F: FnMut(&'a T) -> K,
+{
+    key_range(lo, hi, arr)
+        .iter()
+        .min_by_key(|&element| f(element))
+}
+
+/// Returns the elements of `arr` greater than or equal to `lo` and smaller than `hi`
+fn key_range<'a, T>(lo: &T, hi: &T, arr: &'a [T]) -> &'a [T]
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Binary search encountered an array that is note sorted");
+    }
+
+    let start = core::lower_bound(lo, arr);
+    let end = core::lower_bound(hi, arr).max(start);
 
-    core::binary_search(target, arr)
+    &arr[start..end]
 }
 
+// The tests that allocate only run with `std`; the rest also run on the `no_std` build with:
+//
+//     cargo test --no-default-features
 #[cfg(test)]
 mod tests {
-    use super::binary_search;
+    use super::{
+        binary_search, binary_search_by, binary_search_by_key, binary_search_by_key_or_err,
+        binary_search_by_or_err, binary_search_entry, binary_search_first, binary_search_in,
+        binary_search_last, binary_search_or_err, binary_search_range, binary_search_required,
+        binary_search_unchecked, contains, find, find_map_range, fold_range, found_or,
+        min_by_key_in_range, partition_point, NotFound,
+    };
+    #[cfg(feature = "std")]
+    use super::{binary_search_borrowed, binary_search_seq};
+    #[cfg(feature = "std")]
+    use std::path::{Path, PathBuf};
 
     #[test]
     fn binary_search_returns_none_for_empty_arr() {
@@ -67,6 +746,33 @@ mod tests {
         assert_eq!(found, Some(0));
     }
 
+    #[test]
+    fn binary_search_returns_none_if_target_below_one_element_arr() {
+        let target = 1;
+        let arr = [5];
+        let found = binary_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn binary_search_returns_none_if_target_below_every_element() {
+        let target = 1;
+        let arr = [5, 6, 7];
+        let found = binary_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn binary_search_returns_none_if_target_above_every_element() {
+        let target = 8;
+        let arr = [5, 6, 7];
+        let found = binary_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
     #[test]
     fn binary_search_returns_none_if_target_not_in_arr() {
         let target = 12;
@@ -85,6 +791,30 @@ mod tests {
         assert_eq!(found, Some(4));
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn binary_search_unchecked_matches_binary_search_on_sorted_arrs() {
+        for len in 0..40 {
+            let arr: Vec<u32> = (0..len).map(|element| element * 2).collect();
+
+            for target in 0..=len * 2 {
+                assert_eq!(
+                    binary_search_unchecked(&target, &arr),
+                    binary_search(&target, &arr)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn binary_search_unchecked_does_not_panic_when_arr_is_not_sorted() {
+        let arr = [5, 3, 9, 1];
+
+        for target in 0..10 {
+            binary_search_unchecked(&target, &arr);
+        }
+    }
+
     #[test]
     #[should_panic(expected = "Binary search encountered an array that is note sorted")]
     fn binary_search_panics_when_arr_is_not_sorted() {
@@ -93,4 +823,511 @@ mod tests {
 
         binary_search(&target, &arr);
     }
+
+    #[test]
+    fn binary_search_range_returns_absolute_index() {
+        let target = 7;
+        let arr = [9, 8, 1, 3, 5, 7, 9, 0];
+        let found = binary_search_range(&target, &arr, 2..7);
+
+        assert_eq!(found, Some(5));
+    }
+
+    #[test]
+    fn binary_search_range_returns_none_if_target_outside_window() {
+        let target = 1;
+        let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let found = binary_search_range(&target, &arr, 3..8);
+
+        assert_eq!(found, None);
+
+        let found = binary_search_range(&10, &arr, 3..8);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn binary_search_range_returns_none_for_empty_range() {
+        let target = 5;
+        let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let found = binary_search_range(&target, &arr, 4..4);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn binary_search_range_only_requires_window_to_be_sorted() {
+        let target = 4;
+        let arr = [10, 2, 4, 6, 1];
+        let found = binary_search_range(&target, &arr, 1..4);
+
+        assert_eq!(found, Some(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Binary search encountered a reversed range 5..2")]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn binary_search_range_panics_for_reversed_range() {
+        let target = 5;
+        let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+        binary_search_range(&target, &arr, 5..2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Binary search range 5..11 is out of bounds for an array of length 10"
+    )]
+    fn binary_search_range_panics_for_out_of_bounds_range() {
+        let target = 5;
+        let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+        binary_search_range(&target, &arr, 5..11);
+    }
+
+    #[test]
+    #[should_panic(expected = "Binary search encountered an array that is note sorted")]
+    fn binary_search_range_panics_when_window_is_not_sorted() {
+        let target = 5;
+        let arr = [1, 3, 2, 5];
+
+        binary_search_range(&target, &arr, 0..3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn binary_search_borrowed_searches_strings_with_str() {
+        let arr = vec![
+            String::from("apple"),
+            String::from("banana"),
+            String::from("cherry"),
+        ];
+
+        assert_eq!(binary_search_borrowed("cherry", &arr), Some(2));
+        assert_eq!(binary_search_borrowed("durian", &arr), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn binary_search_borrowed_searches_byte_vecs_with_byte_slices() {
+        let arr = vec![vec![1u8, 2], vec![1, 3], vec![2]];
+        let target: &[u8] = &[1, 3];
+
+        assert_eq!(binary_search_borrowed(target, &arr), Some(1));
+        assert_eq!(binary_search_borrowed(&[0u8][..], &arr), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn binary_search_borrowed_searches_path_bufs_with_paths() {
+        let arr = vec![
+            PathBuf::from("/a"),
+            PathBuf::from("/b"),
+            PathBuf::from("/c"),
+        ];
+
+        assert_eq!(binary_search_borrowed(Path::new("/b"), &arr), Some(1));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic(expected = "Binary search encountered an array that is note sorted")]
+    fn binary_search_borrowed_panics_when_arr_is_not_sorted() {
+        let arr = vec![String::from("b"), String::from("a")];
+
+        binary_search_borrowed("a", &arr);
+    }
+
+    #[test]
+    fn binary_search_by_returns_some_index_if_target_in_arr() {
+        let arr = [(1, 'a'), (3, 'b'), (5, 'c'), (7, 'd')];
+        let found = binary_search_by(&arr, |(key, _)| key.cmp(&5));
+
+        assert_eq!(found, Some(2));
+        assert_eq!(binary_search_by(&arr, |(key, _)| key.cmp(&4)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Binary search encountered an array that is note sorted")]
+    fn binary_search_by_panics_when_arr_is_not_sorted() {
+        let arr = [3, 1, 2];
+
+        binary_search_by(&arr, |element| element.cmp(&2));
+    }
+
+    #[test]
+    fn binary_search_by_key_returns_some_index_if_key_in_arr() {
+        let arr = [(1, 'a'), (3, 'b'), (5, 'c'), (7, 'd')];
+        let found = binary_search_by_key(&'b', &arr, |&(_, value)| value);
+
+        assert_eq!(found, Some(1));
+        assert_eq!(binary_search_by_key(&'e', &arr, |&(_, value)| value), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Binary search encountered an array that is note sorted")]
+    fn binary_search_by_key_panics_when_arr_is_not_sorted_by_key() {
+        let arr = [(1, 'b'), (2, 'a')];
+
+        binary_search_by_key(&'a', &arr, |&(_, value)| value);
+    }
+
+    #[test]
+    fn binary_search_or_err_does_not_call_err_on_hit() {
+        let arr = [1, 2, 3, 4, 5];
+        let found: Result<usize, ()> =
+            binary_search_or_err(&3, &arr, || panic!("err must not be called on a hit"));
+        let found_by: Result<usize, ()> = binary_search_by_or_err(
+            &arr,
+            |element| element.cmp(&3),
+            || panic!("err must not be called on a hit"),
+        );
+        let found_by_key: Result<usize, ()> = binary_search_by_key_or_err(
+            &6,
+            &arr,
+            |element| element * 2,
+            || panic!("err must not be called on a hit"),
+        );
+
+        assert_eq!(found, Ok(2));
+        assert_eq!(found_by, Ok(2));
+        assert_eq!(found_by_key, Ok(2));
+    }
+
+    #[test]
+    fn binary_search_or_err_returns_err_on_miss() {
+        let arr = [1, 2, 3, 4, 5];
+        let found = binary_search_or_err(&7, &arr, || "missing");
+
+        assert_eq!(found, Err("missing"));
+    }
+
+    #[test]
+    fn binary_search_required_returns_not_found_on_miss() {
+        let arr = [1, 2, 3, 4, 5];
+
+        assert_eq!(binary_search_required(&2, &arr), Ok(1));
+        assert_eq!(binary_search_required(&7, &arr), Err(NotFound));
+    }
+
+    #[test]
+    fn found_or_converts_search_results() {
+        assert_eq!(found_or(Some(3), || "missing"), Ok(3));
+        assert_eq!(found_or(None, || "missing"), Err("missing"));
+    }
+
+    #[test]
+    fn binary_search_first_and_last_return_none_for_empty_arr() {
+        let target = 5;
+        let arr = [];
+
+        assert_eq!(binary_search_first(&target, &arr), None);
+        assert_eq!(binary_search_last(&target, &arr), None);
+    }
+
+    #[test]
+    fn binary_search_first_and_last_find_run_at_start() {
+        let target = 1;
+        let arr = [1, 1, 1, 2, 3, 4];
+
+        assert_eq!(binary_search_first(&target, &arr), Some(0));
+        assert_eq!(binary_search_last(&target, &arr), Some(2));
+    }
+
+    #[test]
+    fn binary_search_first_and_last_find_run_in_middle() {
+        let target = 4;
+        let arr = [1, 2, 4, 4, 4, 5, 6, 7];
+
+        assert_eq!(binary_search_first(&target, &arr), Some(2));
+        assert_eq!(binary_search_last(&target, &arr), Some(4));
+    }
+
+    #[test]
+    fn binary_search_first_and_last_find_run_at_end() {
+        let target = 9;
+        let arr = [1, 2, 3, 9, 9, 9, 9];
+
+        assert_eq!(binary_search_first(&target, &arr), Some(3));
+        assert_eq!(binary_search_last(&target, &arr), Some(6));
+    }
+
+    #[test]
+    fn binary_search_first_and_last_return_none_if_target_not_in_arr() {
+        let arr = [1, 2, 4, 4, 4, 5];
+
+        assert_eq!(binary_search_first(&3, &arr), None);
+        assert_eq!(binary_search_last(&3, &arr), None);
+        assert_eq!(binary_search_first(&0, &arr), None);
+        assert_eq!(binary_search_last(&0, &arr), None);
+        assert_eq!(binary_search_first(&6, &arr), None);
+        assert_eq!(binary_search_last(&6, &arr), None);
+    }
+
+    #[test]
+    fn contains_returns_false_for_empty_arr() {
+        let target = 5;
+        let arr = [];
+
+        assert!(!contains(&target, &arr));
+    }
+
+    #[test]
+    fn contains_handles_one_element_arr() {
+        let arr = [5];
+
+        assert!(contains(&5, &arr));
+        assert!(!contains(&4, &arr));
+    }
+
+    #[test]
+    fn contains_returns_true_if_target_in_arr() {
+        let target = 5;
+        let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+        assert!(contains(&target, &arr));
+    }
+
+    #[test]
+    fn contains_returns_false_if_target_not_in_arr() {
+        let arr = [2, 4, 6, 8, 10];
+
+        assert!(!contains(&0, &arr));
+        assert!(!contains(&5, &arr));
+        assert!(!contains(&12, &arr));
+    }
+
+    #[test]
+    #[should_panic(expected = "Binary search encountered an array that is note sorted")]
+    fn contains_panics_when_arr_is_not_sorted() {
+        let target = 5;
+        let arr = [1, 3, 2, 5];
+
+        contains(&target, &arr);
+    }
+
+    #[test]
+    fn partition_point_returns_zero_for_empty_arr() {
+        let arr: [i32; 0] = [];
+        let index = partition_point(&arr, |&element| element < 5);
+
+        assert_eq!(index, 0);
+    }
+
+    #[test]
+    fn partition_point_returns_len_if_pred_all_true() {
+        let arr = [1, 2, 3, 4];
+        let index = partition_point(&arr, |&element| element < 5);
+
+        assert_eq!(index, 4);
+    }
+
+    #[test]
+    fn partition_point_returns_zero_if_pred_all_false() {
+        let arr = [5, 6, 7, 8];
+        let index = partition_point(&arr, |&element| element < 5);
+
+        assert_eq!(index, 0);
+    }
+
+    #[test]
+    fn partition_point_returns_index_of_first_false() {
+        let arr = [(1, 'd'), (2, 'b'), (3, 'c'), (4, 'a')];
+        let index = partition_point(&arr, |&(time, _)| time <= 2);
+
+        assert_eq!(index, 2);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Partition point encountered a predicate that is not partitioned")]
+    fn partition_point_panics_in_debug_if_pred_not_partitioned() {
+        let arr = [8, 1, 2, 3];
+
+        partition_point(&arr, |&element| element < 5);
+    }
+
+    #[test]
+    fn binary_search_in_returns_absolute_index_for_every_element_of_window() {
+        let arr = [9, 8, 1, 3, 5, 7, 9, 0];
+
+        for index in 2..7 {
+            let found = binary_search_in(&arr[index], &arr, 2..7);
+
+            assert_eq!(found, Some(index));
+        }
+    }
+
+    #[test]
+    fn binary_search_in_returns_none_for_empty_range() {
+        let target = 5;
+        let arr = [1, 3, 5, 7];
+        let found = binary_search_in(&target, &arr, 2..2);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Binary search range 2..5 is out of bounds for an array of length 4")]
+    fn binary_search_in_panics_for_out_of_bounds_range() {
+        let target = 5;
+        let arr = [1, 3, 5, 7];
+
+        binary_search_in(&target, &arr, 2..5);
+    }
+
+    #[test]
+    fn find_returns_reference_to_element() {
+        let target = 5;
+        let arr = [1, 3, 5, 7];
+        let found = find(&target, &arr);
+
+        assert_eq!(found, Some(&arr[2]));
+        assert_eq!(find(&4, &arr), None);
+    }
+
+    #[test]
+    fn find_map_range_stops_at_first_some() {
+        let arr = [1, 4, 6, 7, 9, 12];
+        let mut calls = 0;
+        let found = find_map_range(&4, &100, &arr, |&element| {
+            calls += 1;
+
+            if element > 5 {
+                Some(element)
+            } else {
+                None
+            }
+        });
+
+        assert_eq!(found, Some(6));
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn range_helpers_visit_nothing_for_empty_ranges() {
+        let arr = [1, 4, 6, 7, 9, 12];
+        let mut calls = 0;
+
+        assert_eq!(
+            find_map_range(&5, &6, &arr, |&element| {
+                calls += 1;
+                Some(element)
+            }),
+            None
+        );
+        assert_eq!(fold_range(&9, &4, &arr, 0, |count, _| count + 1), 0);
+        assert_eq!(
+            min_by_key_in_range(&10, &12, &arr, |&element| element),
+            None
+        );
+        assert_eq!(calls, 0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn range_helpers_clip_ranges_at_slice_ends() {
+        let arr = [1, 4, 6, 7, 9, 12];
+
+        assert_eq!(
+            fold_range(&-10, &5, &arr, Vec::new(), |mut seen, &element| {
+                seen.push(element);
+                seen
+            }),
+            vec![1, 4]
+        );
+        assert_eq!(
+            fold_range(&9, &100, &arr, 0, |sum, element| sum + element),
+            21
+        );
+        assert_eq!(
+            min_by_key_in_range(&-10, &100, &arr, |&element| element % 3),
+            Some(&6)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Binary search encountered an array that is note sorted")]
+    fn fold_range_panics_when_arr_is_not_sorted() {
+        let arr = [1, 3, 2];
+
+        fold_range(&0, &5, &arr, 0, |sum, element| sum + element);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn binary_search_seq_accepts_vec_boxed_slice_and_array_reference() {
+        let target = 7;
+        let vec = vec![1, 3, 5, 7, 9];
+        let boxed: Box<[i32]> = vec.clone().into_boxed_slice();
+        let arr: &[i32; 5] = &[1, 3, 5, 7, 9];
+
+        assert_eq!(binary_search_seq(&target, &vec), Some(3));
+        assert_eq!(binary_search_seq(&target, vec.clone()), Some(3));
+        assert_eq!(binary_search_seq(&target, &boxed), Some(3));
+        assert_eq!(binary_search_seq(&target, arr), Some(3));
+        assert_eq!(binary_search_seq(&4, arr), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn binary_search_seq_accepts_make_contiguous_vec_deque() {
+        let mut deque: std::collections::VecDeque<i32> = (5..10).collect();
+
+        deque.push_front(2);
+
+        assert_eq!(binary_search_seq(&8, &*deque.make_contiguous()), Some(4));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic(expected = "Binary search encountered an array that is note sorted")]
+    fn binary_search_seq_panics_when_seq_is_not_sorted() {
+        binary_search_seq(&5, vec![1, 3, 2, 5]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn binary_search_entry_returns_index_and_reference_to_element() {
+        let target = 5;
+        let arr = [1, 3, 5, 7];
+        let found = binary_search_entry(&target, &arr);
+
+        assert_eq!(found, Some((2, &arr[2])));
+        assert!(std::ptr::eq(found.unwrap().1, &arr[2]));
+        assert_eq!(binary_search_entry(&4, &arr), None);
+    }
+
+    #[test]
+    fn binary_search_entry_reference_outlives_target() {
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Record {
+            key: u32,
+            payload: &'static str,
+        }
+
+        let arr = [
+            Record {
+                key: 1,
+                payload: "one",
+            },
+            Record {
+                key: 2,
+                payload: "two",
+            },
+        ];
+        let entry = {
+            let target = Record {
+                key: 2,
+                payload: "two",
+            };
+
+            binary_search_entry(&target, &arr)
+        };
+
+        assert_eq!(
+            entry.map(|(index, record)| (index, record.payload)),
+            Some((1, "two"))
+        );
+    }
 }
@@ -0,0 +1,160 @@
+//! Memory accounting for the structures that own their data
+
+use std::mem;
+
+/// Heap memory held by one field of a structure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldUsage {
+    /// The name of the field
+    pub name: &'static str,
+    /// The bytes occupied by the elements currently stored
+    pub used_bytes: usize,
+    /// The bytes allocated, including spare capacity
+    pub reserved_bytes: usize,
+}
+
+impl FieldUsage {
+    /// Returns the usage of a `Vec` field
+    pub(crate) fn of_vec<T>(name: &'static str, vec: &Vec<T>) -> Self {
+        Self {
+            name,
+            used_bytes: vec.len() * mem::size_of::<T>(),
+            reserved_bytes: vec.capacity() * mem::size_of::<T>(),
+        }
+    }
+}
+
+/// Breakdown of the memory held by a structure
+///
+/// Heap usage is shallow: memory owned by the elements themselves, such as the buffer of a `String`, is not counted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryBreakdown {
+    /// The size of the structure itself
+    pub inline_bytes: usize,
+    /// The heap memory held by each field
+    pub fields: Vec<FieldUsage>,
+}
+
+impl MemoryBreakdown {
+    /// Returns a breakdown for `value`, with no heap fields yet
+    pub(crate) fn new<S>(value: &S) -> Self {
+        Self {
+            inline_bytes: mem::size_of_val(value),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Adds the usage of a heap field
+    pub(crate) fn with(mut self, field: FieldUsage) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Returns the heap bytes occupied by stored elements across all fields
+    pub fn used_bytes(&self) -> usize {
+        self.fields.iter().map(|field| field.used_bytes).sum()
+    }
+
+    /// Returns the heap bytes allocated across all fields, including spare capacity
+    pub fn reserved_bytes(&self) -> usize {
+        self.fields.iter().map(|field| field.reserved_bytes).sum()
+    }
+
+    /// Returns the usage of the field called `name`
+    pub fn field(&self, name: &str) -> Option<&FieldUsage> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+}
+
+/// Structures able to report the memory they hold
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::{
+///     memory::MemoryUsage,
+///     piecewise::{PiecewiseSorted, SortOrder},
+/// };
+///
+/// let arr = [1, 2, 3, 2, 1];
+/// let mut segments = Vec::with_capacity(8);
+///
+/// segments.push((0..3, SortOrder::Ascending));
+/// segments.push((3..5, SortOrder::Descending));
+///
+/// let mut piecewise = PiecewiseSorted::new(&arr, segments).unwrap();
+///
+/// piecewise.shrink_to_fit();
+///
+/// let breakdown = piecewise.memory_bytes();
+///
+/// assert_eq!(breakdown.used_bytes(), breakdown.reserved_bytes());
+/// ```
+pub trait MemoryUsage {
+    /// Returns the breakdown of the memory held by the structure
+    fn memory_bytes(&self) -> MemoryBreakdown;
+
+    /// Releases spare capacity and memory the structure no longer needs. Does nothing by default.
+    fn shrink_to_fit(&mut self) {}
+}
+
+#[cfg(test)]
+mod memory_tests {
+    use super::MemoryUsage;
+    use crate::{
+        piecewise::{PiecewiseSorted, SortOrder},
+        staged::StagedIndex,
+        variations::UniformBinarySearch,
+    };
+    use std::{mem, ops::Range};
+
+    #[test]
+    fn uniform_binary_search_holds_no_heap_memory() {
+        let uniform_binary_search = UniformBinarySearch::new();
+        let breakdown = uniform_binary_search.memory_bytes();
+
+        assert_eq!(
+            breakdown.inline_bytes,
+            mem::size_of::<UniformBinarySearch>()
+        );
+        assert_eq!(breakdown.reserved_bytes(), 0);
+    }
+
+    #[test]
+    fn piecewise_sorted_reports_segments_and_shrinks_them() {
+        let arr = [1, 2, 3, 2, 1];
+        let mut segments = Vec::with_capacity(16);
+
+        segments.push((0..3, SortOrder::Ascending));
+        segments.push((3..5, SortOrder::Descending));
+
+        let mut piecewise = PiecewiseSorted::new(&arr, segments).unwrap();
+        let segment_size = mem::size_of::<(Range<usize>, SortOrder)>();
+        let breakdown = piecewise.memory_bytes();
+
+        assert_eq!(breakdown.used_bytes(), 2 * segment_size);
+        assert_eq!(breakdown.reserved_bytes(), 16 * segment_size);
+
+        piecewise.shrink_to_fit();
+
+        assert_eq!(piecewise.memory_bytes().reserved_bytes(), 2 * segment_size);
+    }
+
+    #[test]
+    fn staged_index_releases_unsorted_copy_once_sorted() {
+        let mut index = StagedIndex::new(&[4u64, 1, 3, 2]);
+
+        index.wait_ready();
+
+        let breakdown = index.memory_bytes();
+
+        assert_eq!(breakdown.field("unsorted").unwrap().used_bytes, 32);
+        assert_eq!(breakdown.field("sorted").unwrap().used_bytes, 32);
+        assert_eq!(breakdown.used_bytes(), 64);
+
+        index.shrink_to_fit();
+
+        assert_eq!(index.memory_bytes().reserved_bytes(), 32);
+        assert!(index.contains(&3));
+    }
+}
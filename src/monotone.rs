@@ -0,0 +1,305 @@
+//! Searches over monotone predicates and functions on integer ranges, rather than over arrays
+//!
+//! These answer questions such as "what is the smallest `x` for which `f(x)` holds" by binary searching the range of
+//! possible answers, calling the predicate O(log n) times.
+
+use ::core::cmp::Ordering;
+
+use crate::utils;
+
+/// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) on the range `low..high` in order to
+/// find the smallest value for which `pred` is true.
+///
+/// `pred` must be monotone over the range: once it is true for some value, it is true for every larger value. It is
+/// never called with `high`.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::monotone;
+///
+/// // The smallest number whose square is at least 1000
+/// let root = monotone::search_first_true(0, 1000, |x| x * x >= 1000);
+///
+/// assert_eq!(root, Some(32));
+/// assert_eq!(monotone::search_first_true(0, 10, |x| x > 20), None);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the range is reversed.
+pub fn search_first_true<F>(low: usize, high: usize, mut pred: F) -> Option<usize>
+where
+    F: FnMut(usize) -> bool,
+{
+    if low > high {
+        panic!(
+            "Monotone search encountered a reversed range {}..{}",
+            low, high
+        );
+    }
+
+    let mut left = low;
+    let mut right = high;
+
+    while left < right {
+        let middle = utils::midpoint(left, right);
+
+        if pred(middle) {
+            right = middle;
+        } else {
+            left = middle + 1;
+        }
+    }
+
+    if left < high {
+        Some(left)
+    } else {
+        None
+    }
+}
+
+/// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) on the virtual array `f(lo)`, ...,
+/// `f(hi)` in order to find a value for which `f` returns [`Ordering::Equal`].
+///
+/// `f` compares the element at a value with the target, like the comparator of
+/// [`binary_search_by`](crate::binary_search_by), and must be monotone: [`Ordering::Less`] for the values below the
+/// matches and [`Ordering::Greater`] for the values above them. It is only called with values in `lo..=hi`, at most
+/// once each, so `hi` may be `u64::MAX`. An empty range, with `lo > hi`, gives `None`.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::monotone;
+///
+/// // The exact square root of a perfect square
+/// let root = monotone::search_by_fn(0, u32::MAX as u64, |x| (x * x).cmp(&1_522_756));
+///
+/// assert_eq!(root, Some(1234));
+/// assert_eq!(monotone::search_by_fn(0, u32::MAX as u64, |x| (x * x).cmp(&1_522_757)), None);
+/// ```
+pub fn search_by_fn<F>(lo: u64, hi: u64, mut f: F) -> Option<u64>
+where
+    F: FnMut(u64) -> Ordering,
+{
+    if lo > hi {
+        return None;
+    }
+
+    // The value is in left..=right, which is never empty
+    let mut left = lo;
+    let mut right = hi;
+
+    loop {
+        let middle = midpoint(left, right);
+
+        match f(middle) {
+            Ordering::Equal => return Some(middle),
+            Ordering::Less if middle == right => return None,
+            Ordering::Less => left = middle + 1,
+            Ordering::Greater if middle == left => return None,
+            Ordering::Greater => right = middle - 1,
+        }
+    }
+}
+
+/// Finds the first value in `lo..=hi` for which `pred` is false, i.e. the partition point of the virtual array
+/// `pred(lo)`, ..., `pred(hi)`, like [`partition_point`](crate::partition_point) on a slice.
+///
+/// `pred` must be true for a prefix of the range and false for the rest. Returns `None` if `pred` is true for the
+/// whole range, since the value past `hi` may not fit in a `u64`. `pred` is only called with values in `lo..=hi`, at
+/// most once each. An empty range, with `lo > hi`, gives `None`.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::monotone;
+///
+/// // The integer square root of `n` is just before the first value whose square is larger than `n`
+/// let isqrt = |n: u64| {
+///     monotone::partition_point_fn(0, n, |x| x.checked_mul(x).is_some_and(|square| square <= n))
+///         .map_or(n, |x| x - 1)
+/// };
+///
+/// assert_eq!(isqrt(0), 0);
+/// assert_eq!(isqrt(1000), 31);
+/// assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+/// ```
+pub fn partition_point_fn<P>(lo: u64, hi: u64, mut pred: P) -> Option<u64>
+where
+    P: FnMut(u64) -> bool,
+{
+    if lo > hi {
+        return None;
+    }
+
+    // The first false value is in left..=right, or is `found` if they are all true
+    let mut left = lo;
+    let mut right = hi;
+    let mut found = None;
+
+    loop {
+        let middle = midpoint(left, right);
+
+        if pred(middle) {
+            if middle == right {
+                return found;
+            }
+
+            left = middle + 1;
+        } else {
+            found = Some(middle);
+
+            if middle == left {
+                return found;
+            }
+
+            right = middle - 1;
+        }
+    }
+}
+
+/// Returns the midpoint of `left..=right`, rounded down, without overflowing for bounds close to `u64::MAX`
+fn midpoint(left: u64, right: u64) -> u64 {
+    left + (right - left) / 2
+}
+
+#[cfg(test)]
+mod monotone_tests {
+    use ::core::cmp::Ordering;
+
+    use super::{partition_point_fn, search_by_fn, search_first_true};
+
+    #[test]
+    fn search_first_true_returns_threshold_in_the_middle() {
+        for threshold in 10..50 {
+            let found = search_first_true(10, 50, |x| x >= threshold);
+
+            assert_eq!(found, Some(threshold));
+        }
+    }
+
+    #[test]
+    fn search_first_true_returns_none_if_pred_is_always_false() {
+        let mut calls = 0;
+        let found = search_first_true(0, 1000, |_| {
+            calls += 1;
+            false
+        });
+
+        assert_eq!(found, None);
+        assert!(calls <= 10);
+    }
+
+    #[test]
+    fn search_first_true_returns_low_if_pred_is_always_true() {
+        assert_eq!(search_first_true(7, 20, |_| true), Some(7));
+    }
+
+    #[test]
+    fn search_first_true_returns_none_for_empty_range() {
+        assert_eq!(search_first_true(5, 5, |_| true), None);
+    }
+
+    #[test]
+    fn search_first_true_reaches_the_end_of_usize() {
+        let found = search_first_true(0, usize::MAX, |x| x >= usize::MAX - 1);
+
+        assert_eq!(found, Some(usize::MAX - 1));
+    }
+
+    #[test]
+    fn search_first_true_does_not_overflow_for_bounds_past_half_of_usize() {
+        let low = usize::MAX / 2 + 1;
+
+        for threshold in [low, low + 1, usize::MAX - 3, usize::MAX - 1] {
+            let found = search_first_true(low, usize::MAX, |x| x >= threshold);
+
+            assert_eq!(found, Some(threshold));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Monotone search encountered a reversed range 5..3")]
+    fn search_first_true_panics_for_reversed_range() {
+        search_first_true(5, 3, |_| true);
+    }
+
+    #[test]
+    fn search_by_fn_returns_every_value_of_a_strictly_monotone_fn() {
+        for target in 10..=50 {
+            let found = search_by_fn(10, 50, |x| (x * 3).cmp(&(target * 3)));
+
+            assert_eq!(found, Some(target));
+        }
+
+        assert_eq!(search_by_fn(10, 50, |x| (x * 3).cmp(&31)), None);
+        assert_eq!(search_by_fn(10, 50, |x| x.cmp(&9)), None);
+        assert_eq!(search_by_fn(10, 50, |x| x.cmp(&51)), None);
+    }
+
+    #[test]
+    fn search_by_fn_returns_none_for_empty_range() {
+        assert_eq!(search_by_fn(5, 4, |_| Ordering::Equal), None);
+        assert_eq!(search_by_fn(5, 5, |x| x.cmp(&5)), Some(5));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn search_by_fn_calls_f_inside_the_range_at_most_once_per_value() {
+        let (lo, hi) = (u64::MAX - 1000, u64::MAX);
+
+        for target in [lo - 1, lo, lo + 1, hi - 500, hi - 1, hi] {
+            let mut calls = Vec::new();
+            let found = search_by_fn(lo, hi, |x| {
+                calls.push(x);
+                x.cmp(&target)
+            });
+
+            assert_eq!(found, Some(target).filter(|&target| target >= lo));
+            assert!(calls.iter().all(|&x| (lo..=hi).contains(&x)));
+            calls.sort_unstable();
+            calls.dedup();
+            assert!(calls.len() <= 11);
+        }
+    }
+
+    #[test]
+    fn partition_point_fn_returns_first_false_value() {
+        for threshold in 10..=50 {
+            assert_eq!(
+                partition_point_fn(10, 50, |x| x < threshold),
+                Some(threshold)
+            );
+        }
+
+        assert_eq!(partition_point_fn(10, 50, |_| true), None);
+        assert_eq!(partition_point_fn(5, 4, |_| false), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn partition_point_fn_handles_bounds_near_u64_max() {
+        let lo = u64::MAX - 1000;
+
+        for threshold in [lo, lo + 1, u64::MAX - 1, u64::MAX] {
+            let mut calls = Vec::new();
+            let found = partition_point_fn(lo, u64::MAX, |x| {
+                calls.push(x);
+                x < threshold
+            });
+
+            assert_eq!(found, Some(threshold));
+            assert!(calls.iter().all(|&x| x >= lo));
+
+            let len = calls.len();
+
+            calls.sort_unstable();
+            calls.dedup();
+            assert_eq!(calls.len(), len);
+        }
+
+        assert_eq!(partition_point_fn(0, u64::MAX, |_| true), None);
+        assert_eq!(partition_point_fn(0, u64::MAX, |_| false), Some(0));
+    }
+}
@@ -0,0 +1,283 @@
+//! Search over data viewed through a sorting permutation (an argsort)
+
+use crate::core;
+use std::{error::Error, fmt};
+
+/// Error returned by [`validate`] when the indices are not a sorting permutation of the data
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PermutationError {
+    /// There are not as many indices as elements
+    LengthMismatch {
+        /// The length of the data
+        data_len: usize,
+        /// The number of indices
+        indices_len: usize,
+    },
+    /// The index at `position` does not point into the data
+    OutOfRange {
+        /// The position of the offending index in the indices
+        position: usize,
+        /// The offending index
+        index: usize,
+    },
+    /// The index at `position` already appears earlier in the indices
+    Duplicate {
+        /// The position of the offending index in the indices
+        position: usize,
+        /// The offending index
+        index: usize,
+    },
+    /// The element referenced at `position` is smaller than the one referenced at `position - 1`
+    NotSorted {
+        /// The position of the first out of order index in the indices
+        position: usize,
+    },
+}
+
+impl fmt::Display for PermutationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LengthMismatch {
+                data_len,
+                indices_len,
+            } => write!(f, "{} indices given for {} elements", indices_len, data_len),
+            Self::OutOfRange { position, index } => {
+                write!(
+                    f,
+                    "index {} at position {} is out of range",
+                    index, position
+                )
+            }
+            Self::Duplicate { position, index } => {
+                write!(f, "index {} at position {} is a duplicate", index, position)
+            }
+            Self::NotSorted { position } => {
+                write!(
+                    f,
+                    "the permuted data is not sorted at position {}",
+                    position
+                )
+            }
+        }
+    }
+}
+
+impl Error for PermutationError {}
+
+/// Fixed-size set of indices storing one bit per index
+struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    /// Returns an empty set able to hold indices below `len`
+    fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    /// Inserts `index`, returning whether it was absent
+    fn insert(&mut self, index: usize) -> bool {
+        let word = &mut self.words[index / 64];
+        let mask = 1 << (index % 64);
+        let absent = *word & mask == 0;
+
+        *word |= mask;
+
+        absent
+    }
+}
+
+/// Validates that `indices` is a permutation of the indices of `data` that orders it, returning a view on which
+/// searches need no further validation.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::permuted;
+///
+/// let data = [30, 10, 20];
+/// let indices = [1, 2, 0];
+/// let permuted = permuted::validate(&data, &indices).unwrap();
+///
+/// assert_eq!(permuted.search(&30), Some(0));
+/// ```
+pub fn validate<'a, T>(
+    data: &'a [T],
+    indices: &'a [usize],
+) -> Result<PermutedSorted<'a, T>, PermutationError>
+where
+    T: Ord,
+{
+    if data.len() != indices.len() {
+        return Err(PermutationError::LengthMismatch {
+            data_len: data.len(),
+            indices_len: indices.len(),
+        });
+    }
+
+    let mut seen = BitSet::new(data.len());
+
+    for (position, &index) in indices.iter().enumerate() {
+        if index >= data.len() {
+            return Err(PermutationError::OutOfRange { position, index });
+        }
+
+        if !seen.insert(index) {
+            return Err(PermutationError::Duplicate { position, index });
+        }
+
+        if position > 0 && data[indices[position - 1]] > data[index] {
+            return Err(PermutationError::NotSorted { position });
+        }
+    }
+
+    Ok(PermutedSorted { data, indices })
+}
+
+/// Data viewed in the sorted order given by a validated permutation
+///
+/// Ranks are positions in the sorted view, while searches return indices into the data.
+#[derive(Debug, Clone, Copy)]
+pub struct PermutedSorted<'a, T> {
+    /// The unsorted data
+    data: &'a [T],
+    /// The indices of `data` in sorted order
+    indices: &'a [usize],
+}
+
+impl<'a, T> PermutedSorted<'a, T>
+where
+    T: Ord,
+{
+    /// Returns the index in the data of an element equal to `target`.
+    pub fn search(&self, target: &T) -> Option<usize> {
+        core::binary_search_by(self.indices, |&index| self.data[index].cmp(target))
+            .map(|position| self.indices[position])
+    }
+
+    /// Calculates the leftmost rank of `target` in the sorted view.
+    pub fn leftmost_rank(&self, target: &T) -> usize {
+        core::partition_point(self.indices, |&index| self.data[index] < *target)
+    }
+
+    /// Calculates the rightmost rank of `target` in the sorted view, or 0 if no element is smaller than or equal to
+    /// `target`.
+    pub fn rightmost_rank(&self, target: &T) -> usize {
+        core::partition_point(self.indices, |&index| self.data[index] <= *target).saturating_sub(1)
+    }
+
+    /// Returns the element at `rank` in the sorted view.
+    pub fn get(&self, rank: usize) -> Option<&'a T> {
+        self.indices.get(rank).map(|&index| &self.data[index])
+    }
+
+    /// Returns the number of elements
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns whether there are no elements
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod permuted_tests {
+    use super::{validate, PermutationError};
+
+    #[test]
+    fn validate_accepts_sorting_permutation() {
+        let data = [50, 10, 40, 20, 30, 20];
+        let indices = [1, 3, 5, 4, 2, 0];
+        let permuted = validate(&data, &indices).unwrap();
+
+        assert_eq!(permuted.search(&40), Some(2));
+        assert_eq!(permuted.search(&35), None);
+        assert_eq!(permuted.leftmost_rank(&20), 1);
+        assert_eq!(permuted.rightmost_rank(&20), 2);
+        assert_eq!(permuted.get(5), Some(&50));
+        assert_eq!(permuted.len(), 6);
+    }
+
+    #[test]
+    fn validate_accepts_empty_data() {
+        let data: [i32; 0] = [];
+        let indices = [];
+        let permuted = validate(&data, &indices).unwrap();
+
+        assert!(permuted.is_empty());
+        assert_eq!(permuted.search(&1), None);
+    }
+
+    #[test]
+    fn validate_rejects_length_mismatch() {
+        let data = [1, 2, 3];
+        let indices = [0, 1];
+
+        assert_eq!(
+            validate(&data, &indices).unwrap_err(),
+            PermutationError::LengthMismatch {
+                data_len: 3,
+                indices_len: 2
+            }
+        );
+    }
+
+    #[test]
+    fn validate_rejects_out_of_range_index() {
+        let data = [1, 2, 3];
+        let indices = [0, 3, 1];
+
+        assert_eq!(
+            validate(&data, &indices).unwrap_err(),
+            PermutationError::OutOfRange {
+                position: 1,
+                index: 3
+            }
+        );
+    }
+
+    #[test]
+    fn validate_rejects_duplicate_index() {
+        let data = [1, 1, 1];
+        let indices = [0, 1, 0];
+
+        assert_eq!(
+            validate(&data, &indices).unwrap_err(),
+            PermutationError::Duplicate {
+                position: 2,
+                index: 0
+            }
+        );
+    }
+
+    #[test]
+    fn validate_rejects_unordered_permutation() {
+        let data = [10, 30, 20, 40];
+        let indices = [0, 1, 2, 3];
+
+        assert_eq!(
+            validate(&data, &indices).unwrap_err(),
+            PermutationError::NotSorted { position: 2 }
+        );
+    }
+
+    #[test]
+    fn validate_detects_duplicates_across_bitset_words() {
+        let data: Vec<i32> = (0..200).collect();
+        let mut indices: Vec<usize> = (0..200).collect();
+
+        indices[130] = 70;
+
+        assert_eq!(
+            validate(&data, &indices).unwrap_err(),
+            PermutationError::Duplicate {
+                position: 130,
+                index: 70
+            }
+        );
+    }
+}
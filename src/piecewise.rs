@@ -0,0 +1,421 @@
+//! Search over arrays made of consecutive ascending and descending sorted segments
+
+use crate::{
+    core,
+    memory::{FieldUsage, MemoryBreakdown, MemoryUsage},
+    seam::SeamPolicy,
+    utils,
+};
+use std::{error::Error, fmt, ops::Range};
+
+/// The order in which a segment is sorted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Every element is smaller than or equal to the next one
+    Ascending,
+    /// Every element is greater than or equal to the next one
+    Descending,
+}
+
+/// Error returned when the segments given to [`PiecewiseSorted::new`] are invalid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PiecewiseError {
+    /// The segment does not start where the previous segment ends, or at 0 for the first segment
+    NotContiguous {
+        /// The index of the offending segment
+        segment: usize,
+    },
+    /// The segment is reversed or ends past the end of the array
+    OutOfBounds {
+        /// The index of the offending segment
+        segment: usize,
+    },
+    /// The segment is not sorted in its declared order
+    NotSorted {
+        /// The index of the offending segment
+        segment: usize,
+        /// The declared order of the segment
+        order: SortOrder,
+    },
+    /// The first element of the segment equals the last element of the previous segment, which
+    /// [`SeamPolicy::RequireStrictSeams`] forbids
+    EqualAcrossSeam {
+        /// The index of the segment starting at the seam
+        segment: usize,
+    },
+    /// The segments end before the end of the array
+    IncompleteCoverage {
+        /// The number of elements covered by the segments
+        covered: usize,
+        /// The length of the array
+        len: usize,
+    },
+}
+
+impl PiecewiseError {
+    /// Returns the index of the offending segment, if the error concerns a single segment
+    pub fn segment(&self) -> Option<usize> {
+        match self {
+            Self::NotContiguous { segment }
+            | Self::OutOfBounds { segment }
+            | Self::NotSorted { segment, .. }
+            | Self::EqualAcrossSeam { segment } => Some(*segment),
+            Self::IncompleteCoverage { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for PiecewiseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotContiguous { segment } => write!(
+                f,
+                "segment {} does not start where the previous segment ends",
+                segment
+            ),
+            Self::OutOfBounds { segment } => write!(f, "segment {} is out of bounds", segment),
+            Self::NotSorted { segment, order } => {
+                write!(f, "segment {} is not sorted in {:?} order", segment, order)
+            }
+            Self::EqualAcrossSeam { segment } => write!(
+                f,
+                "segment {} starts with an element equal to the end of the previous segment",
+                segment
+            ),
+            Self::IncompleteCoverage { covered, len } => write!(
+                f,
+                "segments cover {} elements of an array of length {}",
+                covered, len
+            ),
+        }
+    }
+}
+
+impl Error for PiecewiseError {}
+
+/// An array made of consecutive segments, each sorted either ascending or descending
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::piecewise::{PiecewiseSorted, SortOrder};
+///
+/// let arr = [1, 3, 5, 7, 6, 4, 2, 3, 5];
+/// let segments = vec![
+///     (0..4, SortOrder::Ascending),
+///     (4..7, SortOrder::Descending),
+///     (7..9, SortOrder::Ascending),
+/// ];
+/// let piecewise = PiecewiseSorted::new(&arr, segments).unwrap();
+///
+/// assert_eq!(piecewise.search(&3), vec![1, 7]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PiecewiseSorted<'a, T> {
+    /// The whole array
+    arr: &'a [T],
+    /// The validated segments, in array order
+    segments: Vec<(Range<usize>, SortOrder)>,
+}
+
+impl<'a, T> PiecewiseSorted<'a, T>
+where
+    T: Ord,
+{
+    /// Returns a new `PiecewiseSorted` after validating that `segments` cover `arr` in order and that each segment is
+    /// sorted in its declared order.
+    pub fn new(
+        arr: &'a [T],
+        segments: Vec<(Range<usize>, SortOrder)>,
+    ) -> Result<Self, PiecewiseError> {
+        Self::with_seam_policy(arr, segments, SeamPolicy::AllowEqualAcrossSeams)
+    }
+
+    /// Returns a new `PiecewiseSorted` like [`new`](Self::new), additionally requiring under
+    /// [`SeamPolicy::RequireStrictSeams`] that each non-empty segment starts with an element different from the last
+    /// element of the previous one.
+    pub fn with_seam_policy(
+        arr: &'a [T],
+        segments: Vec<(Range<usize>, SortOrder)>,
+        policy: SeamPolicy,
+    ) -> Result<Self, PiecewiseError> {
+        let mut covered = 0;
+
+        for (segment, (range, order)) in segments.iter().enumerate() {
+            if range.start != covered {
+                return Err(PiecewiseError::NotContiguous { segment });
+            }
+
+            if range.start > range.end || range.end > arr.len() {
+                return Err(PiecewiseError::OutOfBounds { segment });
+            }
+
+            let sorted = match order {
+                SortOrder::Ascending => utils::is_sorted(&arr[range.clone()]),
+                SortOrder::Descending => utils::is_sorted_by(&arr[range.clone()], |a, b| b.cmp(a)),
+            };
+
+            if !sorted {
+                return Err(PiecewiseError::NotSorted {
+                    segment,
+                    order: *order,
+                });
+            }
+
+            if policy == SeamPolicy::RequireStrictSeams
+                && range.start > 0
+                && range.start < range.end
+                && arr[range.start - 1] == arr[range.start]
+            {
+                return Err(PiecewiseError::EqualAcrossSeam { segment });
+            }
+
+            covered = range.end;
+        }
+
+        if covered != arr.len() {
+            return Err(PiecewiseError::IncompleteCoverage {
+                covered,
+                len: arr.len(),
+            });
+        }
+
+        Ok(Self { arr, segments })
+    }
+
+    /// Returns the indices of every element equal to `target`, in ascending order.
+    pub fn search(&self, target: &T) -> Vec<usize> {
+        self.matches(target).flatten().collect()
+    }
+
+    /// Returns the smallest index of an element equal to `target`.
+    pub fn first_match(&self, target: &T) -> Option<usize> {
+        self.matches(target).flatten().next()
+    }
+
+    /// Returns the number of elements equal to `target`.
+    pub fn count(&self, target: &T) -> usize {
+        self.matches(target).map(|range| range.len()).sum()
+    }
+
+    /// Returns the maximal ranges of consecutive elements equal to `target`, in ascending order.
+    ///
+    /// A run continuing past a seam is returned as a single range, so with
+    /// [`SeamPolicy::RequireStrictSeams`] every range lies within one segment.
+    pub fn equal_ranges(&self, target: &T) -> Vec<Range<usize>> {
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+
+        for range in self.matches(target).filter(|range| !range.is_empty()) {
+            match ranges.last_mut() {
+                Some(last) if last.end == range.start => last.end = range.end,
+                _ => ranges.push(range),
+            }
+        }
+
+        ranges
+    }
+
+    /// Returns the segments of the array
+    pub fn segments(&self) -> &[(Range<usize>, SortOrder)] {
+        &self.segments
+    }
+
+    /// Returns, for every segment, the absolute range of elements equal to `target`
+    fn matches<'s>(&'s self, target: &'s T) -> impl Iterator<Item = Range<usize>> + 's {
+        self.segments.iter().map(move |(range, order)| {
+            let segment = &self.arr[range.clone()];
+            let (start, end) = match order {
+                SortOrder::Ascending => (
+                    core::partition_point(segment, |element| element < target),
+                    core::partition_point(segment, |element| element <= target),
+                ),
+                SortOrder::Descending => (
+                    core::partition_point(segment, |element| element > target),
+                    core::partition_point(segment, |element| element >= target),
+                ),
+            };
+
+            range.start + start..range.start + end
+        })
+    }
+}
+
+impl<'a, T> MemoryUsage for PiecewiseSorted<'a, T> {
+    fn memory_bytes(&self) -> MemoryBreakdown {
+        MemoryBreakdown::new(self).with(FieldUsage::of_vec("segments", &self.segments))
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.segments.shrink_to_fit();
+    }
+}
+
+#[cfg(test)]
+mod piecewise_tests {
+    use super::{PiecewiseError, PiecewiseSorted, SortOrder};
+    use crate::seam::SeamPolicy;
+    use std::ops::Range;
+
+    #[test]
+    fn search_returns_matches_across_segments() {
+        let arr = [1, 3, 5, 7, 6, 4, 2, 3, 5];
+        let segments = vec![
+            (0..4, SortOrder::Ascending),
+            (4..7, SortOrder::Descending),
+            (7..9, SortOrder::Ascending),
+        ];
+        let piecewise = PiecewiseSorted::new(&arr, segments).unwrap();
+
+        assert_eq!(piecewise.search(&5), vec![2, 8]);
+        assert_eq!(piecewise.first_match(&5), Some(2));
+        assert_eq!(piecewise.count(&5), 2);
+        assert_eq!(piecewise.search(&4), vec![5]);
+    }
+
+    #[test]
+    fn search_returns_matches_on_shared_boundary_values() {
+        let arr = [1, 3, 5, 5, 3, 1, 1, 2];
+        let segments = vec![
+            (0..3, SortOrder::Ascending),
+            (3..6, SortOrder::Descending),
+            (6..8, SortOrder::Ascending),
+        ];
+        let piecewise = PiecewiseSorted::new(&arr, segments).unwrap();
+
+        assert_eq!(piecewise.search(&5), vec![2, 3]);
+        assert_eq!(piecewise.search(&1), vec![0, 5, 6]);
+        assert_eq!(piecewise.count(&3), 2);
+    }
+
+    #[test]
+    fn search_returns_nothing_if_target_not_in_arr() {
+        let arr = [1, 3, 5, 4, 2];
+        let segments = vec![(0..3, SortOrder::Ascending), (3..5, SortOrder::Descending)];
+        let piecewise = PiecewiseSorted::new(&arr, segments).unwrap();
+
+        assert!(piecewise.search(&6).is_empty());
+        assert_eq!(piecewise.first_match(&6), None);
+        assert_eq!(piecewise.count(&6), 0);
+    }
+
+    #[test]
+    fn search_works_with_single_segment() {
+        let arr = [9, 7, 7, 3];
+        let segments = vec![(0..4, SortOrder::Descending)];
+        let piecewise = PiecewiseSorted::new(&arr, segments).unwrap();
+
+        assert_eq!(piecewise.search(&7), vec![1, 2]);
+        assert_eq!(piecewise.first_match(&3), Some(3));
+    }
+
+    #[test]
+    fn new_rejects_segment_not_sorted_in_declared_order() {
+        let arr = [1, 3, 5, 4, 2];
+        let segments = vec![(0..3, SortOrder::Ascending), (3..5, SortOrder::Ascending)];
+        let error = PiecewiseSorted::new(&arr, segments).unwrap_err();
+
+        assert_eq!(
+            error,
+            PiecewiseError::NotSorted {
+                segment: 1,
+                order: SortOrder::Ascending
+            }
+        );
+        assert_eq!(error.segment(), Some(1));
+    }
+
+    #[test]
+    fn new_rejects_gaps_and_overlaps() {
+        let arr = [1, 2, 3, 4];
+        let gap = vec![(0..2, SortOrder::Ascending), (3..4, SortOrder::Ascending)];
+        let overlap = vec![(0..3, SortOrder::Ascending), (2..4, SortOrder::Ascending)];
+
+        assert_eq!(
+            PiecewiseSorted::new(&arr, gap).unwrap_err(),
+            PiecewiseError::NotContiguous { segment: 1 }
+        );
+        assert_eq!(
+            PiecewiseSorted::new(&arr, overlap).unwrap_err(),
+            PiecewiseError::NotContiguous { segment: 1 }
+        );
+    }
+
+    #[test]
+    fn new_rejects_out_of_bounds_and_incomplete_segments() {
+        let arr = [1, 2, 3, 4];
+        let out_of_bounds = vec![(0..2, SortOrder::Ascending), (2..5, SortOrder::Ascending)];
+        let incomplete = vec![(0..3, SortOrder::Ascending)];
+
+        assert_eq!(
+            PiecewiseSorted::new(&arr, out_of_bounds).unwrap_err(),
+            PiecewiseError::OutOfBounds { segment: 1 }
+        );
+        assert_eq!(
+            PiecewiseSorted::new(&arr, incomplete).unwrap_err(),
+            PiecewiseError::IncompleteCoverage { covered: 3, len: 4 }
+        );
+    }
+
+    /// Returns the maximal runs of elements equal to `target` by scanning `arr`
+    fn scanned_runs(target: i32, arr: &[i32]) -> Vec<Range<usize>> {
+        let mut runs: Vec<Range<usize>> = Vec::new();
+
+        for index in (0..arr.len()).filter(|&index| arr[index] == target) {
+            match runs.last_mut() {
+                Some(last) if last.end == index => last.end = index + 1,
+                _ => runs.push(index..index + 1),
+            }
+        }
+
+        runs
+    }
+
+    #[test]
+    fn equal_ranges_merges_runs_across_seams_when_lenient() {
+        let arr = [1, 3, 5, 5, 5, 5, 3, 1, 1, 2];
+        let segments = vec![
+            (0..3, SortOrder::Ascending),
+            (3..4, SortOrder::Ascending),
+            (4..8, SortOrder::Descending),
+            (8..10, SortOrder::Ascending),
+        ];
+        let piecewise =
+            PiecewiseSorted::with_seam_policy(&arr, segments, SeamPolicy::AllowEqualAcrossSeams)
+                .unwrap();
+
+        assert_eq!(piecewise.equal_ranges(&5), vec![2..6]);
+
+        for target in 0..7 {
+            assert_eq!(piecewise.equal_ranges(&target), scanned_runs(target, &arr));
+        }
+    }
+
+    #[test]
+    fn with_seam_policy_rejects_equal_across_seam_when_strict() {
+        let arr = [1, 3, 5, 5, 3, 1];
+        let segments = vec![(0..3, SortOrder::Ascending), (3..6, SortOrder::Descending)];
+        let error =
+            PiecewiseSorted::with_seam_policy(&arr, segments, SeamPolicy::RequireStrictSeams)
+                .unwrap_err();
+
+        assert_eq!(error, PiecewiseError::EqualAcrossSeam { segment: 1 });
+        assert_eq!(error.segment(), Some(1));
+    }
+
+    #[test]
+    fn equal_ranges_stay_within_segments_when_strict() {
+        let arr = [1, 3, 5, 5, 4, 3, 3, 4];
+        let segments = vec![
+            (0..4, SortOrder::Ascending),
+            (4..7, SortOrder::Descending),
+            (7..8, SortOrder::Ascending),
+        ];
+        let piecewise =
+            PiecewiseSorted::with_seam_policy(&arr, segments, SeamPolicy::RequireStrictSeams)
+                .unwrap();
+
+        for target in 0..7 {
+            assert_eq!(piecewise.equal_ranges(&target), scanned_runs(target, &arr));
+        }
+    }
+}
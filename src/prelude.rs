@@ -0,0 +1,66 @@
+//! The most used items of the crate, for glob importing
+//!
+//! The items keep their canonical homes in their own modules; this only re-exports them.
+//!
+//! # Examples
+//!
+//! ```
+//! use binary_search::prelude::*;
+//!
+//! let arr = [1, 2, 4, 4, 4, 5];
+//!
+//! assert_eq!(binary_search(&5, &arr), Some(5));
+//! assert_eq!(lower_bound(&4, &arr)..upper_bound(&4, &arr), equal_range(&4, &arr));
+//! assert_eq!(arr.bsearch(&2), Some(1));
+//! ```
+
+pub use crate::{
+    binary_search, binary_search_by, binary_search_by_key, contains,
+    ext::SliceSearchExt,
+    partition_point,
+    ranks::{equal_range, leftmost_rank, lower_bound, rightmost_rank, upper_bound},
+    sorted::SortedSlice,
+    variations::{Algorithm, Searcher},
+};
+
+#[cfg(test)]
+mod prelude_tests {
+    #[test]
+    fn prelude_glob_import_brings_common_items_into_scope() {
+        use crate::prelude::*;
+
+        let arr = [1, 2, 4, 4, 4, 5];
+        let sorted = SortedSlice::new(&arr).unwrap();
+
+        assert_eq!(binary_search_by(&arr, |element| element.cmp(&2)), Some(1));
+        assert_eq!(
+            binary_search_by_key(&10, &arr, |element| element * 2),
+            Some(5)
+        );
+        assert_eq!(leftmost_rank(&4, &arr), 2);
+        assert_eq!(rightmost_rank(&4, &arr), 4);
+        assert_eq!(partition_point(&arr, |&element| element < 3), 2);
+        assert!(contains(&5, &arr));
+        assert_eq!(sorted.leftmost_rank(&5), 5);
+    }
+
+    #[test]
+    fn ranks_are_reachable_from_crate_root_and_module() {
+        let arr = [1, 2, 4, 4, 4, 5];
+
+        assert_eq!(
+            crate::lower_bound(&4, &arr),
+            crate::ranks::lower_bound(&4, &arr)
+        );
+        assert_eq!(
+            crate::upper_bound(&4, &arr),
+            crate::ranks::upper_bound(&4, &arr)
+        );
+        assert_eq!(
+            crate::equal_range(&4, &arr),
+            crate::ranks::equal_range(&4, &arr)
+        );
+        assert_eq!(crate::leftmost_rank(&4, &arr), 2);
+        assert_eq!(crate::rightmost_rank(&4, &arr), 4);
+    }
+}
@@ -0,0 +1,288 @@
+//! Searches over any container with indexed access, rather than only over slices
+//!
+//! Implement [`RandomAccess`] for a container to search it with [`search_in`] and the rank functions in this module.
+//! The slice functions of the crate stay as they are, so searching a slice does not go through the trait.
+//!
+//! # Examples
+//!
+//! A paged buffer, whose elements are not contiguous:
+//!
+//! ```
+//! use binary_search::random_access::{self, RandomAccess};
+//!
+//! struct Paged {
+//!     pages: Vec<Vec<u32>>,
+//!     len: usize,
+//! }
+//!
+//! const PAGE_LEN: usize = 4;
+//!
+//! impl RandomAccess<u32> for Paged {
+//!     fn len(&self) -> usize {
+//!         self.len
+//!     }
+//!
+//!     fn get(&self, index: usize) -> &u32 {
+//!         &self.pages[index / PAGE_LEN][index % PAGE_LEN]
+//!     }
+//! }
+//!
+//! let paged = Paged {
+//!     pages: vec![vec![1, 3, 5, 7], vec![9, 11, 11, 15], vec![17]],
+//!     len: 9,
+//! };
+//!
+//! assert_eq!(random_access::search_in(&9, &paged), Some(4));
+//! assert_eq!(random_access::leftmost_rank_in(&11, &paged), 5);
+//! assert_eq!(random_access::rightmost_rank_in(&11, &paged), 6);
+//! ```
+
+use crate::utils;
+use ::core::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+/// A container whose elements can be read by index in constant time
+pub trait RandomAccess<T> {
+    /// Returns the number of elements.
+    fn len(&self) -> usize;
+
+    /// Returns the element at `index`.
+    ///
+    /// The searches only call this with indices smaller than [`len`](Self::len).
+    fn get(&self, index: usize) -> &T;
+
+    /// Returns `true` if there are no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> RandomAccess<T> for [T] {
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn get(&self, index: usize) -> &T {
+        &self[index]
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> RandomAccess<T> for Vec<T> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn get(&self, index: usize) -> &T {
+        &self[index]
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> RandomAccess<T> for Box<[T]> {
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn get(&self, index: usize) -> &T {
+        &self[index]
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> RandomAccess<T> for VecDeque<T> {
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+
+    fn get(&self, index: usize) -> &T {
+        &self[index]
+    }
+}
+
+/// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) on `seq` in order to find the index
+/// of `target`, like [`binary_search`](crate::binary_search) on a slice.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")]
+/// # {
+/// use binary_search::random_access;
+/// use std::collections::VecDeque;
+///
+/// let mut deque: VecDeque<i32> = (3..8).collect();
+///
+/// deque.push_front(1);
+///
+/// assert_eq!(random_access::search_in(&5, &deque), Some(3));
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the container is not sorted.
+pub fn search_in<T, A>(target: &T, seq: &A) -> Option<usize>
+where
+    T: Ord,
+    A: RandomAccess<T> + ?Sized,
+{
+    check_sorted(seq);
+
+    let mut left = 0;
+    let mut right = seq.len();
+
+    while left < right {
+        let middle = utils::midpoint(left, right);
+
+        match seq.get(middle).cmp(target) {
+            Ordering::Equal => return Some(middle),
+            Ordering::Greater => right = middle,
+            Ordering::Less => left = middle + 1,
+        }
+    }
+
+    None
+}
+
+/// Calculates the leftmost rank of `target` in `seq`, i.e. the number of elements smaller than it, like
+/// [`leftmost_rank`](crate::leftmost_rank) on a slice.
+///
+/// # Panics
+///
+/// The function panics if the container is not sorted.
+pub fn leftmost_rank_in<T, A>(target: &T, seq: &A) -> usize
+where
+    T: Ord,
+    A: RandomAccess<T> + ?Sized,
+{
+    check_sorted(seq);
+
+    partition_point(seq, |element| element < target)
+}
+
+/// Calculates the rightmost rank of `target` in `seq`, like [`rightmost_rank`](crate::rightmost_rank) on a slice.
+///
+/// Returns 0 if no element is smaller than or equal to `target`.
+///
+/// # Panics
+///
+/// The function panics if the container is not sorted.
+pub fn rightmost_rank_in<T, A>(target: &T, seq: &A) -> usize
+where
+    T: Ord,
+    A: RandomAccess<T> + ?Sized,
+{
+    check_sorted(seq);
+
+    partition_point(seq, |element| element <= target).saturating_sub(1)
+}
+
+/// Panics if `seq` is not sorted
+fn check_sorted<T, A>(seq: &A)
+where
+    T: Ord,
+    A: RandomAccess<T> + ?Sized,
+{
+    if (1..seq.len()).any(|index| seq.get(index - 1) > seq.get(index)) {
+        panic!("Binary search encountered an array that is note sorted");
+    }
+}
+
+/// Returns the index of the first element of `seq` for which `pred` is false
+fn partition_point<T, A, P>(seq: &A, mut pred: P) -> usize
+where
+    A: RandomAccess<T> + ?Sized,
+    P: FnMut(&T) -> bool,
+{
+    let mut left = 0;
+    let mut right = seq.len();
+
+    while left < right {
+        let middle = utils::midpoint(left, right);
+
+        if pred(seq.get(middle)) {
+            left = middle + 1;
+        } else {
+            right = middle;
+        }
+    }
+
+    left
+}
+
+#[cfg(test)]
+mod random_access_tests {
+    #[cfg(feature = "std")]
+    use super::{leftmost_rank_in, rightmost_rank_in, search_in, RandomAccess};
+    #[cfg(feature = "std")]
+    use crate::{binary_search, leftmost_rank, rightmost_rank};
+    #[cfg(feature = "std")]
+    use std::collections::VecDeque;
+
+    /// Container storing its elements in reverse, so that indexing is not slice indexing
+    #[cfg(feature = "std")]
+    struct Reversed(Vec<i32>);
+
+    #[cfg(feature = "std")]
+    impl RandomAccess<i32> for Reversed {
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn get(&self, index: usize) -> &i32 {
+            &self.0[self.0.len() - 1 - index]
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic(expected = "Binary search encountered an array that is note sorted")]
+    fn search_in_panics_when_container_is_not_sorted() {
+        let deque: VecDeque<i32> = vec![1, 3, 2, 5].into();
+
+        search_in(&5, &deque);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn searches_match_slice_functions_for_every_container() {
+        let arr = [1, 2, 4, 4, 4, 5, 6, 7];
+        let vec = arr.to_vec();
+        let boxed: Box<[i32]> = vec.clone().into_boxed_slice();
+        let mut deque: VecDeque<i32> = arr[2..].iter().copied().collect();
+        let reversed = Reversed(arr.iter().rev().copied().collect());
+
+        deque.push_front(2);
+        deque.push_front(1);
+
+        for target in 0..9 {
+            let found = binary_search(&target, &arr);
+            let leftmost = leftmost_rank(&target, &arr);
+            let rightmost = rightmost_rank(&target, &arr);
+
+            assert_eq!(search_in(&target, &arr[..]), found);
+            assert_eq!(search_in(&target, &vec), found);
+            assert_eq!(search_in(&target, &boxed), found);
+            assert_eq!(search_in(&target, &deque), found);
+            assert_eq!(search_in(&target, &reversed), found);
+
+            assert_eq!(leftmost_rank_in(&target, &deque), leftmost);
+            assert_eq!(leftmost_rank_in(&target, &reversed), leftmost);
+            assert_eq!(rightmost_rank_in(&target, &deque), rightmost);
+            assert_eq!(rightmost_rank_in(&target, &reversed), rightmost);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn searches_handle_empty_containers() {
+        let deque: VecDeque<i32> = VecDeque::new();
+
+        assert!(deque.is_empty());
+        assert_eq!(search_in(&1, &deque), None);
+        assert_eq!(leftmost_rank_in(&1, &deque), 0);
+        assert_eq!(rightmost_rank_in(&1, &deque), 0);
+    }
+}
@@ -1,6 +1,13 @@
-//! Contains functions for checking leftmost and rightmost rank
+//! Contains functions for checking leftmost and rightmost rank, finding predecessors and successors, and counting
+//! occurrences
+//!
+//! Every rank is a [`partition_point`](crate::partition_point) of the array for a comparison with the target.
 
-use crate::utils;
+use crate::{core, utils};
+use ::core::{
+    cmp::Ordering,
+    ops::{Range, RangeBounds},
+};
 
 /// Calculates the leftmost rank of the given target in the array.
 ///
@@ -22,77 +29,597 @@ pub fn leftmost_rank<T>(target: &T, arr: &[T]) -> usize
 where
     T: Ord,
 {
-    if !utils::is_sorted(&arr) {
+    if !utils::is_sorted(arr) {
         panic!("Binary search encountered an array that is note sorted");
     }
 
-    if arr.is_empty() {
-        return 0;
+    core::lower_bound(target, arr)
+}
+
+/// Calculates the rightmost rank of the given target in the array.
+///
+/// Returns 0 if no element is smaller than or equal to `target`.
+///
+/// # Examples
+/// ```
+/// use binary_search::ranks;
+///
+/// let target = 4;
+/// let arr = [1, 2, 4, 4, 4, 5, 6, 7];
+/// let rank = ranks::rightmost_rank(&target, &arr);
+///
+/// assert_eq!(rank, 4);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn rightmost_rank<T>(target: &T, arr: &[T]) -> usize
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Binary search encountered an array that is note sorted");
     }
 
-    let mut left = 0;
-    let mut right = arr.len();
+    core::upper_bound(target, arr).saturating_sub(1)
+}
 
-    while left < right {
-        let middle = (left + right) / 2;
+/// Calculates the leftmost rank of a target in the array, given by a comparator: the number of elements for which
+/// `compare` returns [`Ordering::Less`].
+///
+/// `compare` orders an element relative to the target, like the comparator of
+/// [`binary_search_by`](crate::binary_search_by). This allows ranks by a derived key or in a custom order.
+///
+/// # Examples
+/// ```
+/// use binary_search::ranks;
+///
+/// let arr = [(1, 'a'), (2, 'b'), (4, 'c'), (4, 'd'), (5, 'e')];
+/// let rank = ranks::leftmost_rank_by(&arr, |element| element.0.cmp(&4));
+///
+/// assert_eq!(rank, 2);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted relative to the target, i.e. if an element for which `compare`
+/// returns [`Ordering::Less`] follows one for which it does not, or likewise for [`Ordering::Greater`].
+pub fn leftmost_rank_by<T, F>(arr: &[T], mut compare: F) -> usize
+where
+    F: FnMut(&T) -> Ordering,
+{
+    if !utils::is_sorted_relative_to(arr, &mut compare) {
+        panic!("Binary search encountered an array that is note sorted");
+    }
 
-        if arr[middle] < *target {
-            left = middle + 1;
-        } else {
-            right = middle;
-        }
+    core::partition_point(arr, |element| compare(element) == Ordering::Less)
+}
+
+/// Calculates the rightmost rank of a target in the array, given by a comparator like in [`leftmost_rank_by`]. This
+/// is the same as [`rightmost_rank`] with the order of `compare`.
+///
+/// Returns 0 if `compare` returns [`Ordering::Greater`] for every element.
+///
+/// # Examples
+/// ```
+/// use binary_search::ranks;
+///
+/// let arr = [(1, 'a'), (2, 'b'), (4, 'c'), (4, 'd'), (5, 'e')];
+/// let rank = ranks::rightmost_rank_by(&arr, |element| element.0.cmp(&4));
+///
+/// assert_eq!(rank, 3);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted relative to the target, like [`leftmost_rank_by`].
+pub fn rightmost_rank_by<T, F>(arr: &[T], mut compare: F) -> usize
+where
+    F: FnMut(&T) -> Ordering,
+{
+    if !utils::is_sorted_relative_to(arr, &mut compare) {
+        panic!("Binary search encountered an array that is note sorted");
     }
 
-    left
+    core::partition_point(arr, |element| compare(element) != Ordering::Greater).saturating_sub(1)
 }
 
-/// Calculates the rightmost rank of the given target in the array.
+/// Calculates the number of elements smaller than the given target in the array, i.e. the first index at which it
+/// could be inserted while keeping the array sorted. This is the same as [`leftmost_rank`].
+///
+/// # Examples
+/// ```
+/// use binary_search::ranks;
+///
+/// let arr = [1, 2, 4, 4, 4, 5];
+///
+/// assert_eq!(ranks::lower_bound(&4, &arr), 2);
+/// assert_eq!(ranks::lower_bound(&3, &arr), 2);
+/// assert_eq!(ranks::lower_bound(&9, &arr), 6);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn lower_bound<T>(target: &T, arr: &[T]) -> usize
+where
+    T: Ord,
+{
+    leftmost_rank(target, arr)
+}
+
+/// Calculates the number of elements smaller than or equal to the given target in the array, i.e. the last index at
+/// which it could be inserted while keeping the array sorted.
+///
+/// Unlike [`rightmost_rank`], this is defined for every target, including those below the first element.
+///
+/// # Examples
+/// ```
+/// use binary_search::ranks;
+///
+/// let arr = [1, 2, 4, 4, 4, 5];
+///
+/// assert_eq!(ranks::upper_bound(&4, &arr), 5);
+/// assert_eq!(ranks::upper_bound(&0, &arr), 0);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn upper_bound<T>(target: &T, arr: &[T]) -> usize
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Binary search encountered an array that is note sorted");
+    }
+
+    core::upper_bound(target, arr)
+}
+
+/// Counts how many times the given target appears in the array.
+///
+/// # Examples
+/// ```
+/// use binary_search::ranks;
+///
+/// let target = 4;
+/// let arr = [1, 2, 4, 4, 4, 5];
+/// let count = ranks::count(&target, &arr);
+///
+/// assert_eq!(count, 3);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn count<T>(target: &T, arr: &[T]) -> usize
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Binary search encountered an array that is note sorted");
+    }
+
+    core::upper_bound(target, arr) - core::lower_bound(target, arr)
+}
+
+/// Calculates the half-open range of indices of the elements equal to the given target in the array.
+///
+/// If the target is absent, the range is empty and starts at the index where the target would be inserted.
+///
+/// # Examples
+/// ```
+/// use binary_search::ranks;
+///
+/// let target = 4;
+/// let arr = [1, 2, 4, 4, 4, 5, 6, 7];
+/// let range = ranks::equal_range(&target, &arr);
+///
+/// assert_eq!(range, 2..5);
+/// assert_eq!(&arr[range], &[4, 4, 4]);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn equal_range<T>(target: &T, arr: &[T]) -> Range<usize>
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Binary search encountered an array that is note sorted");
+    }
+
+    core::lower_bound(target, arr)..core::upper_bound(target, arr)
+}
+
+/// Returns an iterator over the indices of every element equal to the given target in the array, in ascending order.
+///
+/// # Examples
+/// ```
+/// use binary_search::ranks;
+///
+/// let target = 4;
+/// let arr = [1, 2, 4, 4, 4, 5];
+/// let indices: Vec<usize> = ranks::all_occurrences(&target, &arr).collect();
+///
+/// assert_eq!(indices, [2, 3, 4]);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn all_occurrences<'a, T>(target: &T, arr: &'a [T]) -> impl Iterator<Item = usize> + 'a
+where
+    T: Ord,
+{
+    equal_range(target, arr)
+}
+
+/// Calculates the index of the first element equal to the given target in the array, or `None` if it is absent.
+/// This is the same as [`binary_search_first`](crate::binary_search_first).
+///
+/// Unlike [`leftmost_rank`], an absent target does not return the index it would be inserted at.
+///
+/// # Examples
+/// ```
+/// use binary_search::ranks;
+///
+/// let arr = [1, 2, 4, 4, 4, 5];
+///
+/// assert_eq!(ranks::first(&4, &arr), Some(2));
+/// assert_eq!(ranks::first(&3, &arr), None);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn first<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    crate::binary_search_first(target, arr)
+}
+
+/// Calculates the index of the last element equal to the given target in the array, or `None` if it is absent.
+/// This is the same as [`binary_search_last`](crate::binary_search_last).
+///
+/// Unlike [`rightmost_rank`], an absent target does not return a rank.
+///
+/// # Examples
+/// ```
+/// use binary_search::ranks;
+///
+/// let arr = [1, 2, 4, 4, 4, 5];
+///
+/// assert_eq!(ranks::last(&4, &arr), Some(4));
+/// assert_eq!(ranks::last(&3, &arr), None);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn last<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    crate::binary_search_last(target, arr)
+}
+
+/// Calculates the index of the first element greater than or equal to the given target in the array, or `None` if
+/// every element is smaller.
+///
+/// # Examples
+/// ```
+/// use binary_search::ranks;
+///
+/// let arr = [1, 2, 4, 4, 4, 5];
+///
+/// assert_eq!(ranks::first_ge(&4, &arr), Some(2));
+/// assert_eq!(ranks::first_ge(&3, &arr), Some(2));
+/// assert_eq!(ranks::first_ge(&6, &arr), None);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn first_ge<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Binary search encountered an array that is note sorted");
+    }
+
+    core::ceil(target, arr)
+}
+
+/// Calculates the index of the first element strictly greater than the given target in the array, or `None` if
+/// every element is smaller than or equal to it.
+///
+/// # Examples
+/// ```
+/// use binary_search::ranks;
+///
+/// let arr = [1, 2, 4, 4, 4, 5];
+///
+/// assert_eq!(ranks::first_gt(&4, &arr), Some(5));
+/// assert_eq!(ranks::first_gt(&5, &arr), None);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn first_gt<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Binary search encountered an array that is note sorted");
+    }
+
+    let rank = core::upper_bound(target, arr);
+
+    if rank < arr.len() {
+        Some(rank)
+    } else {
+        None
+    }
+}
+
+/// Calculates the index of the last element smaller than or equal to the given target in the array, or `None` if
+/// every element is greater.
+///
+/// # Examples
+/// ```
+/// use binary_search::ranks;
+///
+/// let arr = [1, 2, 4, 4, 4, 5];
+///
+/// assert_eq!(ranks::last_le(&4, &arr), Some(4));
+/// assert_eq!(ranks::last_le(&3, &arr), Some(1));
+/// assert_eq!(ranks::last_le(&0, &arr), None);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn last_le<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Binary search encountered an array that is note sorted");
+    }
+
+    core::floor(target, arr)
+}
+
+/// Calculates the index of the last element strictly smaller than the given target in the array, or `None` if every
+/// element is greater than or equal to it.
+///
+/// # Examples
+/// ```
+/// use binary_search::ranks;
+///
+/// let arr = [1, 2, 4, 4, 4, 5];
+///
+/// assert_eq!(ranks::last_lt(&4, &arr), Some(1));
+/// assert_eq!(ranks::last_lt(&1, &arr), None);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn last_lt<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Binary search encountered an array that is note sorted");
+    }
+
+    core::lower_bound(target, arr).checked_sub(1)
+}
+
+/// Calculates the index of the largest element smaller than or equal to the given target in the array, i.e. its
+/// predecessor. Among equal elements, the last one is returned. This is the same as [`last_le`].
+///
+/// # Examples
+/// ```
+/// use binary_search::ranks;
+///
+/// let arr = [1, 2, 4, 4, 4, 5, 6, 7];
+///
+/// assert_eq!(ranks::floor(&4, &arr), Some(4));
+/// assert_eq!(ranks::floor(&3, &arr), Some(1));
+/// assert_eq!(ranks::floor(&0, &arr), None);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn floor<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    last_le(target, arr)
+}
+
+/// Calculates the index of the smallest element greater than or equal to the given target in the array, i.e. its
+/// successor. Among equal elements, the first one is returned. This is the same as [`first_ge`].
+///
+/// # Examples
+/// ```
+/// use binary_search::ranks;
+///
+/// let arr = [1, 2, 4, 4, 4, 5, 6, 7];
+///
+/// assert_eq!(ranks::ceil(&4, &arr), Some(2));
+/// assert_eq!(ranks::ceil(&3, &arr), Some(2));
+/// assert_eq!(ranks::ceil(&8, &arr), None);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn ceil<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    first_ge(target, arr)
+}
+
+/// Calculates the index of the largest element strictly smaller than the given target in the array. Among equal
+/// elements, the last one is returned. This is the same as [`last_lt`].
+///
+/// # Examples
+/// ```
+/// use binary_search::ranks;
+///
+/// let arr = [1, 2, 4, 4, 4, 5, 6, 7];
+///
+/// assert_eq!(ranks::lower(&4, &arr), Some(1));
+/// assert_eq!(ranks::lower(&1, &arr), None);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn lower<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    last_lt(target, arr)
+}
+
+/// Calculates the index of the smallest element strictly greater than the given target in the array. Among equal
+/// elements, the first one is returned. This is the same as [`first_gt`].
+///
+/// # Examples
+/// ```
+/// use binary_search::ranks;
+///
+/// let arr = [1, 2, 4, 4, 4, 5, 6, 7];
+///
+/// assert_eq!(ranks::higher(&4, &arr), Some(5));
+/// assert_eq!(ranks::higher(&7, &arr), None);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn higher<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    first_gt(target, arr)
+}
+
+/// Calculates the indices of the predecessor and the successor of the given target in the array, i.e. of the largest
+/// element smaller than or equal to it and of the smallest element greater than or equal to it.
+///
+/// This is the same as ([`floor`], [`ceil`]), with a single check of the order of the array. When `target` is in the
+/// array both point at elements equal to it, the last and the first one respectively. When it is past one end of the
+/// array, that side is `None`.
 ///
 /// # Examples
 /// ```
 /// use binary_search::ranks;
 ///
-/// let target = 4;
 /// let arr = [1, 2, 4, 4, 4, 5, 6, 7];
-/// let rank = ranks::rightmost_rank(&target, &arr);
 ///
-/// assert_eq!(rank, 4);
+/// assert_eq!(ranks::neighbors(&3, &arr), (Some(1), Some(2)));
+/// assert_eq!(ranks::neighbors(&4, &arr), (Some(4), Some(2)));
+/// assert_eq!(ranks::neighbors(&0, &arr), (None, Some(0)));
+/// assert_eq!(ranks::neighbors(&8, &arr), (Some(7), None));
 /// ```
 ///
 /// # Panics
 ///
 /// The function panics if the array is not sorted.
-pub fn rightmost_rank<T>(target: &T, arr: &[T]) -> usize
+pub fn neighbors<T>(target: &T, arr: &[T]) -> (Option<usize>, Option<usize>)
 where
     T: Ord,
 {
-    if !utils::is_sorted(&arr) {
+    if !utils::is_sorted(arr) {
         panic!("Binary search encountered an array that is note sorted");
     }
 
-    if arr.is_empty() {
-        return 0;
-    }
+    let rank = core::lower_bound(target, arr);
+    // Elements equal to `target` can only follow `rank`, so the upper bound is searched in the rest of the array
+    let upper = rank + core::upper_bound(target, &arr[rank..]);
+    let successor = if rank < arr.len() { Some(rank) } else { None };
 
-    let mut left = 0;
-    let mut right = arr.len();
+    (upper.checked_sub(1), successor)
+}
 
-    while left < right {
-        let middle = (left + right) / 2;
+/// Counts the elements of the array within the given range of values.
+///
+/// Any kind of bound is supported, and a reversed range counts nothing.
+///
+/// # Examples
+/// ```
+/// use binary_search::ranks;
+///
+/// let arr = [5, 10, 12, 15, 20, 20, 25];
+///
+/// assert_eq!(ranks::count_in_range(&arr, 10..=20), 5);
+/// assert_eq!(ranks::count_in_range(&arr, ..12), 2);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn count_in_range<T, R>(arr: &[T], range: R) -> usize
+where
+    T: Ord,
+    R: RangeBounds<T>,
+{
+    slice_in_range(arr, range).len()
+}
 
-        if arr[middle] > *target {
-            right = middle;
-        } else {
-            left = middle + 1;
-        }
+/// Returns the sub-slice of the elements of the array within the given range of values.
+///
+/// Any kind of bound is supported, and a reversed range gives an empty slice.
+///
+/// # Examples
+/// ```
+/// use binary_search::ranks;
+/// use std::ops::Bound;
+///
+/// let arr = [5, 10, 12, 15, 20, 20, 25];
+///
+/// assert_eq!(ranks::slice_in_range(&arr, 12..20), &[12, 15]);
+/// assert_eq!(ranks::slice_in_range(&arr, (Bound::Excluded(15), Bound::Unbounded)), &[20, 20, 25]);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn slice_in_range<T, R>(arr: &[T], range: R) -> &[T]
+where
+    T: Ord,
+    R: RangeBounds<T>,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Binary search encountered an array that is note sorted");
     }
 
-    right - 1
+    &arr[core::range_indices(arr, range)]
 }
 
 #[cfg(test)]
 mod ranks_tests {
-    use super::{leftmost_rank, rightmost_rank};
+    use super::{
+        all_occurrences, ceil, count, count_in_range, equal_range, first, first_ge, first_gt,
+        floor, higher, last, last_le, last_lt, leftmost_rank, leftmost_rank_by, lower, lower_bound,
+        neighbors, rightmost_rank, rightmost_rank_by, slice_in_range, upper_bound,
+    };
+    use crate::partition_point;
+    use core::ops::Bound;
 
     #[test]
     #[should_panic(expected = "Binary search encountered an array that is note sorted")]
@@ -165,4 +692,503 @@ mod ranks_tests {
 
         assert_eq!(rank, 7);
     }
+
+    #[test]
+    fn rightmost_rank_returns_zero_if_target_below_every_element() {
+        let target = 0;
+        let arr = [1, 2, 4, 4, 4, 5, 6, 7];
+        let rank = rightmost_rank(&target, &arr);
+
+        assert_eq!(rank, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Binary search encountered an array that is note sorted")]
+    fn count_panics_if_the_arr_is_not_sorted() {
+        let target = 4;
+        let arr = [1, 2, 5, 4, 4, 6];
+
+        count(&target, &arr);
+    }
+
+    #[test]
+    fn count_returns_zero_if_arr_is_empty() {
+        let target = 4;
+        let arr: [i32; 0] = [];
+        let occurrences = count(&target, &arr);
+
+        assert_eq!(occurrences, 0);
+    }
+
+    #[test]
+    fn count_returns_the_number_of_duplicates() {
+        let target = 4;
+        let arr = [1, 2, 4, 4, 4, 5];
+        let occurrences = count(&target, &arr);
+
+        assert_eq!(occurrences, 3);
+    }
+
+    #[test]
+    fn count_returns_one_for_a_single_occurrence() {
+        let target = 2;
+        let arr = [1, 2, 4, 4, 4, 5];
+        let occurrences = count(&target, &arr);
+
+        assert_eq!(occurrences, 1);
+    }
+
+    #[test]
+    fn count_returns_zero_if_target_not_in_arr() {
+        let target = 3;
+        let arr = [1, 2, 4, 4, 4, 5];
+        let occurrences = count(&target, &arr);
+
+        assert_eq!(occurrences, 0);
+    }
+
+    #[test]
+    fn equal_range_returns_empty_range_if_arr_is_empty() {
+        let target = 4;
+        let arr: [i32; 0] = [];
+        let range = equal_range(&target, &arr);
+
+        assert_eq!(range, 0..0);
+    }
+
+    #[test]
+    fn equal_range_returns_the_range_of_duplicates() {
+        let target = 4;
+        let arr = [1, 2, 4, 4, 4, 5, 6, 7];
+        let range = equal_range(&target, &arr);
+
+        assert_eq!(range, 2..5);
+    }
+
+    #[test]
+    fn equal_range_returns_the_range_of_a_single_occurrence() {
+        let target = 5;
+        let arr = [1, 2, 4, 4, 4, 5, 6, 7];
+        let range = equal_range(&target, &arr);
+
+        assert_eq!(range, 5..6);
+    }
+
+    #[test]
+    fn equal_range_returns_empty_range_at_insertion_point_if_target_not_in_arr() {
+        let arr = [1, 2, 4, 4, 4, 5, 6, 7];
+
+        assert_eq!(equal_range(&3, &arr), 2..2);
+        assert_eq!(equal_range(&0, &arr), 0..0);
+        assert_eq!(equal_range(&8, &arr), 8..8);
+    }
+
+    #[test]
+    fn ranks_match_partition_point_of_comparisons() {
+        let arr = [1, 2, 4, 4, 4, 5, 6, 7];
+
+        for target in 1..=8 {
+            let below = partition_point(&arr, |element| *element < target);
+            let at_or_below = partition_point(&arr, |element| *element <= target);
+
+            assert_eq!(leftmost_rank(&target, &arr), below);
+            assert_eq!(rightmost_rank(&target, &arr), at_or_below - 1);
+            assert_eq!(equal_range(&target, &arr), below..at_or_below);
+        }
+    }
+
+    #[test]
+    fn floor_and_ceil_return_none_for_empty_arr() {
+        let target = 5;
+        let arr: [i32; 0] = [];
+
+        assert_eq!(floor(&target, &arr), None);
+        assert_eq!(ceil(&target, &arr), None);
+        assert_eq!(lower(&target, &arr), None);
+        assert_eq!(higher(&target, &arr), None);
+    }
+
+    #[test]
+    fn floor_and_ceil_handle_target_below_first_element() {
+        let target = 0;
+        let arr = [1, 2, 4, 4, 4, 5];
+
+        assert_eq!(floor(&target, &arr), None);
+        assert_eq!(ceil(&target, &arr), Some(0));
+        assert_eq!(lower(&target, &arr), None);
+        assert_eq!(higher(&target, &arr), Some(0));
+    }
+
+    #[test]
+    fn floor_and_ceil_handle_target_above_last_element() {
+        let target = 9;
+        let arr = [1, 2, 4, 4, 4, 5];
+
+        assert_eq!(floor(&target, &arr), Some(5));
+        assert_eq!(ceil(&target, &arr), None);
+        assert_eq!(lower(&target, &arr), Some(5));
+        assert_eq!(higher(&target, &arr), None);
+    }
+
+    #[test]
+    fn floor_and_ceil_handle_target_equal_to_element() {
+        let target = 2;
+        let arr = [1, 2, 4, 4, 4, 5];
+
+        assert_eq!(floor(&target, &arr), Some(1));
+        assert_eq!(ceil(&target, &arr), Some(1));
+        assert_eq!(lower(&target, &arr), Some(0));
+        assert_eq!(higher(&target, &arr), Some(2));
+    }
+
+    #[test]
+    fn floor_and_ceil_handle_duplicates() {
+        let target = 4;
+        let arr = [1, 2, 4, 4, 4, 5];
+
+        assert_eq!(floor(&target, &arr), Some(4));
+        assert_eq!(ceil(&target, &arr), Some(2));
+        assert_eq!(lower(&target, &arr), Some(1));
+        assert_eq!(higher(&target, &arr), Some(5));
+    }
+
+    #[test]
+    fn floor_and_ceil_handle_target_between_duplicates() {
+        let target = 3;
+        let arr = [1, 2, 2, 4, 4, 5];
+
+        assert_eq!(floor(&target, &arr), Some(2));
+        assert_eq!(ceil(&target, &arr), Some(3));
+        assert_eq!(lower(&target, &arr), Some(2));
+        assert_eq!(higher(&target, &arr), Some(3));
+    }
+
+    /// Computes the four bounds of `target` with a linear scan, in the order first_ge, first_gt, last_le, last_lt
+    fn linear_bounds(target: i32, arr: &[i32]) -> [Option<usize>; 4] {
+        [
+            arr.iter().position(|&element| element >= target),
+            arr.iter().position(|&element| element > target),
+            arr.iter().rposition(|&element| element <= target),
+            arr.iter().rposition(|&element| element < target),
+        ]
+    }
+
+    #[test]
+    fn bound_quartet_matches_linear_scan_at_every_boundary() {
+        let arrs: [&[i32]; 6] = [
+            &[],
+            &[5],
+            &[5, 5, 5],
+            &[1, 2, 3, 4],
+            &[1, 3, 3, 3, 7, 7, 9],
+            &[2, 2, 4, 4, 6, 6],
+        ];
+
+        for arr in arrs.iter() {
+            for target in -1..=11 {
+                let bounds = [
+                    first_ge(&target, arr),
+                    first_gt(&target, arr),
+                    last_le(&target, arr),
+                    last_lt(&target, arr),
+                ];
+
+                assert_eq!(
+                    bounds,
+                    linear_bounds(target, arr),
+                    "{} in {:?}",
+                    target,
+                    arr
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn bound_quartet_handles_duplicate_runs() {
+        let target = 3;
+        let arr = [1, 3, 3, 3, 7];
+
+        assert_eq!(first_ge(&target, &arr), Some(1));
+        assert_eq!(first_gt(&target, &arr), Some(4));
+        assert_eq!(last_le(&target, &arr), Some(3));
+        assert_eq!(last_lt(&target, &arr), Some(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Binary search encountered an array that is note sorted")]
+    fn first_ge_panics_when_arr_is_not_sorted() {
+        let target = 5;
+        let arr = [1, 3, 2, 5];
+
+        first_ge(&target, &arr);
+    }
+
+    #[test]
+    fn count_in_range_handles_every_bound_kind() {
+        let arr = [5, 10, 12, 15, 20, 20, 25];
+
+        assert_eq!(count_in_range(&arr, 10..20), 3);
+        assert_eq!(count_in_range(&arr, 10..=20), 5);
+        assert_eq!(count_in_range(&arr, ..15), 3);
+        assert_eq!(count_in_range(&arr, ..=15), 4);
+        assert_eq!(count_in_range(&arr, 20..), 3);
+        assert_eq!(count_in_range(&arr, ..), 7);
+    }
+
+    #[test]
+    fn count_in_range_handles_excluded_start_bound() {
+        let arr = [5, 10, 12, 15, 20, 20, 25];
+
+        assert_eq!(
+            count_in_range(&arr, (Bound::Excluded(20), Bound::Unbounded)),
+            1
+        );
+        assert_eq!(
+            count_in_range(&arr, (Bound::Excluded(10), Bound::Excluded(20))),
+            2
+        );
+        assert_eq!(
+            count_in_range(&arr, (Bound::Excluded(11), Bound::Included(12))),
+            1
+        );
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn count_in_range_returns_zero_for_reversed_range() {
+        let arr = [5, 10, 12, 15, 20, 20, 25];
+
+        assert_eq!(count_in_range(&arr, 20..10), 0);
+        assert_eq!(
+            count_in_range(&arr, (Bound::Excluded(12), Bound::Excluded(12))),
+            0
+        );
+        assert!(slice_in_range(&arr, 20..=10).is_empty());
+    }
+
+    #[test]
+    fn slice_in_range_returns_sub_slice() {
+        let arr = [5, 10, 12, 15, 20, 20, 25];
+
+        assert_eq!(slice_in_range(&arr, 12..=20), &[12, 15, 20, 20]);
+        assert_eq!(slice_in_range(&arr, ..5), &[] as &[i32]);
+        assert_eq!(slice_in_range(&arr, 26..), &[] as &[i32]);
+        assert_eq!(slice_in_range(&[] as &[i32], 0..10), &[] as &[i32]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Binary search encountered an array that is note sorted")]
+    fn slice_in_range_panics_when_arr_is_not_sorted() {
+        let arr = [1, 3, 2, 5];
+
+        slice_in_range(&arr, 1..3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn all_occurrences_yields_every_index_of_duplicates() {
+        let target = 4;
+        let arr = [1, 2, 4, 4, 4, 5];
+        let indices: Vec<usize> = all_occurrences(&target, &arr).collect();
+
+        assert_eq!(indices, [2, 3, 4]);
+    }
+
+    #[test]
+    fn all_occurrences_yields_nothing_if_target_not_in_arr() {
+        let target = 3;
+        let arr = [1, 2, 4, 4, 4, 5];
+
+        assert_eq!(all_occurrences(&target, &arr).next(), None);
+    }
+
+    #[test]
+    fn all_occurrences_yields_nothing_for_empty_arr() {
+        let target = 3;
+        let arr: [i32; 0] = [];
+
+        assert_eq!(all_occurrences(&target, &arr).count(), 0);
+    }
+
+    #[test]
+    fn lower_and_upper_bound_bracket_matches() {
+        let arr = [1, 2, 4, 4, 4, 5];
+
+        for target in 0..7 {
+            assert_eq!(
+                lower_bound(&target, &arr)..upper_bound(&target, &arr),
+                equal_range(&target, &arr)
+            );
+        }
+    }
+
+    #[test]
+    fn first_distinguishes_present_and_absent_targets_with_same_rank() {
+        let arr = [1, 2, 4, 4, 4, 5];
+
+        assert_eq!(leftmost_rank(&3, &arr), leftmost_rank(&4, &arr));
+        assert_eq!(first(&3, &arr), None);
+        assert_eq!(first(&4, &arr), Some(2));
+        assert_eq!(first(&6, &arr), None);
+        assert_eq!(first(&0, &arr), None);
+    }
+
+    #[test]
+    fn last_distinguishes_present_and_absent_targets_with_same_rank() {
+        let arr = [1, 2, 4, 4, 4, 6];
+
+        assert_eq!(upper_bound(&4, &arr), upper_bound(&5, &arr));
+        assert_eq!(last(&5, &arr), None);
+        assert_eq!(last(&4, &arr), Some(4));
+        assert_eq!(last(&0, &arr), None);
+        assert_eq!(last(&6, &arr), Some(5));
+    }
+
+    #[test]
+    #[should_panic(expected = "Binary search encountered an array that is note sorted")]
+    fn first_panics_when_arr_is_not_sorted() {
+        let target = 1;
+        let arr = [2, 1];
+
+        first(&target, &arr);
+    }
+
+    #[test]
+    #[should_panic(expected = "Binary search encountered an array that is note sorted")]
+    fn neighbors_panics_if_the_arr_is_not_sorted() {
+        neighbors(&3, &[1, 3, 2]);
+    }
+
+    #[test]
+    fn neighbors_returns_none_for_empty_arr() {
+        let arr: [i32; 0] = [];
+
+        assert_eq!(neighbors(&5, &arr), (None, None));
+    }
+
+    #[test]
+    fn neighbors_returns_surrounding_elements_for_target_between_two_elements() {
+        let arr = [1, 2, 2, 4, 4, 5];
+
+        assert_eq!(neighbors(&3, &arr), (Some(2), Some(3)));
+    }
+
+    #[test]
+    fn neighbors_returns_matching_elements_for_target_at_the_minimum() {
+        let arr = [1, 1, 2, 4, 5];
+
+        assert_eq!(neighbors(&1, &arr), (Some(1), Some(0)));
+    }
+
+    #[test]
+    fn neighbors_returns_matching_elements_for_target_at_the_maximum() {
+        let arr = [1, 2, 4, 5, 5];
+
+        assert_eq!(neighbors(&5, &arr), (Some(4), Some(3)));
+    }
+
+    #[test]
+    fn neighbors_returns_one_side_for_target_outside_the_arr() {
+        let arr = [1, 2, 4, 5];
+
+        assert_eq!(neighbors(&0, &arr), (None, Some(0)));
+        assert_eq!(neighbors(&6, &arr), (Some(3), None));
+    }
+
+    #[test]
+    fn neighbors_matches_floor_and_ceil() {
+        let arr = [1, 2, 2, 2, 5, 7, 7, 9];
+
+        for target in 0..11 {
+            assert_eq!(
+                neighbors(&target, &arr),
+                (floor(&target, &arr), ceil(&target, &arr))
+            );
+        }
+    }
+
+    #[derive(Debug)]
+    struct Employee {
+        name: &'static str,
+        age: u32,
+    }
+
+    /// Employees sorted by age
+    fn employees() -> [Employee; 5] {
+        [
+            Employee {
+                name: "Ana",
+                age: 23,
+            },
+            Employee {
+                name: "Ben",
+                age: 31,
+            },
+            Employee {
+                name: "Cy",
+                age: 31,
+            },
+            Employee {
+                name: "Dee",
+                age: 31,
+            },
+            Employee {
+                name: "Eve",
+                age: 45,
+            },
+        ]
+    }
+
+    #[test]
+    fn rank_by_computes_ranks_on_a_field() {
+        let employees = employees();
+        let ages = employees.each_ref().map(|employee| employee.age);
+
+        for age in 20..50 {
+            let by_age = |employee: &Employee| employee.age.cmp(&age);
+
+            assert_eq!(
+                leftmost_rank_by(&employees, by_age),
+                leftmost_rank(&age, &ages)
+            );
+            assert_eq!(
+                rightmost_rank_by(&employees, by_age),
+                rightmost_rank(&age, &ages)
+            );
+        }
+
+        assert_eq!(leftmost_rank_by(&employees, |e| e.age.cmp(&31)), 1);
+        assert_eq!(rightmost_rank_by(&employees, |e| e.age.cmp(&31)), 3);
+        assert_eq!(employees[3].name, "Dee");
+    }
+
+    #[test]
+    fn rank_by_supports_a_custom_order() {
+        let arr = [9, 7, 7, 4, 1];
+        let descending = |target: i32| move |element: &i32| target.cmp(element);
+
+        assert_eq!(leftmost_rank_by(&arr, descending(7)), 1);
+        assert_eq!(rightmost_rank_by(&arr, descending(7)), 2);
+        assert_eq!(leftmost_rank_by(&arr, descending(10)), 0);
+        assert_eq!(rightmost_rank_by(&arr, descending(10)), 0);
+        assert_eq!(leftmost_rank_by(&arr, descending(0)), 5);
+    }
+
+    #[test]
+    fn rank_by_returns_zero_for_empty_arr() {
+        let empty: [Employee; 0] = [];
+
+        assert_eq!(leftmost_rank_by(&empty, |e| e.age.cmp(&5)), 0);
+        assert_eq!(rightmost_rank_by(&empty, |e| e.age.cmp(&5)), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Binary search encountered an array that is note sorted")]
+    fn rank_by_panics_if_the_arr_is_not_sorted_by_the_key() {
+        let mut employees = employees();
+
+        employees.swap(0, 4);
+        leftmost_rank_by(&employees, |employee| employee.age.cmp(&31));
+    }
 }
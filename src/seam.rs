@@ -0,0 +1,26 @@
+//! Policies for equal elements on either side of the seam between two chunks or segments
+
+/// Whether equal elements may sit on both sides of a seam
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")]
+/// # {
+/// use binary_search::{columnar::ChunkedColumn, seam::SeamPolicy};
+///
+/// let first = [1, 2, 2];
+/// let second = [2, 3];
+///
+/// assert!(ChunkedColumn::with_seam_policy(vec![&first[..], &second], SeamPolicy::AllowEqualAcrossSeams).is_ok());
+/// assert!(ChunkedColumn::with_seam_policy(vec![&first[..], &second], SeamPolicy::RequireStrictSeams).is_err());
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SeamPolicy {
+    /// A run of equal elements may continue past a seam, so ranks and ranges of equal elements are merged across it
+    #[default]
+    AllowEqualAcrossSeams,
+    /// The elements on either side of a seam must differ, so every run of equal elements stays within one piece
+    RequireStrictSeams,
+}
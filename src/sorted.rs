@@ -0,0 +1,251 @@
+//! Slices validated as sorted once, so that searches skip the per-call check
+
+use crate::{core, error::NotSortedError};
+use ::core::{
+    ops::{Deref, Range},
+    slice::SliceIndex,
+};
+
+/// A slice known to be sorted
+///
+/// The O(n) sortedness check runs once on construction, so every search is O(log n).
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::SortedSlice;
+///
+/// let arr = [1, 2, 4, 4, 4, 5, 6, 7];
+/// let sorted = SortedSlice::new(&arr).unwrap();
+///
+/// assert_eq!(sorted.search(&5), Some(5));
+/// assert_eq!(sorted.equal_range(&4), 2..5);
+/// assert_eq!(sorted.slice(3..).leftmost_rank(&5), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SortedSlice<'a, T> {
+    arr: &'a [T],
+}
+
+/// Shorter name for [`SortedSlice`]
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::Sorted;
+///
+/// let arr = [1, 3, 5, 7];
+/// let sorted = Sorted::new(&arr).unwrap();
+///
+/// assert!(sorted.contains(&5));
+/// assert!(Sorted::new(&[2, 1]).is_err());
+/// ```
+pub type Sorted<'a, T> = SortedSlice<'a, T>;
+
+impl<'a, T> SortedSlice<'a, T>
+where
+    T: Ord,
+{
+    /// Returns a new `SortedSlice` if `arr` is sorted.
+    pub fn new(arr: &'a [T]) -> Result<Self, NotSortedError> {
+        NotSortedError::check(arr)?;
+
+        Ok(Self { arr })
+    }
+
+    /// Returns a new `SortedSlice` without checking that `arr` is sorted.
+    ///
+    /// Passing an unsorted array is not memory unsafety, searches on it only give meaningless results.
+    pub fn new_unchecked(arr: &'a [T]) -> Self {
+        Self { arr }
+    }
+
+    /// Returns the underlying slice
+    pub fn as_slice(&self) -> &'a [T] {
+        self.arr
+    }
+
+    /// Returns the sorted sub-slice at `range`.
+    ///
+    /// # Panics
+    ///
+    /// The function panics if `range` is out of bounds.
+    pub fn slice<R>(&self, range: R) -> Self
+    where
+        R: SliceIndex<[T], Output = [T]>,
+    {
+        Self {
+            arr: &self.arr[range],
+        }
+    }
+
+    /// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) in order to find the index of
+    /// `target`.
+    pub fn search(&self, target: &T) -> Option<usize> {
+        core::binary_search_by(self.arr, |element| element.cmp(target))
+    }
+
+    /// Checks if `target` is in the slice.
+    pub fn contains(&self, target: &T) -> bool {
+        self.search(target).is_some()
+    }
+
+    /// Calculates the leftmost rank of `target`.
+    pub fn leftmost_rank(&self, target: &T) -> usize {
+        core::lower_bound(target, self.arr)
+    }
+
+    /// Calculates the rightmost rank of `target`, or 0 if no element is smaller than or equal to `target`.
+    pub fn rightmost_rank(&self, target: &T) -> usize {
+        core::upper_bound(target, self.arr).saturating_sub(1)
+    }
+
+    /// Calculates the half-open range of indices of the elements equal to `target`.
+    pub fn equal_range(&self, target: &T) -> Range<usize> {
+        core::lower_bound(target, self.arr)..core::upper_bound(target, self.arr)
+    }
+
+    /// Performs [exponential search](https://en.wikipedia.org/wiki/Exponential_search) in order to find the index of
+    /// `target`.
+    pub fn exponential_search(&self, target: &T) -> Option<usize> {
+        core::exponential_search(target, self.arr)
+    }
+
+    /// Performs binary search in order to find the index of `target`, prefetching both possible next probes like
+    /// [`variations::prefetch_search`](crate::variations::prefetch_search).
+    #[cfg(feature = "prefetch")]
+    pub fn prefetch_search(&self, target: &T) -> Option<usize> {
+        core::binary_search_prefetch(target, self.arr)
+    }
+}
+
+impl<'a, T> Deref for SortedSlice<'a, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.arr
+    }
+}
+
+impl<'a, T> AsRef<[T]> for SortedSlice<'a, T> {
+    fn as_ref(&self) -> &[T] {
+        self.arr
+    }
+}
+
+#[cfg(test)]
+mod sorted_tests {
+    use super::SortedSlice;
+    #[cfg(feature = "std")]
+    use core::cell::Cell;
+    #[cfg(feature = "std")]
+    use core::cmp::Ordering;
+
+    /// Value counting every comparison made between values sharing the same counter
+    #[cfg(feature = "std")]
+    #[derive(Debug)]
+    struct Counted<'c> {
+        value: i32,
+        comparisons: &'c Cell<usize>,
+    }
+
+    #[cfg(feature = "std")]
+    impl PartialEq for Counted<'_> {
+        fn eq(&self, other: &Self) -> bool {
+            self.cmp(other) == Ordering::Equal
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl Eq for Counted<'_> {}
+
+    #[cfg(feature = "std")]
+    impl PartialOrd for Counted<'_> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl Ord for Counted<'_> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.comparisons.set(self.comparisons.get() + 1);
+            self.value.cmp(&other.value)
+        }
+    }
+
+    #[test]
+    fn new_rejects_unsorted_arr() {
+        let arr = [1, 2, 5, 3, 4];
+        let error = SortedSlice::new(&arr).unwrap_err();
+
+        assert_eq!(error.index(), 3);
+    }
+
+    #[test]
+    fn searches_return_the_same_results_as_the_checked_functions() {
+        let arr = [1, 2, 4, 4, 4, 5, 6, 7];
+        let sorted = SortedSlice::new(&arr).unwrap();
+
+        assert_eq!(sorted.search(&6), Some(6));
+        assert_eq!(sorted.search(&3), None);
+        assert_eq!(sorted.leftmost_rank(&4), 2);
+        assert_eq!(sorted.rightmost_rank(&4), 4);
+        assert_eq!(sorted.equal_range(&4), 2..5);
+        assert_eq!(sorted.exponential_search(&7), Some(7));
+        assert!(sorted.contains(&5));
+        assert!(!sorted.contains(&3));
+    }
+
+    #[test]
+    fn slice_preserves_sortedness() {
+        let arr = [1, 2, 4, 4, 4, 5, 6, 7];
+        let sorted = SortedSlice::new(&arr).unwrap();
+        let sub_slice = sorted.slice(2..6);
+
+        assert_eq!(&*sub_slice, &[4, 4, 4, 5]);
+        assert_eq!(sub_slice.search(&5), Some(3));
+        assert_eq!(sub_slice.equal_range(&4), 0..3);
+    }
+
+    #[test]
+    fn deref_gives_read_access_to_the_slice() {
+        let arr = [1, 2, 3];
+        let sorted = SortedSlice::new(&arr).unwrap();
+
+        assert_eq!(sorted.len(), 3);
+        assert_eq!(sorted[1], 2);
+        assert_eq!(sorted.as_slice(), &arr);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn repeated_searches_never_rerun_is_sorted() {
+        let comparisons = Cell::new(0);
+        let arr: Vec<Counted> = (0..1024)
+            .map(|value| Counted {
+                value,
+                comparisons: &comparisons,
+            })
+            .collect();
+        let sorted = SortedSlice::new(&arr).unwrap();
+
+        assert_eq!(comparisons.get(), 1023);
+
+        comparisons.set(0);
+
+        for value in 0..100 {
+            let target = Counted {
+                value: value * 10,
+                comparisons: &comparisons,
+            };
+
+            sorted.search(&target);
+            sorted.leftmost_rank(&target);
+            sorted.rightmost_rank(&target);
+            sorted.contains(&target);
+        }
+
+        assert!(comparisons.get() <= 100 * 4 * 11);
+    }
+}
@@ -0,0 +1,266 @@
+//! Search over unsorted data while it is being sorted in the background
+
+use crate::{
+    core,
+    memory::{FieldUsage, MemoryBreakdown, MemoryUsage},
+};
+use std::{
+    panic,
+    sync::{Arc, OnceLock},
+    thread::{self, JoinHandle},
+};
+
+/// Index over a snapshot of unsorted data that answers queries immediately
+///
+/// On construction the data is cloned and a background thread sorts a second copy. Until the sort finishes queries
+/// are answered with a linear scan of the unsorted copy; afterwards they switch to binary search over the sorted
+/// copy. Both phases give the same answers, and once the index is in the sorted phase it stays there.
+///
+/// Dropping the index while the sort is in flight is safe: the background thread finishes sorting its own copy and
+/// then discards it.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::staged::StagedIndex;
+///
+/// let mut index = StagedIndex::new(&[5, 3, 9, 1]);
+///
+/// assert!(index.contains(&9));
+///
+/// index.wait_ready();
+///
+/// assert!(index.is_sorted_phase());
+/// assert_eq!(index.search(&3), Some(&3));
+/// ```
+#[derive(Debug)]
+pub struct StagedIndex<T> {
+    /// The snapshot in its original order, scanned until the sort finishes
+    unsorted: Vec<T>,
+    /// The sorted snapshot, set once by the background thread
+    sorted: Arc<OnceLock<Vec<T>>>,
+    /// The background thread, until it has been joined
+    sorter: Option<JoinHandle<()>>,
+}
+
+impl<T> StagedIndex<T>
+where
+    T: Ord + Clone + Send + Sync + 'static,
+{
+    /// Returns a new `StagedIndex` over a snapshot of `data`, spawning the thread that sorts it.
+    pub fn new(data: &[T]) -> Self {
+        let unsorted = data.to_vec();
+        let sorted = Arc::new(OnceLock::new());
+        let mut snapshot = unsorted.clone();
+        let sorter = {
+            let sorted = Arc::clone(&sorted);
+
+            thread::spawn(move || {
+                snapshot.sort();
+
+                let _ = sorted.set(snapshot);
+            })
+        };
+
+        Self {
+            unsorted,
+            sorted,
+            sorter: Some(sorter),
+        }
+    }
+
+    /// Returns whether an element equal to `target` is in the snapshot.
+    pub fn contains(&self, target: &T) -> bool {
+        self.search(target).is_some()
+    }
+
+    /// Returns a reference to an element of the snapshot equal to `target`.
+    pub fn search(&self, target: &T) -> Option<&T> {
+        match self.sorted.get() {
+            Some(sorted) => core::binary_search_by(sorted, |element| element.cmp(target))
+                .map(|index| &sorted[index]),
+            None => self.unsorted.iter().find(|element| *element == target),
+        }
+    }
+
+    /// Returns whether queries are answered by binary search over the sorted snapshot.
+    pub fn is_sorted_phase(&self) -> bool {
+        self.sorted.get().is_some()
+    }
+
+    /// Returns whether the background sort has finished, without blocking.
+    pub fn poll_ready(&self) -> bool {
+        self.is_sorted_phase()
+    }
+
+    /// Blocks until the background sort has finished.
+    ///
+    /// # Panics
+    ///
+    /// The function resumes the panic of the background thread if sorting panicked, e.g. in a comparator.
+    pub fn wait_ready(&mut self) {
+        if let Some(sorter) = self.sorter.take() {
+            if let Err(payload) = sorter.join() {
+                panic::resume_unwind(payload);
+            }
+        }
+    }
+}
+
+impl<T> MemoryUsage for StagedIndex<T> {
+    /// Reports the unsorted snapshot, and the sorted snapshot once the background sort has finished
+    fn memory_bytes(&self) -> MemoryBreakdown {
+        let breakdown =
+            MemoryBreakdown::new(self).with(FieldUsage::of_vec("unsorted", &self.unsorted));
+
+        match self.sorted.get() {
+            Some(sorted) => breakdown.with(FieldUsage::of_vec("sorted", sorted)),
+            None => breakdown,
+        }
+    }
+
+    /// Releases the unsorted snapshot once the index is in the sorted phase, as queries no longer scan it
+    fn shrink_to_fit(&mut self) {
+        if self.sorted.get().is_some() {
+            self.unsorted = Vec::new();
+        }
+    }
+}
+
+#[cfg(test)]
+mod staged_tests {
+    use super::StagedIndex;
+    use std::{
+        cmp::Ordering,
+        sync::{
+            atomic::{AtomicBool, Ordering as AtomicOrdering},
+            Arc,
+        },
+        thread,
+        time::Duration,
+    };
+
+    /// Value whose comparisons block until its gate opens, keeping the background sort in flight
+    #[derive(Debug, Clone)]
+    struct Gated {
+        value: i32,
+        gate: Arc<AtomicBool>,
+    }
+
+    impl PartialEq for Gated {
+        fn eq(&self, other: &Self) -> bool {
+            self.value == other.value
+        }
+    }
+
+    impl Eq for Gated {}
+
+    impl PartialOrd for Gated {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Gated {
+        fn cmp(&self, other: &Self) -> Ordering {
+            while !self.gate.load(AtomicOrdering::SeqCst) {
+                thread::sleep(Duration::from_millis(1));
+            }
+
+            self.value.cmp(&other.value)
+        }
+    }
+
+    fn gated(values: &[i32]) -> (Vec<Gated>, Arc<AtomicBool>) {
+        let gate = Arc::new(AtomicBool::new(false));
+        let data = values
+            .iter()
+            .map(|&value| Gated {
+                value,
+                gate: Arc::clone(&gate),
+            })
+            .collect();
+
+        (data, gate)
+    }
+
+    #[test]
+    fn queries_are_correct_in_both_phases() {
+        let (data, gate) = gated(&[8, 3, 5, 1, 9, 2]);
+        let mut index = StagedIndex::new(&data);
+        let probe = |value| Gated {
+            value,
+            gate: Arc::clone(&gate),
+        };
+
+        assert!(!index.is_sorted_phase());
+        assert!(!index.poll_ready());
+        assert!(index.contains(&probe(5)));
+        assert!(!index.contains(&probe(4)));
+        assert_eq!(index.search(&probe(9)).map(|found| found.value), Some(9));
+
+        gate.store(true, AtomicOrdering::SeqCst);
+        index.wait_ready();
+
+        assert!(index.is_sorted_phase());
+        assert!(index.poll_ready());
+        assert!(index.contains(&probe(5)));
+        assert!(!index.contains(&probe(4)));
+        assert_eq!(index.search(&probe(9)).map(|found| found.value), Some(9));
+    }
+
+    #[test]
+    fn transition_to_sorted_phase_is_atomic() {
+        let values: Vec<i32> = (0..500).rev().map(|value| value * 2).collect();
+        let (data, gate) = gated(&values);
+        let index = StagedIndex::new(&data);
+        let probe = |value| Gated {
+            value,
+            gate: Arc::clone(&gate),
+        };
+        let mut queries = 0;
+        let mut sorted_queries = 0;
+
+        while sorted_queries < 200 {
+            if queries == 50 {
+                gate.store(true, AtomicOrdering::SeqCst);
+            }
+
+            let was_sorted = sorted_queries > 0;
+            let sorted = index.is_sorted_phase();
+
+            assert!(sorted || !was_sorted);
+            assert!(!sorted || queries >= 50);
+            assert!(index.contains(&probe(498)));
+            assert!(!index.contains(&probe(499)));
+            assert_eq!(index.search(&probe(0)).map(|found| found.value), Some(0));
+
+            queries += 1;
+
+            if sorted {
+                sorted_queries += 1;
+            }
+        }
+
+        assert!(index.is_sorted_phase());
+    }
+
+    #[test]
+    fn drop_while_sorting_is_safe() {
+        let (data, gate) = gated(&[3, 2, 1]);
+        let index = StagedIndex::new(&data);
+
+        drop(index);
+        gate.store(true, AtomicOrdering::SeqCst);
+    }
+
+    #[test]
+    fn empty_data_is_ready_immediately_after_waiting() {
+        let mut index = StagedIndex::<i32>::new(&[]);
+
+        index.wait_ready();
+
+        assert!(index.is_sorted_phase());
+        assert!(!index.contains(&1));
+    }
+}
@@ -0,0 +1,346 @@
+//! Instrumented searches reporting how much work they performed
+//!
+//! The instrumented functions are separate copies of the algorithms, so the normal functions pay nothing for the
+//! counting. The sortedness check is not counted.
+
+use crate::utils;
+use core::cmp::{min, Ordering};
+
+/// The work performed by a search
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SearchStats {
+    /// The number of comparisons between the target and an element
+    pub comparisons: usize,
+    /// The number of positions the search chose to inspect, e.g. one per halving for binary search
+    pub probes: usize,
+}
+
+/// Compares `element` to `target`, counting the comparison
+pub(crate) fn compare<T>(element: &T, target: &T, stats: &mut SearchStats) -> Ordering
+where
+    T: Ord,
+{
+    stats.comparisons += 1;
+    element.cmp(target)
+}
+
+/// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) like
+/// [`binary_search`](crate::binary_search), also returning the work it performed.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::stats;
+///
+/// let target = 5;
+/// let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+/// let (found, stats) = stats::binary_search_with_stats(&target, &arr);
+///
+/// assert_eq!(found, Some(4));
+/// assert_eq!(stats.comparisons, 3);
+/// assert_eq!(stats.probes, 3);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn binary_search_with_stats<T>(target: &T, arr: &[T]) -> (Option<usize>, SearchStats)
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Binary search encountered an array that is note sorted");
+    }
+
+    let mut stats = SearchStats::default();
+    let found = counted_binary_search(target, arr, &mut stats);
+
+    (found, stats)
+}
+
+/// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) like
+/// [`binary_search`](crate::binary_search), also returning the number of comparisons between `target` and an element.
+///
+/// This is [`binary_search_with_stats`] keeping only the comparison count.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::stats;
+///
+/// let arr: Vec<u32> = (0..1_000_000).collect();
+/// let (found, comparisons) = stats::binary_search_counted(&123_456, &arr);
+///
+/// assert_eq!(found, Some(123_456));
+/// assert!(comparisons <= 21);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn binary_search_counted<T>(target: &T, arr: &[T]) -> (Option<usize>, usize)
+where
+    T: Ord,
+{
+    let (found, stats) = binary_search_with_stats(target, arr);
+
+    (found, stats.comparisons)
+}
+
+/// Performs [exponential search](https://en.wikipedia.org/wiki/Exponential_search) like
+/// [`variations::exponential_search`](crate::variations::exponential_search), also returning the work it performed.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::stats;
+///
+/// let target = 2;
+/// let arr: Vec<i32> = (0..1000).collect();
+/// let (found, exponential) = stats::exponential_search_with_stats(&target, &arr);
+/// let (_, binary) = stats::binary_search_with_stats(&target, &arr);
+///
+/// assert_eq!(found, Some(2));
+/// assert!(exponential.comparisons < binary.comparisons);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn exponential_search_with_stats<T>(target: &T, arr: &[T]) -> (Option<usize>, SearchStats)
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Exponential search encountered an array that is note sorted");
+    }
+
+    let mut stats = SearchStats::default();
+    let arr_size = arr.len();
+    let mut bound = 1;
+
+    while bound < arr_size && {
+        stats.probes += 1;
+        compare(&arr[bound], target, &mut stats) == Ordering::Less
+    } {
+        bound = bound.saturating_mul(2);
+    }
+
+    let left_bound = bound / 2;
+    let right_bound = min(bound.saturating_add(1), arr_size);
+    let found = counted_binary_search(target, &arr[left_bound..right_bound], &mut stats)
+        .map(|slice_index| slice_index + left_bound);
+
+    (found, stats)
+}
+
+/// Performs [interpolation search](https://en.wikipedia.org/wiki/Interpolation_search) like
+/// [`variations::interpolation_search`](crate::variations::interpolation_search), also returning the work it performed.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::stats;
+///
+/// let target = 5u16;
+/// let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+/// let (found, stats) =
+///     stats::interpolation_search_with_stats(&target, &arr, |t, l, r| ((*t - *l) / (*r - *l)).into());
+///
+/// assert_eq!(found, Some(4));
+/// assert!(stats.comparisons > stats.probes);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn interpolation_search_with_stats<T, InterpolationFn>(
+    target: &T,
+    arr: &[T],
+    interpolation_fn: InterpolationFn,
+) -> (Option<usize>, SearchStats)
+where
+    InterpolationFn: Fn(&T, &T, &T) -> usize,
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Interpolation search encountered an array that is note sorted");
+    }
+
+    let mut stats = SearchStats::default();
+
+    if arr.is_empty() {
+        return (None, stats);
+    }
+
+    let mut left = 0;
+    let mut right = arr.len() - 1;
+
+    while left <= right
+        && compare(&arr[right], target, &mut stats) == Ordering::Greater
+        && compare(&arr[left], target, &mut stats) == Ordering::Less
+    {
+        let middle = left + interpolation_fn(target, &arr[left], &arr[right]);
+
+        stats.probes += 1;
+
+        match compare(&arr[middle], target, &mut stats) {
+            Ordering::Less => left = middle + 1,
+            Ordering::Equal => return (Some(middle), stats),
+            Ordering::Greater => right = middle - 1,
+        }
+
+        if compare(&arr[left], target, &mut stats) == Ordering::Equal {
+            return (Some(left), stats);
+        }
+    }
+
+    let found = if left <= right && compare(&arr[left], target, &mut stats) == Ordering::Equal {
+        Some(left)
+    } else if left <= right && compare(&arr[right], target, &mut stats) == Ordering::Equal {
+        Some(right)
+    } else {
+        None
+    };
+
+    (found, stats)
+}
+
+/// Binary search over the half-open window, counting every probe into `stats`
+fn counted_binary_search<T>(target: &T, arr: &[T], stats: &mut SearchStats) -> Option<usize>
+where
+    T: Ord,
+{
+    let mut left = 0;
+    let mut right = arr.len();
+
+    while left < right {
+        let middle = utils::midpoint(left, right);
+
+        stats.probes += 1;
+
+        match compare(&arr[middle], target, stats) {
+            Ordering::Equal => return Some(middle),
+            Ordering::Greater => right = middle,
+            Ordering::Less => left = middle + 1,
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod stats_tests {
+    use super::{
+        binary_search_counted, binary_search_with_stats, exponential_search_with_stats,
+        interpolation_search_with_stats, SearchStats,
+    };
+    use crate::{binary_search, variations};
+
+    #[test]
+    fn instrumented_searches_return_the_same_results_as_the_normal_functions() {
+        let arr = [1u16, 3, 4, 6, 7, 8, 12, 13, 15, 20, 21, 22, 30];
+        let interpolation_fn = |t: &u16, l: &u16, r: &u16| ((*t - *l) / (*r - *l)).into();
+
+        for target in 0..32 {
+            assert_eq!(
+                binary_search_with_stats(&target, &arr).0,
+                binary_search(&target, &arr)
+            );
+            assert_eq!(
+                exponential_search_with_stats(&target, &arr).0,
+                variations::exponential_search(&target, &arr)
+            );
+            assert_eq!(
+                interpolation_search_with_stats(&target, &arr, interpolation_fn).0,
+                variations::interpolation_search(&target, &arr, interpolation_fn)
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn binary_search_with_stats_makes_at_most_logarithmic_comparisons() {
+        let arr: Vec<i32> = (0..1023).collect();
+
+        for target in -1..1024 {
+            let (_, stats) = binary_search_with_stats(&target, &arr);
+
+            assert_eq!(stats.comparisons, stats.probes);
+            assert!(stats.comparisons <= 10);
+        }
+    }
+
+    #[test]
+    fn exponential_search_with_stats_counts_gallop_and_binary_search() {
+        let target = 5;
+        let arr = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+        let (found, stats) = exponential_search_with_stats(&target, &arr);
+
+        assert_eq!(found, Some(5));
+        assert_eq!(
+            stats,
+            SearchStats {
+                comparisons: 6,
+                probes: 6
+            }
+        );
+    }
+
+    #[test]
+    fn instrumented_searches_report_no_work_for_empty_arr() {
+        let target = 1u16;
+        let arr: [u16; 0] = [];
+
+        assert_eq!(
+            binary_search_with_stats(&target, &arr),
+            (None, SearchStats::default())
+        );
+        assert_eq!(
+            exponential_search_with_stats(&target, &arr),
+            (None, SearchStats::default())
+        );
+        assert_eq!(
+            interpolation_search_with_stats(&target, &arr, |_, _, _| 0),
+            (None, SearchStats::default())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Binary search encountered an array that is note sorted")]
+    fn binary_search_with_stats_panics_when_arr_is_not_sorted() {
+        let target = 1;
+        let arr = [1, 3, 2];
+
+        binary_search_with_stats(&target, &arr);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn binary_search_counted_is_bounded_by_ceil_log2_plus_one() {
+        for &len in &[1usize, 2, 3, 7, 8, 9, 100, 1000, 1024, 1025] {
+            let arr: Vec<usize> = (0..len).map(|element| element * 2).collect();
+            let bound = (usize::BITS - (len - 1).leading_zeros()) as usize + 1;
+
+            for target in 0..len * 2 + 1 {
+                let (found, comparisons) = binary_search_counted(&target, &arr);
+
+                assert_eq!(found, binary_search(&target, &arr));
+                assert!(
+                    comparisons <= bound,
+                    "{} comparisons searching {} in {} elements",
+                    comparisons,
+                    target,
+                    len
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn binary_search_counted_makes_no_comparisons_for_empty_arr() {
+        let arr: [u32; 0] = [];
+
+        assert_eq!(binary_search_counted(&1, &arr), (None, 0));
+    }
+}
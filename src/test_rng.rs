@@ -0,0 +1,56 @@
+//! Deterministic random numbers for the test modules
+
+/// Xorshift generator, so that the randomised tests are reproducible without extra dependencies.
+pub(crate) struct Rng(u64);
+
+impl Rng {
+    /// Creates a generator from a non-zero `seed`.
+    pub(crate) fn new(seed: u64) -> Self {
+        assert_ne!(seed, 0, "Xorshift generator needs a non-zero seed");
+
+        Self(seed)
+    }
+
+    /// Returns the next number of the sequence.
+    pub(crate) fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns the next number of the sequence, reduced to `0..bound`.
+    pub(crate) fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+}
+
+#[cfg(test)]
+mod test_rng_tests {
+    use super::Rng;
+
+    #[test]
+    fn below_stays_within_bound() {
+        let mut rng = Rng::new(0x7e57);
+
+        for bound in 1..200 {
+            assert!(rng.below(bound) < bound);
+        }
+    }
+
+    #[test]
+    fn same_seed_gives_same_sequence() {
+        let mut first = Rng::new(0x5eed);
+        let mut second = Rng::new(0x5eed);
+
+        for _ in 0..100 {
+            assert_eq!(first.next(), second.next());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Xorshift generator needs a non-zero seed")]
+    fn new_panics_on_zero_seed() {
+        Rng::new(0);
+    }
+}
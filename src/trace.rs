@@ -0,0 +1,348 @@
+//! Traced searches reporting every probe they make, e.g. for visualizing how a search narrows its window
+//!
+//! Like the [`stats`](crate::stats) functions, these are separate copies of the algorithms, so the normal functions
+//! pay nothing for the tracing.
+
+use crate::utils;
+use core::cmp::{min, Ordering};
+
+/// One probe of a search
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Probe {
+    /// The start of the window still being searched
+    pub left: usize,
+    /// The end of the window still being searched, exclusive
+    pub right: usize,
+    /// The index of the probed element
+    pub middle: usize,
+    /// The ordering of the probed element relative to the target
+    pub ordering: Ordering,
+}
+
+/// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) like
+/// [`binary_search`](crate::binary_search), calling `on_probe` with every probe made before returning the outcome.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::trace::{self, Probe};
+/// use std::cmp::Ordering;
+///
+/// let target = 70;
+/// let arr = [10, 20, 30, 40, 50, 60, 70, 80, 90, 100];
+/// let mut probes = Vec::new();
+/// let found = trace::binary_search_traced(&target, &arr, |probe| probes.push(probe));
+///
+/// assert_eq!(found, Some(6));
+/// assert_eq!(
+///     probes[0],
+///     Probe { left: 0, right: 10, middle: 5, ordering: Ordering::Less }
+/// );
+/// assert_eq!(probes.len(), 4);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn binary_search_traced<T, F>(target: &T, arr: &[T], mut on_probe: F) -> Option<usize>
+where
+    T: Ord,
+    F: FnMut(Probe),
+{
+    if !utils::is_sorted(arr) {
+        panic!("Binary search encountered an array that is note sorted");
+    }
+
+    traced_binary_search(target, arr, 0, arr.len(), &mut on_probe)
+}
+
+/// Performs [exponential search](https://en.wikipedia.org/wiki/Exponential_search) like
+/// [`variations::exponential_search`](crate::variations::exponential_search), calling `on_probe` with every probe made
+/// before returning the outcome.
+///
+/// While galloping, the window runs from the previous bound to the end of the array. The binary search that follows
+/// reports absolute indices.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::trace;
+///
+/// let target = 70;
+/// let arr = [10, 20, 30, 40, 50, 60, 70, 80, 90, 100];
+/// let mut middles = Vec::new();
+/// let found = trace::exponential_search_traced(&target, &arr, |probe| middles.push(probe.middle));
+///
+/// assert_eq!(found, Some(6));
+/// assert_eq!(middles, vec![1, 2, 4, 8, 6]);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn exponential_search_traced<T, F>(target: &T, arr: &[T], mut on_probe: F) -> Option<usize>
+where
+    T: Ord,
+    F: FnMut(Probe),
+{
+    if !utils::is_sorted(arr) {
+        panic!("Exponential search encountered an array that is note sorted");
+    }
+
+    let arr_size = arr.len();
+    let mut bound = 1;
+
+    while bound < arr_size {
+        let ordering = arr[bound].cmp(target);
+
+        on_probe(Probe {
+            left: bound / 2,
+            right: arr_size,
+            middle: bound,
+            ordering,
+        });
+
+        if ordering != Ordering::Less {
+            break;
+        }
+
+        bound = bound.saturating_mul(2);
+    }
+
+    traced_binary_search(
+        target,
+        arr,
+        bound / 2,
+        min(bound.saturating_add(1), arr_size),
+        &mut on_probe,
+    )
+}
+
+/// Performs [interpolation search](https://en.wikipedia.org/wiki/Interpolation_search) like
+/// [`variations::interpolation_search`](crate::variations::interpolation_search), calling `on_probe` with every
+/// interpolated probe made before returning the outcome.
+///
+/// The checks of the window ends that decide whether to keep interpolating are not reported.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::trace;
+///
+/// let target = 6u16;
+/// let arr = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+/// let mut middles = Vec::new();
+/// let found = trace::interpolation_search_traced(&target, &arr, |t, l, _| (*t - *l).into(), |probe| {
+///     middles.push(probe.middle)
+/// });
+///
+/// assert_eq!(found, Some(6));
+/// assert_eq!(middles, vec![6]);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn interpolation_search_traced<T, InterpolationFn, F>(
+    target: &T,
+    arr: &[T],
+    interpolation_fn: InterpolationFn,
+    mut on_probe: F,
+) -> Option<usize>
+where
+    InterpolationFn: Fn(&T, &T, &T) -> usize,
+    T: Ord,
+    F: FnMut(Probe),
+{
+    if !utils::is_sorted(arr) {
+        panic!("Interpolation search encountered an array that is note sorted");
+    }
+
+    if arr.is_empty() {
+        return None;
+    }
+
+    let mut left = 0;
+    let mut right = arr.len() - 1;
+
+    while left <= right && target < &arr[right] && target > &arr[left] {
+        let middle = left + interpolation_fn(target, &arr[left], &arr[right]);
+        let ordering = arr[middle].cmp(target);
+
+        on_probe(Probe {
+            left,
+            right: right + 1,
+            middle,
+            ordering,
+        });
+
+        match ordering {
+            Ordering::Less => left = middle + 1,
+            Ordering::Equal => return Some(middle),
+            Ordering::Greater => right = middle - 1,
+        }
+
+        if &arr[left] == target {
+            return Some(left);
+        }
+    }
+
+    if left <= right && &arr[left] == target {
+        Some(left)
+    } else if left <= right && &arr[right] == target {
+        Some(right)
+    } else {
+        None
+    }
+}
+
+/// Binary search over the half-open window `left..right` of `arr`, reporting every probe with absolute indices
+fn traced_binary_search<T, F>(
+    target: &T,
+    arr: &[T],
+    mut left: usize,
+    mut right: usize,
+    on_probe: &mut F,
+) -> Option<usize>
+where
+    T: Ord,
+    F: FnMut(Probe),
+{
+    while left < right {
+        let middle = utils::midpoint(left, right);
+        let ordering = arr[middle].cmp(target);
+
+        on_probe(Probe {
+            left,
+            right,
+            middle,
+            ordering,
+        });
+
+        match ordering {
+            Ordering::Equal => return Some(middle),
+            Ordering::Greater => right = middle,
+            Ordering::Less => left = middle + 1,
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod trace_tests {
+    #[cfg(feature = "std")]
+    use super::Probe;
+    use super::{binary_search_traced, exponential_search_traced, interpolation_search_traced};
+    #[cfg(feature = "std")]
+    use core::cmp::Ordering;
+    #[cfg(feature = "std")]
+    use core::cmp::Ordering::{Equal, Greater, Less};
+
+    #[cfg(feature = "std")]
+    const ARR: [u16; 10] = [10, 20, 30, 40, 50, 60, 70, 80, 90, 100];
+
+    #[cfg(feature = "std")]
+    fn probe(left: usize, right: usize, middle: usize, ordering: Ordering) -> Probe {
+        Probe {
+            left,
+            right,
+            middle,
+            ordering,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn binary_search_traced_reports_probes_for_present_target() {
+        let mut probes = Vec::new();
+        let found = binary_search_traced(&70, &ARR, |probe| probes.push(probe));
+
+        assert_eq!(found, Some(6));
+        assert_eq!(
+            probes,
+            vec![
+                probe(0, 10, 5, Less),
+                probe(6, 10, 8, Greater),
+                probe(6, 8, 7, Greater),
+                probe(6, 7, 6, Equal),
+            ]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn binary_search_traced_reports_probes_for_absent_target() {
+        let mut probes = Vec::new();
+        let found = binary_search_traced(&35, &ARR, |probe| probes.push(probe));
+
+        assert_eq!(found, None);
+        assert_eq!(
+            probes,
+            vec![
+                probe(0, 10, 5, Greater),
+                probe(0, 5, 2, Less),
+                probe(3, 5, 4, Greater),
+                probe(3, 4, 3, Greater),
+            ]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn exponential_search_traced_reports_gallop_then_binary_search() {
+        let mut probes = Vec::new();
+        let found = exponential_search_traced(&70, &ARR, |probe| probes.push(probe));
+
+        assert_eq!(found, Some(6));
+        assert_eq!(
+            probes,
+            vec![
+                probe(0, 10, 1, Less),
+                probe(1, 10, 2, Less),
+                probe(2, 10, 4, Less),
+                probe(4, 10, 8, Greater),
+                probe(4, 9, 6, Equal),
+            ]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn interpolation_search_traced_reports_interpolated_probes() {
+        let mut probes = Vec::new();
+        let found = interpolation_search_traced(
+            &70,
+            &ARR,
+            |t, l, r| ((*t - *l) / (*r - *l)).into(),
+            |probe| probes.push(probe),
+        );
+
+        assert_eq!(found, Some(6));
+        assert_eq!(
+            probes,
+            vec![
+                probe(0, 10, 0, Less),
+                probe(1, 10, 1, Less),
+                probe(2, 10, 2, Less),
+                probe(3, 10, 3, Less),
+                probe(4, 10, 4, Less),
+                probe(5, 10, 5, Less),
+            ]
+        );
+    }
+
+    #[test]
+    fn traced_searches_report_nothing_for_empty_arr() {
+        let arr: [u16; 0] = [];
+        let mut probes = 0;
+
+        assert_eq!(binary_search_traced(&1, &arr, |_| probes += 1), None);
+        assert_eq!(exponential_search_traced(&1, &arr, |_| probes += 1), None);
+        assert_eq!(
+            interpolation_search_traced(&1, &arr, |_, _, _| 0, |_| probes += 1),
+            None
+        );
+        assert_eq!(probes, 0);
+    }
+}
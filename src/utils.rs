@@ -1,23 +1,75 @@
 //! Utilities module
 
+use core::cmp::Ordering;
+
+/// Returns the midpoint of `left..right`, rounded down, without overflowing for bounds close to `usize::MAX`.
+///
+/// `left` must not be greater than `right`.
+pub const fn midpoint(left: usize, right: usize) -> usize {
+    left + (right - left) / 2
+}
+
 /// Checks if `arr` is sorted.
 pub fn is_sorted<T>(arr: &[T]) -> bool
 where
     T: Ord,
 {
-    arr.windows(2).all(|pair| pair[0] <= pair[1])
+    is_sorted_by(arr, Ord::cmp)
+}
+
+/// Checks if `arr` is sorted according to `compare`, i.e. no element compares `Greater` than the next one.
+pub fn is_sorted_by<T, F>(arr: &[T], mut compare: F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    arr.windows(2)
+        .all(|pair| compare(&pair[0], &pair[1]) != Ordering::Greater)
+}
+
+/// Checks if `compare` orders the elements of `arr` the way a sorted array is ordered relative to a target: every
+/// `Less` comes before every `Equal`, which comes before every `Greater`.
+pub fn is_sorted_relative_to<T, F>(arr: &[T], mut compare: F) -> bool
+where
+    F: FnMut(&T) -> Ordering,
+{
+    let mut previous = Ordering::Less;
+
+    arr.iter().all(|element| {
+        let current = compare(element);
+        let in_order = previous <= current;
+
+        previous = current;
+
+        in_order
+    })
+}
+
+/// Returns the index of the smallest element of `arr` if it is a rotation of a sorted array, i.e. if it has at most
+/// one descent and, when it has one, its last element is smaller than or equal to its first. An unrotated array
+/// returns 0.
+pub fn rotation_offset<T>(arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    let mut descents = (1..arr.len()).filter(|&index| arr[index - 1] > arr[index]);
+
+    match (descents.next(), descents.next()) {
+        (None, _) => Some(0),
+        (Some(offset), None) if arr[arr.len() - 1] <= arr[0] => Some(offset),
+        _ => None,
+    }
 }
 
 #[cfg(test)]
 mod utils_tests {
-    use super::is_sorted;
+    use super::{is_sorted, is_sorted_by, is_sorted_relative_to, midpoint, rotation_offset};
 
     #[test]
     fn is_sorted_returns_true_for_empty_arr() {
         let arr: [i32; 0] = [];
         let result = is_sorted(&arr);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -25,7 +77,7 @@ mod utils_tests {
         let arr = [1];
         let result = is_sorted(&arr);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -33,7 +85,7 @@ mod utils_tests {
         let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
         let result = is_sorted(&arr);
 
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -41,6 +93,95 @@ mod utils_tests {
         let arr = [1, 2, 3, 5, 4, 6, 7, 8, 9, 10];
         let result = is_sorted(&arr);
 
-        assert_eq!(result, false);
+        assert!(!result);
+    }
+
+    #[test]
+    fn is_sorted_relative_to_returns_true_for_ordered_comparisons() {
+        let arr = [1, 2, 3, 3, 4, 5];
+        let result = is_sorted_relative_to(&arr, |element| element.cmp(&3));
+
+        assert!(result);
+    }
+
+    #[test]
+    fn is_sorted_relative_to_returns_false_for_unordered_comparisons() {
+        let arr = [1, 4, 3, 5];
+        let result = is_sorted_relative_to(&arr, |element| element.cmp(&3));
+
+        assert!(!result);
+    }
+
+    #[test]
+    fn rotation_offset_returns_index_of_smallest_element_for_rotated_arr() {
+        let arr = [4, 5, 6, 1, 2, 3];
+        let result = rotation_offset(&arr);
+
+        assert_eq!(result, Some(3));
+    }
+
+    #[test]
+    fn rotation_offset_returns_zero_for_sorted_arr() {
+        let arr = [1, 2, 2, 3];
+        let result = rotation_offset(&arr);
+
+        assert_eq!(result, Some(0));
+    }
+
+    #[test]
+    fn rotation_offset_returns_none_for_arr_that_is_not_a_rotation() {
+        assert_eq!(rotation_offset(&[1, 3, 2]), None);
+        assert_eq!(rotation_offset(&[3, 1, 4, 2]), None);
+    }
+
+    #[test]
+    fn is_sorted_by_accepts_ascending_order() {
+        let arr = [1, 2, 2, 3];
+        let result = is_sorted_by(&arr, |a, b| a.cmp(b));
+
+        assert!(result);
+    }
+
+    #[test]
+    fn is_sorted_by_accepts_descending_order() {
+        let arr = [3, 2, 2, 1];
+
+        assert!(is_sorted_by(&arr, |a, b| b.cmp(a)));
+        assert!(!is_sorted_by(&arr, |a, b| a.cmp(b)));
+    }
+
+    #[test]
+    fn is_sorted_by_accepts_order_by_key() {
+        let arr = [(3, 'a'), (1, 'b'), (2, 'b'), (0, 'c')];
+
+        assert!(is_sorted_by(&arr, |a, b| a.1.cmp(&b.1)));
+        assert!(!is_sorted_by(&arr, |a, b| a.0.cmp(&b.0)));
+    }
+
+    #[test]
+    fn is_sorted_by_returns_true_for_empty_and_one_element_arrs() {
+        let empty: [i32; 0] = [];
+
+        assert!(is_sorted_by(&empty, |a, b| a.cmp(b)));
+        assert!(is_sorted_by(&[1], |a, b| a.cmp(b)));
+    }
+
+    #[test]
+    fn midpoint_returns_midpoint_of_small_bounds() {
+        assert_eq!(midpoint(0, 0), 0);
+        assert_eq!(midpoint(0, 1), 0);
+        assert_eq!(midpoint(2, 7), 4);
+        assert_eq!(midpoint(3, 9), 6);
+    }
+
+    #[test]
+    fn midpoint_does_not_overflow_near_usize_max() {
+        assert_eq!(midpoint(usize::MAX - 1, usize::MAX), usize::MAX - 1);
+        assert_eq!(midpoint(usize::MAX, usize::MAX), usize::MAX);
+        assert_eq!(
+            midpoint(usize::MAX / 2, usize::MAX),
+            (3 << (usize::BITS - 2)) - 1
+        );
+        assert_eq!(midpoint(0, usize::MAX), usize::MAX / 2);
     }
 }
@@ -0,0 +1,201 @@
+use super::{
+    BinarySearch, ExponentialSearch, FibonacciSearch, JumpSearch, LinearInterpolationSearch,
+    Searcher, TernarySearch, UniformBinarySearch,
+};
+use core::ops::{Div, Sub};
+
+/// The search algorithms able to search any `Ord` element, for choosing one at runtime
+///
+/// Algorithms needing arithmetic on the elements are in [`NumericAlgorithm`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Algorithm {
+    /// [`binary_search`](crate::binary_search)
+    Binary,
+    /// [`exponential_search`](super::exponential_search)
+    Exponential,
+    /// [`fibonacci_search`](super::fibonacci_search)
+    Fibonacci,
+    /// [`jump_search`](super::jump_search)
+    Jump,
+    /// [`ternary_search`](super::ternary_search)
+    Ternary,
+    /// [`UniformBinarySearch`], with a lookup table built for the call
+    Uniform,
+}
+
+impl Algorithm {
+    /// Every algorithm, in declaration order
+    pub const ALL: &'static [Algorithm] = &[
+        Algorithm::Binary,
+        Algorithm::Exponential,
+        Algorithm::Fibonacci,
+        Algorithm::Jump,
+        Algorithm::Ternary,
+        Algorithm::Uniform,
+    ];
+}
+
+/// The search algorithms able to search numeric elements, a superset of [`Algorithm`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NumericAlgorithm {
+    /// An algorithm only needing `Ord`
+    Ordered(Algorithm),
+    /// [`linear_interpolation_search`](super::linear_interpolation_search)
+    InterpolationLinear,
+}
+
+impl From<Algorithm> for NumericAlgorithm {
+    fn from(algorithm: Algorithm) -> Self {
+        Self::Ordered(algorithm)
+    }
+}
+
+/// Searches `arr` for the index of `target` with the algorithm chosen by `algorithm`.
+///
+/// Every algorithm agrees on whether `target` is present. When it appears several times, they may return the indices
+/// of different occurrences.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations::{self, Algorithm};
+///
+/// let target = 5;
+/// let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+///
+/// for &algorithm in Algorithm::ALL {
+///     assert_eq!(variations::search_with(algorithm, &target, &arr), Some(4));
+/// }
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn search_with<T>(algorithm: Algorithm, target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    match algorithm {
+        Algorithm::Binary => BinarySearch.search(target, arr),
+        Algorithm::Exponential => ExponentialSearch.search(target, arr),
+        Algorithm::Fibonacci => FibonacciSearch.search(target, arr),
+        Algorithm::Jump => JumpSearch.search(target, arr),
+        Algorithm::Ternary => TernarySearch.search(target, arr),
+        Algorithm::Uniform => UniformBinarySearch::new().search(target, arr),
+    }
+}
+
+/// Searches `arr` for the index of `target` with the algorithm chosen by `algorithm`, including the algorithms needing
+/// arithmetic on the elements.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations::{self, Algorithm, NumericAlgorithm};
+///
+/// let target = 5u16;
+/// let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+///
+/// assert_eq!(variations::search_numeric_with(NumericAlgorithm::InterpolationLinear, &target, &arr), Some(4));
+/// assert_eq!(variations::search_numeric_with(Algorithm::Jump.into(), &target, &arr), Some(4));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn search_numeric_with<T>(algorithm: NumericAlgorithm, target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord + Sub + Copy,
+    <T as Sub>::Output: Div,
+    <<T as Sub>::Output as Div>::Output: Into<usize>,
+{
+    match algorithm {
+        NumericAlgorithm::Ordered(algorithm) => search_with(algorithm, target, arr),
+        NumericAlgorithm::InterpolationLinear => LinearInterpolationSearch.search(target, arr),
+    }
+}
+
+#[cfg(test)]
+mod algorithm_tests {
+    #[cfg(feature = "std")]
+    use super::{search_numeric_with, NumericAlgorithm};
+    use super::{search_with, Algorithm};
+    #[cfg(feature = "std")]
+    use crate::binary_search;
+    #[cfg(feature = "std")]
+    use crate::test_rng::Rng;
+
+    #[cfg(feature = "std")]
+    fn numeric_algorithms() -> Vec<NumericAlgorithm> {
+        Algorithm::ALL
+            .iter()
+            .map(|&algorithm| algorithm.into())
+            .chain(Some(NumericAlgorithm::InterpolationLinear))
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn algorithms_agree_with_binary_search_on_distinct_elements() {
+        let mut rng = Rng::new(0x5eed);
+
+        for _ in 0..200 {
+            let len = rng.below(40) as usize;
+            let mut arr: Vec<u16> = (0..len).map(|_| rng.below(100) as u16).collect();
+
+            arr.sort_unstable();
+            arr.dedup();
+
+            for target in 0..101 {
+                let expected = binary_search(&target, &arr);
+
+                for &algorithm in numeric_algorithms().iter() {
+                    assert_eq!(
+                        search_numeric_with(algorithm, &target, &arr),
+                        expected,
+                        "{:?} searching {} in {:?}",
+                        algorithm,
+                        target,
+                        arr
+                    );
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn algorithms_agree_with_binary_search_on_duplicates() {
+        let mut rng = Rng::new(0xd0d0);
+
+        for _ in 0..200 {
+            let len = rng.below(40) as usize;
+            let mut arr: Vec<u16> = (0..len).map(|_| rng.below(12) as u16).collect();
+
+            arr.sort_unstable();
+
+            for target in 0..13 {
+                let expected = binary_search(&target, &arr).is_some();
+
+                for &algorithm in numeric_algorithms().iter() {
+                    let found = search_numeric_with(algorithm, &target, &arr);
+
+                    assert_eq!(found.is_some(), expected, "{:?}", algorithm);
+                    assert!(found.is_none_or(|index| arr[index] == target));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn search_with_dispatches_every_ordered_algorithm() {
+        let target = "c";
+        let arr = ["a", "b", "c", "d"];
+
+        for &algorithm in Algorithm::ALL {
+            assert_eq!(search_with(algorithm, &target, &arr), Some(2));
+        }
+    }
+}
@@ -0,0 +1,286 @@
+use super::{Algorithm, NumericAlgorithm};
+use crate::{core, utils};
+use ::core::cmp::Ordering;
+
+/// Arrays shorter than this are always searched with plain binary search, whose few probes cost less than sampling
+pub const AUTO_MIN_LEN: usize = 64;
+
+/// Targets not greater than the element at `len / AUTO_EXPONENTIAL_DIVISOR` sit near the start of the array, where
+/// exponential search needs fewer probes than binary search
+pub const AUTO_EXPONENTIAL_DIVISOR: usize = 16;
+
+/// The number of evenly spaced elements sampled to estimate how uniformly the values are distributed
+pub const AUTO_SAMPLES: usize = 8;
+
+/// The largest distance, as a percentage of the value range, between a sampled element and the value a straight line
+/// from the first to the last element predicts for it, for the array to still count as uniform
+pub const AUTO_MAX_DEVIATION_PERCENT: u32 = 10;
+
+/// The number of interpolation probes made before falling back to binary search on the remaining window
+pub const AUTO_MAX_INTERPOLATION_PROBES: usize = 8;
+
+/// Chooses the algorithm [`search_auto`] uses for `target` in `arr`.
+///
+/// The heuristic, in order:
+///
+/// 1. arrays shorter than [`AUTO_MIN_LEN`] use binary search;
+/// 2. targets within the first [`AUTO_EXPONENTIAL_DIVISOR`]th of the array use exponential search;
+/// 3. arrays whose [`AUTO_SAMPLES`] sampled elements all lie within [`AUTO_MAX_DEVIATION_PERCENT`] of a straight line
+///    from the first to the last element use interpolation search;
+/// 4. everything else, such as clustered data, uses binary search.
+///
+/// The array is not checked to be sorted.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations::{self, Algorithm, NumericAlgorithm};
+///
+/// let uniform: Vec<i64> = (0..1000).map(|element| element * 3).collect();
+/// let clustered: Vec<i64> = (0..1000).map(|element| element * element * element).collect();
+///
+/// assert_eq!(variations::choose_algorithm(&2400, &uniform), NumericAlgorithm::InterpolationLinear);
+/// assert_eq!(variations::choose_algorithm(&9, &uniform), Algorithm::Exponential.into());
+/// assert_eq!(variations::choose_algorithm(&8_000_000, &clustered), Algorithm::Binary.into());
+/// ```
+pub fn choose_algorithm<T>(target: &T, arr: &[T]) -> NumericAlgorithm
+where
+    T: Ord + Copy + Into<i128>,
+{
+    if arr.len() < AUTO_MIN_LEN {
+        return Algorithm::Binary.into();
+    }
+
+    if *target <= arr[arr.len() / AUTO_EXPONENTIAL_DIVISOR] {
+        return Algorithm::Exponential.into();
+    }
+
+    if is_uniform(arr) {
+        NumericAlgorithm::InterpolationLinear
+    } else {
+        Algorithm::Binary.into()
+    }
+}
+
+/// Searches `arr` for the index of `target` with the algorithm [`choose_algorithm`] picks for it.
+///
+/// Interpolation search interpolates over the remaining window and is bounded by
+/// [`AUTO_MAX_INTERPOLATION_PROBES`], after which binary search finishes the window, so badly estimated distributions
+/// cost at most O(log n) extra probes. The result is the same as [`binary_search`](crate::binary_search) when the
+/// elements are distinct; when `target` appears several times, it is the index of one of its occurrences.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let arr: Vec<u32> = (0..1000).map(|element| element * 3).collect();
+///
+/// assert_eq!(variations::search_auto(&2400, &arr), Some(800));
+/// assert_eq!(variations::search_auto(&2401, &arr), None);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn search_auto<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord + Copy + Into<i128>,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Auto search encountered an array that is note sorted");
+    }
+
+    match choose_algorithm(target, arr) {
+        NumericAlgorithm::Ordered(Algorithm::Exponential) => core::exponential_search(target, arr),
+        NumericAlgorithm::InterpolationLinear => bounded_interpolation_search(target, arr),
+        _ => core::binary_search(target, arr),
+    }
+}
+
+/// Checks whether the sampled elements of `arr` lie close to a straight line from its first to its last element
+fn is_uniform<T>(arr: &[T]) -> bool
+where
+    T: Copy + Into<i128>,
+{
+    let last_index = arr.len() - 1;
+    let first = arr[0].into() as f64;
+    let span = arr[last_index].into() as f64 - first;
+    let tolerance = span * f64::from(AUTO_MAX_DEVIATION_PERCENT) / 100.0;
+
+    span > 0.0
+        && (1..AUTO_SAMPLES).all(|sample| {
+            let index = last_index / AUTO_SAMPLES * sample;
+            let predicted = first + span * (index as f64 / last_index as f64);
+
+            (arr[index].into() as f64 - predicted).abs() <= tolerance
+        })
+}
+
+/// Interpolation search over the window still containing `target`, finished by binary search after
+/// [`AUTO_MAX_INTERPOLATION_PROBES`] probes
+fn bounded_interpolation_search<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord + Copy + Into<i128>,
+{
+    let target_value = (*target).into();
+    let mut left = 0;
+    let mut right = arr.len();
+
+    for _ in 0..AUTO_MAX_INTERPOLATION_PROBES {
+        if left >= right {
+            return None;
+        }
+
+        let low = arr[left].into();
+        let high = arr[right - 1].into();
+
+        if target_value < low || target_value > high {
+            return None;
+        }
+
+        let last_offset = right - left - 1;
+        let offset = if high == low {
+            0
+        } else {
+            let fraction = (target_value - low) as f64 / (high - low) as f64;
+
+            ((fraction * last_offset as f64) as usize).min(last_offset)
+        };
+        let middle = left + offset;
+
+        match arr[middle].cmp(target) {
+            Ordering::Equal => return Some(middle),
+            Ordering::Greater => right = middle,
+            Ordering::Less => left = middle + 1,
+        }
+    }
+
+    core::binary_search(target, &arr[left..right]).map(|index| index + left)
+}
+
+#[cfg(test)]
+mod auto_tests {
+    use super::search_auto;
+    #[cfg(feature = "std")]
+    use super::{
+        choose_algorithm, AUTO_EXPONENTIAL_DIVISOR, AUTO_MAX_DEVIATION_PERCENT, AUTO_MIN_LEN,
+    };
+    #[cfg(feature = "std")]
+    use crate::binary_search;
+    #[cfg(feature = "std")]
+    use crate::test_rng::Rng;
+    #[cfg(feature = "std")]
+    use crate::variations::{Algorithm, NumericAlgorithm};
+
+    #[test]
+    #[should_panic(expected = "Auto search encountered an array that is note sorted")]
+    fn search_auto_panics_when_arr_is_not_sorted() {
+        let target = 5;
+        let arr = [1, 3, 2, 5];
+
+        search_auto(&target, &arr);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn choose_algorithm_uses_binary_search_below_min_len() {
+        let short: Vec<i64> = (0..AUTO_MIN_LEN as i64 - 1).collect();
+        let long: Vec<i64> = (0..AUTO_MIN_LEN as i64).collect();
+        let target = AUTO_MIN_LEN as i64 - 2;
+
+        assert_eq!(choose_algorithm(&target, &short), Algorithm::Binary.into());
+        assert_eq!(
+            choose_algorithm(&target, &long),
+            NumericAlgorithm::InterpolationLinear
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn choose_algorithm_uses_exponential_search_near_the_start() {
+        let arr: Vec<i64> = (0..1600).collect();
+        let boundary = (arr.len() / AUTO_EXPONENTIAL_DIVISOR) as i64;
+
+        assert_eq!(
+            choose_algorithm(&boundary, &arr),
+            Algorithm::Exponential.into()
+        );
+        assert_eq!(
+            choose_algorithm(&(boundary + 1), &arr),
+            NumericAlgorithm::InterpolationLinear
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn choose_algorithm_rejects_interpolation_past_max_deviation() {
+        let len = 1001;
+        // The values span 1_000_000, so a tenth of a percent of the range is 1000
+        let bulge = |tenths_of_percent: i64| -> Vec<i64> {
+            (0..len)
+                .map(|index| {
+                    let shift = if index == 500 {
+                        tenths_of_percent * 1000
+                    } else {
+                        0
+                    };
+
+                    index * 1000 + shift
+                })
+                .collect()
+        };
+        let within = bulge(i64::from(AUTO_MAX_DEVIATION_PERCENT) * 10 - 5);
+        let beyond = bulge(i64::from(AUTO_MAX_DEVIATION_PERCENT) * 10 + 5);
+
+        assert_eq!(
+            choose_algorithm(&900_000, &within),
+            NumericAlgorithm::InterpolationLinear
+        );
+        assert_eq!(
+            choose_algorithm(&900_000, &beyond),
+            Algorithm::Binary.into()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn search_auto_matches_binary_search_on_random_distributions() {
+        let mut rng = Rng::new(0xa070);
+
+        for round in 0..300 {
+            let len = rng.below(600) as usize;
+            let mut arr: Vec<i64> = match round % 3 {
+                0 => (0..len).map(|_| rng.below(10_000) as i64 - 5000).collect(),
+                1 => (0..len)
+                    .map(|_| {
+                        let value = rng.below(100) as i64;
+
+                        value * value * value
+                    })
+                    .collect(),
+                _ => (0..len).map(|_| rng.below(50) as i64).collect(),
+            };
+
+            arr.sort_unstable();
+
+            let has_duplicates = arr.windows(2).any(|pair| pair[0] == pair[1]);
+
+            for _ in 0..40 {
+                let target = match rng.below(2) {
+                    0 if !arr.is_empty() => arr[rng.below(arr.len() as u64) as usize],
+                    _ => rng.below(20_000) as i64 - 10_000,
+                };
+                let found = search_auto(&target, &arr);
+                let expected = binary_search(&target, &arr);
+
+                if has_duplicates {
+                    assert_eq!(found.is_some(), expected.is_some());
+                    assert!(found.is_none_or(|index| arr[index] == target));
+                } else {
+                    assert_eq!(found, expected);
+                }
+            }
+        }
+    }
+}
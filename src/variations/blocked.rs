@@ -0,0 +1,240 @@
+use crate::{
+    memory::{FieldUsage, MemoryBreakdown, MemoryUsage},
+    utils,
+};
+
+/// A sorted array stored as an implicit static B-tree (an S-tree) with `B` keys per node
+///
+/// Node `k` holds the keys at `k * B..(k + 1) * B`, and its `B + 1` children are the nodes `k * (B + 1) + i + 1`. A
+/// search reads one node per level and there are only `log_{B+1}(n)` levels, so with 16 `u32` keys per node, one
+/// cache line, a search over a million elements touches five cache lines. The last node is padded with copies of the
+/// largest element, which never match since every result is mapped back to an index of the original sorted array.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations::BlockedLayout;
+///
+/// let sorted: Vec<u32> = (0..100).map(|element| element * 2).collect();
+/// let layout: BlockedLayout<u32> = BlockedLayout::from_sorted(&sorted);
+///
+/// assert_eq!(layout.search(&84), Some(42));
+/// assert_eq!(layout.search(&85), None);
+/// assert_eq!(layout.lower_bound(&85), 43);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockedLayout<T, const B: usize = 16> {
+    keys: Vec<T>,
+    ranks: Vec<usize>,
+    len: usize,
+}
+
+impl<T, const B: usize> BlockedLayout<T, B> {
+    /// Builds the layout from a copy of `sorted`.
+    ///
+    /// # Panics
+    ///
+    /// The function panics if `B` is 0 or if the array is not sorted.
+    pub fn from_sorted(sorted: &[T]) -> Self
+    where
+        T: Ord + Clone,
+    {
+        if B == 0 {
+            panic!("Blocked layout needs at least one key per node");
+        }
+
+        if !utils::is_sorted(sorted) {
+            panic!("Blocked search encountered an array that is note sorted");
+        }
+
+        let blocks = sorted.len().div_ceil(B);
+        let mut positions = Vec::with_capacity(blocks * B);
+
+        in_order::<B>(blocks, 0, &mut positions);
+
+        // `positions` lists the key slots in sorted order. Slots past the end of `sorted` are padding, whose ranks are
+        // past the end as well
+        let mut ranks = vec![0; blocks * B];
+
+        for (rank, &position) in positions.iter().enumerate() {
+            ranks[position] = rank;
+        }
+
+        let keys = ranks
+            .iter()
+            .map(|&rank| sorted[rank.min(sorted.len() - 1)].clone())
+            .collect();
+
+        Self {
+            keys,
+            ranks,
+            len: sorted.len(),
+        }
+    }
+
+    /// Calculates the number of elements smaller than `target`, i.e. the index of its first occurrence in the
+    /// original sorted order if it is present.
+    pub fn lower_bound(&self, target: &T) -> usize
+    where
+        T: Ord,
+    {
+        self.first_not_less(target)
+            .map_or(self.len, |slot| self.ranks[slot].min(self.len))
+    }
+
+    /// Searches for `target`, returning the index of its first occurrence in the original sorted order.
+    pub fn search(&self, target: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        match self.first_not_less(target) {
+            Some(slot) if self.ranks[slot] < self.len && self.keys[slot] == *target => {
+                Some(self.ranks[slot])
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the number of elements, not counting the padding.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the slot of the first key in sorted order not smaller than `target`
+    fn first_not_less(&self, target: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        let blocks = self.keys.len() / B;
+        let mut block = 0;
+        let mut candidate = None;
+
+        while block < blocks {
+            let node = &self.keys[block * B..(block + 1) * B];
+            // The keys of a node are sorted, so counting the smaller ones gives the child to descend into. The count
+            // has no early exit, which lets the compiler vectorize it for primitive keys
+            let smaller = node.iter().filter(|key| *key < target).count();
+
+            if smaller < B {
+                candidate = Some(block * B + smaller);
+            }
+
+            block = block * (B + 1) + smaller + 1;
+        }
+
+        candidate
+    }
+}
+
+impl<T, const B: usize> MemoryUsage for BlockedLayout<T, B> {
+    fn memory_bytes(&self) -> MemoryBreakdown {
+        MemoryBreakdown::new(self)
+            .with(FieldUsage::of_vec("keys", &self.keys))
+            .with(FieldUsage::of_vec("ranks", &self.ranks))
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.keys.shrink_to_fit();
+        self.ranks.shrink_to_fit();
+    }
+}
+
+/// Pushes the key slots of the subtree rooted at `block` in sorted order
+fn in_order<const B: usize>(blocks: usize, block: usize, positions: &mut Vec<usize>) {
+    if block < blocks {
+        for key in 0..B {
+            in_order::<B>(blocks, block * (B + 1) + key + 1, positions);
+            positions.push(block * B + key);
+        }
+
+        in_order::<B>(blocks, block * (B + 1) + B + 1, positions);
+    }
+}
+
+#[cfg(test)]
+mod blocked_tests {
+    use super::BlockedLayout;
+    use crate::test_rng::Rng;
+    use crate::{binary_search_first, leftmost_rank};
+
+    fn assert_matches_sorted<const B: usize>(sorted: &[u32]) {
+        let layout: BlockedLayout<u32, B> = BlockedLayout::from_sorted(sorted);
+        let max = sorted.last().map_or(0, |&last| last + 2);
+
+        assert_eq!(layout.len(), sorted.len());
+
+        for target in 0..max {
+            assert_eq!(
+                layout.lower_bound(&target),
+                leftmost_rank(&target, sorted),
+                "lower bound of {} in {:?} with B = {}",
+                target,
+                sorted,
+                B
+            );
+            assert_eq!(layout.search(&target), binary_search_first(&target, sorted));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Blocked search encountered an array that is note sorted")]
+    fn from_sorted_panics_when_arr_is_not_sorted() {
+        BlockedLayout::<u32>::from_sorted(&[1, 3, 2, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Blocked layout needs at least one key per node")]
+    fn from_sorted_panics_for_empty_nodes() {
+        BlockedLayout::<u32, 0>::from_sorted(&[1, 2]);
+    }
+
+    #[test]
+    fn layout_of_empty_arr_is_empty() {
+        let layout = BlockedLayout::<u32>::from_sorted(&[]);
+
+        assert!(layout.is_empty());
+        assert_eq!(layout.search(&1), None);
+        assert_eq!(layout.lower_bound(&1), 0);
+    }
+
+    #[test]
+    fn layout_matches_sorted_arr_for_lengths_not_filling_the_last_node() {
+        for len in 0..120 {
+            let sorted: Vec<u32> = (0..len).map(|element| element * 2 + 1).collect();
+
+            assert_matches_sorted::<1>(&sorted);
+            assert_matches_sorted::<3>(&sorted);
+            assert_matches_sorted::<16>(&sorted);
+        }
+    }
+
+    #[test]
+    fn layout_returns_first_occurrence_of_duplicates_spanning_nodes() {
+        let mut rng = Rng::new(0xb7ee);
+
+        for _ in 0..200 {
+            let len = rng.below(200) as usize;
+            let mut sorted: Vec<u32> = (0..len).map(|_| rng.below(12) as u32).collect();
+
+            sorted.sort_unstable();
+
+            assert_matches_sorted::<2>(&sorted);
+            assert_matches_sorted::<4>(&sorted);
+            assert_matches_sorted::<16>(&sorted);
+        }
+    }
+
+    #[test]
+    fn padding_never_matches_the_largest_element_twice() {
+        let sorted = [1, 5, 9, 9, 9];
+        let layout: BlockedLayout<u32, 4> = BlockedLayout::from_sorted(&sorted);
+
+        assert_eq!(layout.search(&9), Some(2));
+        assert_eq!(layout.lower_bound(&10), 5);
+    }
+}
@@ -0,0 +1,234 @@
+use crate::utils;
+
+/// Performs binary search on `arr` in order to find the index of the first occurrence of `target`, updating the
+/// search bound with arithmetic on the comparison result instead of a branch. The loop always runs
+/// `ceil(log2(n))` times and the compiler can lower the update to a conditional move, which avoids branch
+/// mispredictions on large arrays.
+///
+/// This is expected to win for small `Copy` elements such as integers and random targets, where a branchy search
+/// mispredicts about half of its branches and a comparison is a single instruction. For elements with expensive
+/// comparisons, such as strings, or for targets that hit the same region repeatedly, the branchy search is as fast or
+/// faster.
+///
+/// The result is the same as [`binary_search`](crate::binary_search) when the elements are distinct. With duplicates,
+/// it is always the first occurrence, like [`binary_search_first`](crate::binary_search_first).
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let target = 5;
+/// let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9 ,10];
+/// let found = variations::branchless_search(&target, &arr);
+///
+/// assert_eq!(found, Some(4));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn branchless_search<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Branchless search encountered an array that is note sorted");
+    }
+
+    branchless_search_unchecked(target, arr)
+}
+
+/// Performs branchless binary search on `arr` like [`branchless_search`], without checking that `arr` is sorted.
+///
+/// This skips the `O(n)` scan of [`branchless_search`]. Passing an unsorted array is not memory unsafety, it only gives
+/// a meaningless result. The lower bound is at most the length and is checked against it before the last comparison, so
+/// the search never panics.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+///
+/// assert_eq!(variations::branchless_search_unchecked(&5, &arr), Some(4));
+/// ```
+pub fn branchless_search_unchecked<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    let index = branchless_lower_bound(target, arr);
+
+    if index < arr.len() && arr[index] == *target {
+        Some(index)
+    } else {
+        None
+    }
+}
+
+/// Calculates the leftmost rank of `target` in `arr`, i.e. the number of elements smaller than it, with the same
+/// branchless loop as [`branchless_search`]. The result is the same as [`leftmost_rank`](crate::leftmost_rank).
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let arr = [1, 2, 4, 4, 4, 5, 6, 7];
+///
+/// assert_eq!(variations::branchless_leftmost_rank(&4, &arr), 2);
+/// assert_eq!(variations::branchless_leftmost_rank(&8, &arr), 8);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn branchless_leftmost_rank<T>(target: &T, arr: &[T]) -> usize
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Branchless search encountered an array that is note sorted");
+    }
+
+    branchless_lower_bound(target, arr)
+}
+
+/// Returns the number of elements of `arr` smaller than `target`, without branching on the comparisons
+fn branchless_lower_bound<T>(target: &T, arr: &[T]) -> usize
+where
+    T: Ord,
+{
+    if arr.is_empty() {
+        return 0;
+    }
+
+    let mut base = 0;
+    let mut size = arr.len();
+
+    while size > 1 {
+        let half = size / 2;
+
+        base += half * (arr[base + half] < *target) as usize;
+        size -= half;
+    }
+
+    base + (arr[base] < *target) as usize
+}
+
+#[cfg(test)]
+mod branchless_search_tests {
+    #[cfg(feature = "std")]
+    use super::branchless_search_unchecked;
+    use super::{branchless_leftmost_rank, branchless_search};
+    #[cfg(feature = "std")]
+    use crate::test_rng::Rng;
+    #[cfg(feature = "std")]
+    use crate::{binary_search_first, core, leftmost_rank};
+
+    #[test]
+    #[should_panic(expected = "Branchless search encountered an array that is note sorted")]
+    fn branchless_search_panics_when_arr_is_not_sorted() {
+        let target = 5;
+        let arr = [1, 3, 2, 5];
+
+        branchless_search(&target, &arr);
+    }
+
+    #[test]
+    fn branchless_search_returns_none_for_empty_arr() {
+        let target = 5;
+        let arr = [];
+        let found = branchless_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn branchless_search_matches_binary_search_on_random_distinct_elements() {
+        let mut rng = Rng::new(0xb7a9c4);
+
+        for _ in 0..300 {
+            let len = rng.below(200) as usize;
+            let mut arr: Vec<u64> = (0..len).map(|_| rng.below(400)).collect();
+
+            arr.sort_unstable();
+            arr.dedup();
+
+            for _ in 0..50 {
+                let target = rng.below(402);
+
+                assert_eq!(
+                    branchless_search(&target, &arr),
+                    core::binary_search(&target, &arr)
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn branchless_search_returns_first_occurrence_of_duplicates() {
+        let mut rng = Rng::new(0xd00b1e);
+
+        for _ in 0..300 {
+            let len = rng.below(100) as usize;
+            let mut arr: Vec<u64> = (0..len).map(|_| rng.below(20)).collect();
+
+            arr.sort_unstable();
+
+            for target in 0..21 {
+                assert_eq!(
+                    branchless_search(&target, &arr),
+                    binary_search_first(&target, &arr)
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Branchless search encountered an array that is note sorted")]
+    fn branchless_leftmost_rank_panics_when_arr_is_not_sorted() {
+        let target = 5;
+        let arr = [1, 3, 2, 5];
+
+        branchless_leftmost_rank(&target, &arr);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn branchless_leftmost_rank_matches_leftmost_rank_on_random_arrays() {
+        let mut rng = Rng::new(0x1ef7);
+
+        for _ in 0..300 {
+            let len = rng.below(150) as usize;
+            let mut arr: Vec<u32> = (0..len).map(|_| rng.below(60) as u32).collect();
+
+            arr.sort_unstable();
+
+            for target in 0..62 {
+                assert_eq!(
+                    branchless_leftmost_rank(&target, &arr),
+                    leftmost_rank(&target, &arr)
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn branchless_search_unchecked_matches_branchless_search_on_sorted_arrs() {
+        for len in 0..40 {
+            let arr: Vec<u32> = (0..len).map(|element| element * 2).collect();
+
+            for target in 0..=len * 2 {
+                assert_eq!(
+                    branchless_search_unchecked(&target, &arr),
+                    branchless_search(&target, &arr)
+                );
+            }
+        }
+    }
+}
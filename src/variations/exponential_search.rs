@@ -1,4 +1,4 @@
-use std::cmp::min;
+use ::core::cmp::{min, Ordering};
 
 use crate::{core, utils};
 
@@ -27,28 +27,99 @@ where
         panic!("Exponential search encountered an array that is note sorted");
     }
 
+    exponential_search_unchecked(target, arr)
+}
+
+/// Performs exponential search on `arr` like [`exponential_search`], without checking that `arr` is sorted.
+///
+/// This skips the `O(n)` scan of [`exponential_search`]. Passing an unsorted array is not memory unsafety, it only
+/// gives a meaningless result. The gallop stops at the end of the array and the binary search stays inside the range it
+/// found, so the search never panics.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+///
+/// assert_eq!(variations::exponential_search_unchecked(&5, &arr), Some(4));
+/// ```
+pub fn exponential_search_unchecked<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    core::exponential_search(target, arr)
+}
+
+/// Performs [exponential search](https://en.wikipedia.org/wiki/Exponential_search) on `arr` in order to find the index of
+/// `target`, galloping outwards from the `start` hint instead of from the beginning of the array. This is cheap when
+/// `target` is close to `start`. A `start` past the end of the array is clamped to the last index.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let target = 7;
+/// let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+/// let found = variations::exponential_search_from(&target, &arr, 5);
+///
+/// assert_eq!(found, Some(6));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn exponential_search_from<T>(target: &T, arr: &[T], start: usize) -> Option<usize>
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Exponential search encountered an array that is note sorted");
+    }
+
     if arr.is_empty() {
         return None;
     }
 
     let arr_size = arr.len();
+    let start = min(start, arr_size - 1);
     let mut bound = 1;
+    let (left_bound, right_bound) = match arr[start].cmp(target) {
+        Ordering::Equal => return Some(start),
+        Ordering::Less => {
+            while bound < arr_size - start && &arr[start + bound] < target {
+                bound = bound.saturating_mul(2);
+            }
 
-    while bound < arr_size && &arr[bound] < target {
-        bound *= 2;
-    }
+            (
+                start + bound / 2 + 1,
+                min(start.saturating_add(bound).saturating_add(1), arr_size),
+            )
+        }
+        Ordering::Greater => {
+            while bound <= start && &arr[start - bound] > target {
+                bound = bound.saturating_mul(2);
+            }
 
-    let left_bound = bound / 2;
-    let right_bound = min(bound + 1, arr_size);
-    let slice_index = core::binary_search(target, &arr[left_bound..right_bound])?;
-    let index = slice_index + left_bound;
+            (start.saturating_sub(bound), start - bound / 2)
+        }
+    };
+    let index = left_bound + core::lower_bound(target, &arr[left_bound..right_bound]);
 
-    Some(index)
+    if index < right_bound && &arr[index] == target {
+        Some(index)
+    } else {
+        None
+    }
 }
 
 #[cfg(test)]
 mod exponential_search_tests {
-    use super::exponential_search;
+    #[cfg(feature = "std")]
+    use super::exponential_search_unchecked;
+    use super::{exponential_search, exponential_search_from};
 
     #[test]
     #[should_panic(expected = "Exponential search encountered an array that is note sorted")]
@@ -86,6 +157,33 @@ mod exponential_search_tests {
         assert_eq!(found, Some(0));
     }
 
+    #[test]
+    fn exponential_search_returns_none_if_target_below_one_element_arr() {
+        let target = 1;
+        let arr = [5];
+        let found = exponential_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn exponential_search_returns_none_if_target_below_every_element() {
+        let target = 1;
+        let arr = [5, 6, 7];
+        let found = exponential_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn exponential_search_returns_none_if_target_above_every_element() {
+        let target = 8;
+        let arr = [5, 6, 7];
+        let found = exponential_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
     #[test]
     fn exponential_search_returns_none_if_target_not_in_arr() {
         let target = 12;
@@ -103,4 +201,83 @@ mod exponential_search_tests {
 
         assert_eq!(found, Some(4));
     }
+
+    #[test]
+    fn exponential_search_from_returns_none_for_empty_arr() {
+        let target = 5;
+        let arr = [];
+        let found = exponential_search_from(&target, &arr, 0);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn exponential_search_from_returns_some_index_if_hint_is_exact() {
+        let target = 5;
+        let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let found = exponential_search_from(&target, &arr, 4);
+
+        assert_eq!(found, Some(4));
+    }
+
+    #[test]
+    fn exponential_search_from_returns_some_index_if_hint_is_too_low() {
+        let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+        for target in 3..=10 {
+            assert_eq!(exponential_search_from(&target, &arr, 2), Some(target - 1));
+        }
+    }
+
+    #[test]
+    fn exponential_search_from_returns_some_index_if_hint_is_too_high() {
+        let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+        for target in 1..=8 {
+            assert_eq!(exponential_search_from(&target, &arr, 8), Some(target - 1));
+        }
+    }
+
+    #[test]
+    fn exponential_search_from_returns_none_if_target_not_in_arr() {
+        let arr = [2, 4, 6, 8, 10, 12];
+
+        assert_eq!(exponential_search_from(&0, &arr, 3), None);
+        assert_eq!(exponential_search_from(&7, &arr, 0), None);
+        assert_eq!(exponential_search_from(&7, &arr, 5), None);
+        assert_eq!(exponential_search_from(&13, &arr, 3), None);
+    }
+
+    #[test]
+    fn exponential_search_from_clamps_start_past_the_end() {
+        let target = 2;
+        let arr = [1, 2, 3];
+        let found = exponential_search_from(&target, &arr, 100);
+
+        assert_eq!(found, Some(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Exponential search encountered an array that is note sorted")]
+    fn exponential_search_from_panics_when_arr_is_not_sorted() {
+        let target = 5;
+        let arr = [1, 3, 2, 5];
+
+        exponential_search_from(&target, &arr, 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn exponential_search_unchecked_matches_exponential_search_on_sorted_arrs() {
+        for len in 0..40 {
+            let arr: Vec<u32> = (0..len).map(|element| element * 2).collect();
+
+            for target in 0..=len * 2 {
+                assert_eq!(
+                    exponential_search_unchecked(&target, &arr),
+                    exponential_search(&target, &arr)
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,355 @@
+use crate::{
+    memory::{FieldUsage, MemoryBreakdown, MemoryUsage},
+    utils,
+};
+
+/// Rearranges a sorted array into the [Eytzinger](https://algorithmica.org/en/eytzinger) layout, the breadth-first
+/// order of the implicit balanced search tree over it. The children of the element at index `i` sit at `2i + 1` and
+/// `2i + 2`, so a search touches the elements of each level close together, which is friendlier to the cache than
+/// halving a sorted array.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let sorted = [1, 2, 3, 4, 5, 6, 7];
+/// let eytzinger = variations::to_eytzinger(&sorted);
+///
+/// assert_eq!(eytzinger, vec![4, 2, 6, 1, 3, 5, 7]);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn to_eytzinger<T>(sorted: &[T]) -> Vec<T>
+where
+    T: Ord + Clone,
+{
+    EytzingerLayout::from_sorted(sorted).layout
+}
+
+/// Searches an array in the Eytzinger layout for `target`, returning the index of its first occurrence in the
+/// original sorted order.
+///
+/// `eytzinger` must have been built by [`to_eytzinger`]; any other array gives meaningless results. The layout is not
+/// checked, since that would cost O(n) per search.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let sorted = [1, 3, 5, 7, 9, 11];
+/// let eytzinger = variations::to_eytzinger(&sorted);
+///
+/// assert_eq!(variations::eytzinger_search(&7, &eytzinger), Some(3));
+/// assert_eq!(variations::eytzinger_search(&8, &eytzinger), None);
+/// ```
+pub fn eytzinger_search<T>(target: &T, eytzinger: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    let position = lower_bound_position(target, eytzinger)?;
+
+    Some(sorted_index(eytzinger.len(), position))
+}
+
+/// Returns the tree position of the first element not less than `target`, if it equals `target`
+///
+/// The descent only moves to a child, `2k + 1` or `2k + 2`, without tracking any rank. Every right turn appends a one
+/// to the binary form of the one-based position, so the last left turn, the lower bound, is recovered at the end by
+/// shifting out the trailing ones and the zero before them.
+fn lower_bound_position<T>(target: &T, eytzinger: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    let len = eytzinger.len();
+    // One-based position, so that the children of `k` are `2k` and `2k + 1`
+    let mut k = 1;
+
+    while k <= len {
+        k = 2 * k + usize::from(eytzinger[k - 1] < *target);
+    }
+
+    k >>= k.trailing_ones() + 1;
+
+    // `k` is zero when every element is less than `target`
+    let position = k.checked_sub(1)?;
+
+    if eytzinger[position] == *target {
+        Some(position)
+    } else {
+        None
+    }
+}
+
+/// A sorted array stored in the [Eytzinger](https://algorithmica.org/en/eytzinger) layout, along with the mappings
+/// between tree positions and sorted indices in both directions
+///
+/// This is the owned form of [`to_eytzinger`] and [`eytzinger_search`]. The layout keeps the elements probed by the
+/// first few levels of every search in a handful of cache lines, which matters once the array no longer fits in the
+/// cache. The sorted index of every tree position is stored too, so a search maps its result back with one lookup
+/// instead of the arithmetic [`eytzinger_search`] does.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations::EytzingerLayout;
+///
+/// let layout = EytzingerLayout::from_sorted(&[1, 3, 3, 5, 8, 13]);
+///
+/// assert_eq!(layout.search(&3), Some(1));
+/// assert_eq!(layout.search(&4), None);
+/// assert_eq!(layout.get_sorted(4), Some(&8));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EytzingerLayout<T> {
+    layout: Vec<T>,
+    positions: Vec<usize>,
+    sorted_indices: Vec<usize>,
+}
+
+impl<T> EytzingerLayout<T> {
+    /// Permutes a copy of `sorted` into the Eytzinger layout.
+    ///
+    /// # Panics
+    ///
+    /// The function panics if the array is not sorted.
+    pub fn from_sorted(sorted: &[T]) -> Self
+    where
+        T: Ord + Clone,
+    {
+        if !utils::is_sorted(sorted) {
+            panic!("Eytzinger search encountered an array that is note sorted");
+        }
+
+        let mut positions = Vec::with_capacity(sorted.len());
+
+        in_order(sorted.len(), 0, &mut positions);
+
+        // `positions` lists the tree positions in sorted order, so inverting it gives the sorted index of every
+        // position
+        let mut sorted_indices = vec![0; sorted.len()];
+
+        for (sorted_index, &position) in positions.iter().enumerate() {
+            sorted_indices[position] = sorted_index;
+        }
+
+        let layout = sorted_indices
+            .iter()
+            .map(|&sorted_index| sorted[sorted_index].clone())
+            .collect();
+
+        Self {
+            layout,
+            positions,
+            sorted_indices,
+        }
+    }
+
+    /// Searches for `target`, returning the index of its first occurrence in the original sorted order.
+    pub fn search(&self, target: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        lower_bound_position(target, &self.layout).map(|position| self.sorted_indices[position])
+    }
+
+    /// Returns the element at `index` in the original sorted order, or `None` if it is out of bounds.
+    pub fn get_sorted(&self, index: usize) -> Option<&T> {
+        self.positions
+            .get(index)
+            .map(|&position| &self.layout[position])
+    }
+
+    /// Returns the elements in the Eytzinger layout.
+    pub fn as_slice(&self) -> &[T] {
+        &self.layout
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.layout.len()
+    }
+
+    /// Returns `true` if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.layout.is_empty()
+    }
+}
+
+impl<T> MemoryUsage for EytzingerLayout<T> {
+    fn memory_bytes(&self) -> MemoryBreakdown {
+        MemoryBreakdown::new(self)
+            .with(FieldUsage::of_vec("layout", &self.layout))
+            .with(FieldUsage::of_vec("positions", &self.positions))
+            .with(FieldUsage::of_vec("sorted_indices", &self.sorted_indices))
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.layout.shrink_to_fit();
+        self.positions.shrink_to_fit();
+        self.sorted_indices.shrink_to_fit();
+    }
+}
+
+/// Pushes the tree positions of the subtree rooted at `position` in sorted order
+fn in_order(len: usize, position: usize, slots: &mut Vec<usize>) {
+    if position < len {
+        in_order(len, 2 * position + 1, slots);
+        slots.push(position);
+        in_order(len, 2 * position + 2, slots);
+    }
+}
+
+/// Returns the index in sorted order of the element at tree `position` of a tree of `len` elements
+///
+/// In the perfect tree with the same height, the node at depth `d` and offset `o` within its level has the in-order
+/// index `(2o + 1) * 2^(h - d) - 1`, and the leaves take the even indices. Subtracting the leaves missing from the
+/// partial last level before the node gives its index in the actual tree, with no walk over subtrees.
+fn sorted_index(len: usize, position: usize) -> usize {
+    let k = position + 1;
+    let depth = usize::BITS - 1 - k.leading_zeros();
+    let height = usize::BITS - 1 - len.leading_zeros();
+    let perfect_index = ((2 * (k - (1 << depth)) + 1) << (height - depth)) - 1;
+    let last_level_len = len - ((1 << height) - 1);
+    let leaves_before = perfect_index.div_ceil(2);
+
+    perfect_index - leaves_before.saturating_sub(last_level_len)
+}
+
+#[cfg(test)]
+mod eytzinger_tests {
+    use super::{eytzinger_search, in_order, sorted_index, to_eytzinger, EytzingerLayout};
+    use crate::test_rng::Rng;
+    use crate::{binary_search_first, leftmost_rank};
+
+    #[test]
+    #[should_panic(expected = "Eytzinger search encountered an array that is note sorted")]
+    fn to_eytzinger_panics_when_arr_is_not_sorted() {
+        let arr = [1, 3, 2, 5];
+
+        to_eytzinger(&arr);
+    }
+
+    #[test]
+    fn to_eytzinger_keeps_every_element() {
+        for len in 0..40 {
+            let sorted: Vec<u32> = (0..len).collect();
+            let mut eytzinger = to_eytzinger(&sorted);
+
+            eytzinger.sort_unstable();
+
+            assert_eq!(eytzinger, sorted);
+        }
+    }
+
+    #[test]
+    fn to_eytzinger_orders_parents_between_children() {
+        let sorted: Vec<u32> = (0..20).collect();
+        let eytzinger = to_eytzinger(&sorted);
+
+        for position in 0..eytzinger.len() {
+            if let Some(left) = eytzinger.get(2 * position + 1) {
+                assert!(*left < eytzinger[position]);
+            }
+
+            if let Some(right) = eytzinger.get(2 * position + 2) {
+                assert!(*right > eytzinger[position]);
+            }
+        }
+    }
+
+    #[test]
+    fn eytzinger_search_returns_sorted_index_for_every_length() {
+        for len in 0..40 {
+            let sorted: Vec<u32> = (0..len).map(|element| element * 2 + 1).collect();
+            let eytzinger = to_eytzinger(&sorted);
+
+            for target in 0..len * 2 + 2 {
+                assert_eq!(
+                    eytzinger_search(&target, &eytzinger),
+                    binary_search_first(&target, &sorted)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn eytzinger_search_returns_first_occurrence_of_duplicates() {
+        let sorted = [1, 2, 2, 2, 3, 3, 5, 5, 5, 5, 8];
+        let eytzinger = to_eytzinger(&sorted);
+
+        for target in 0..10 {
+            assert_eq!(
+                eytzinger_search(&target, &eytzinger),
+                binary_search_first(&target, &sorted)
+            );
+        }
+    }
+
+    #[test]
+    fn sorted_index_matches_in_order_traversal() {
+        for len in 1..130 {
+            let mut positions = Vec::new();
+
+            in_order(len, 0, &mut positions);
+
+            for (index, &position) in positions.iter().enumerate() {
+                assert_eq!(sorted_index(len, position), index, "len {}", len);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Eytzinger search encountered an array that is note sorted")]
+    fn from_sorted_panics_when_arr_is_not_sorted() {
+        EytzingerLayout::from_sorted(&[1, 3, 2, 5]);
+    }
+
+    #[test]
+    fn layout_finds_every_element_at_its_sorted_index() {
+        let mut rng = Rng::new(0xe172);
+
+        for _ in 0..200 {
+            let len = rng.below(300) as usize;
+            let mut sorted: Vec<u64> = (0..len).map(|_| rng.below(500)).collect();
+
+            sorted.sort_unstable();
+
+            let layout = EytzingerLayout::from_sorted(&sorted);
+
+            assert_eq!(layout.len(), sorted.len());
+
+            for (index, element) in sorted.iter().enumerate() {
+                let found = layout.search(element);
+
+                assert_eq!(found, Some(leftmost_rank(element, &sorted)));
+                assert_eq!(sorted[found.unwrap()], sorted[index]);
+                assert_eq!(layout.get_sorted(index), Some(element));
+            }
+
+            assert_eq!(layout.get_sorted(len), None);
+        }
+    }
+
+    #[test]
+    fn layout_returns_none_for_absent_targets() {
+        let sorted: Vec<u64> = (0..100).map(|element| element * 2).collect();
+        let layout = EytzingerLayout::from_sorted(&sorted);
+
+        for target in (1..200).step_by(2) {
+            assert_eq!(layout.search(&target), None);
+        }
+    }
+
+    #[test]
+    fn layout_of_empty_arr_is_empty() {
+        let layout = EytzingerLayout::<u32>::from_sorted(&[]);
+
+        assert!(layout.is_empty());
+        assert_eq!(layout.search(&1), None);
+        assert_eq!(layout.get_sorted(0), None);
+    }
+}
@@ -0,0 +1,185 @@
+use ::core::cmp::{min, Ordering};
+
+use crate::utils;
+
+/// Performs [Fibonacci search](https://en.wikipedia.org/wiki/Fibonacci_search_technique) on `arr` in order to find the index of `target`.
+/// The array is divided using Fibonacci numbers instead of halving, so only additions and subtractions are needed.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let target = 5;
+/// let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9 ,10];
+/// let found = variations::fibonacci_search(&target, &arr);
+///
+/// assert_eq!(found, Some(4));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn fibonacci_search<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Fibonacci search encountered an array that is note sorted");
+    }
+
+    fibonacci_search_unchecked(target, arr)
+}
+
+/// Performs Fibonacci search on `arr` like [`fibonacci_search`], without checking that `arr` is sorted.
+///
+/// This skips the `O(n)` scan of [`fibonacci_search`]. Passing an unsorted array is not memory unsafety, it only gives
+/// a meaningless result. Every probe is clamped to the last element, so the search never panics.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+///
+/// assert_eq!(variations::fibonacci_search_unchecked(&5, &arr), Some(4));
+/// ```
+pub fn fibonacci_search_unchecked<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    if arr.is_empty() {
+        return None;
+    }
+
+    let arr_size = arr.len();
+    let mut fib_lower = 0;
+    let mut fib_middle = 1;
+    let mut fib = 1;
+
+    while fib < arr_size {
+        fib_lower = fib_middle;
+        fib_middle = fib;
+        fib = fib_middle + fib_lower;
+    }
+
+    // Number of elements at the front of the array that have been eliminated
+    let mut offset = 0;
+
+    while fib > 1 {
+        let index = min(offset + fib_lower, arr_size) - 1;
+
+        match arr[index].cmp(target) {
+            Ordering::Less => {
+                fib = fib_middle;
+                fib_middle = fib_lower;
+                fib_lower = fib - fib_middle;
+                offset = index + 1;
+            }
+            Ordering::Equal => return Some(index),
+            Ordering::Greater => {
+                fib = fib_lower;
+                fib_middle -= fib_lower;
+                fib_lower = fib - fib_middle;
+            }
+        }
+    }
+
+    if fib_middle == 1 && offset < arr_size && &arr[offset] == target {
+        return Some(offset);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod fibonacci_search_tests {
+    use super::fibonacci_search;
+    #[cfg(feature = "std")]
+    use super::fibonacci_search_unchecked;
+
+    #[test]
+    #[should_panic(expected = "Fibonacci search encountered an array that is note sorted")]
+    fn fibonacci_search_panics_when_arr_is_not_sorted() {
+        let target = 5;
+        let arr = [1, 3, 2, 5];
+
+        fibonacci_search(&target, &arr);
+    }
+
+    #[test]
+    fn fibonacci_search_returns_none_for_empty_arr() {
+        let target = 5;
+        let arr = [];
+        let found = fibonacci_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn fibonacci_search_returns_none_if_target_not_in_one_element_arr() {
+        let target = 5;
+        let arr = [4];
+        let found = fibonacci_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn fibonacci_search_returns_some_index_if_target_in_one_element_arr() {
+        let target = 5;
+        let arr = [5];
+        let found = fibonacci_search(&target, &arr);
+
+        assert_eq!(found, Some(0));
+    }
+
+    #[test]
+    fn fibonacci_search_returns_none_if_target_not_in_arr() {
+        let target = 12;
+        let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let found = fibonacci_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn fibonacci_search_returns_some_index_if_target_in_arr() {
+        let target = 5;
+        let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let found = fibonacci_search(&target, &arr);
+
+        assert_eq!(found, Some(4));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fibonacci_search_finds_every_element() {
+        for len in 1..=30 {
+            let arr: Vec<i32> = (0..len).map(|i| i * 2).collect();
+
+            for (index, element) in arr.iter().enumerate() {
+                assert_eq!(fibonacci_search(element, &arr), Some(index));
+                assert_eq!(fibonacci_search(&(element + 1), &arr), None);
+            }
+
+            assert_eq!(fibonacci_search(&-1, &arr), None);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fibonacci_search_unchecked_matches_fibonacci_search_on_sorted_arrs() {
+        for len in 0..40 {
+            let arr: Vec<u32> = (0..len).map(|element| element * 2).collect();
+
+            for target in 0..=len * 2 {
+                assert_eq!(
+                    fibonacci_search_unchecked(&target, &arr),
+                    fibonacci_search(&target, &arr)
+                );
+            }
+        }
+    }
+}
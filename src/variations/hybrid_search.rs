@@ -0,0 +1,130 @@
+use crate::utils;
+use ::core::cmp::Ordering;
+
+/// A threshold for [`binary_search_hybrid`] that suits arrays of small `Copy` elements such as integers
+pub const DEFAULT_HYBRID_THRESHOLD: usize = 32;
+
+/// Performs binary search on `arr` in order to find the index of `target`, switching to a linear scan once the window
+/// still being searched holds fewer than `threshold` elements.
+///
+/// A linear scan over a few adjacent elements is predictable for the branch predictor and stays within a couple of
+/// cache lines, so it beats the last halving steps on small windows. A `threshold` of 0 or 1 gives plain binary
+/// search, and one larger than the array gives a linear scan.
+///
+/// The result is the same as [`binary_search`](crate::binary_search) when the elements are distinct. When `target`
+/// appears several times, it is the index of one of its occurrences.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations::{self, DEFAULT_HYBRID_THRESHOLD};
+///
+/// let target = 5;
+/// let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+///
+/// assert_eq!(variations::binary_search_hybrid(&target, &arr, DEFAULT_HYBRID_THRESHOLD), Some(4));
+/// assert_eq!(variations::binary_search_hybrid(&target, &arr, 4), Some(4));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn binary_search_hybrid<T>(target: &T, arr: &[T], threshold: usize) -> Option<usize>
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Hybrid search encountered an array that is note sorted");
+    }
+
+    let mut left = 0;
+    let mut right = arr.len();
+
+    while right - left >= threshold.max(1) {
+        let middle = utils::midpoint(left, right);
+
+        match arr[middle].cmp(target) {
+            Ordering::Equal => return Some(middle),
+            Ordering::Greater => right = middle,
+            Ordering::Less => left = middle + 1,
+        }
+    }
+
+    for (offset, element) in arr[left..right].iter().enumerate() {
+        match element.cmp(target) {
+            Ordering::Equal => return Some(left + offset),
+            Ordering::Greater => return None,
+            Ordering::Less => {}
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod hybrid_search_tests {
+    use super::{binary_search_hybrid, DEFAULT_HYBRID_THRESHOLD};
+    #[cfg(feature = "std")]
+    use crate::binary_search;
+    #[cfg(feature = "std")]
+    use crate::test_rng::Rng;
+
+    #[test]
+    #[should_panic(expected = "Hybrid search encountered an array that is note sorted")]
+    fn binary_search_hybrid_panics_when_arr_is_not_sorted() {
+        let target = 5;
+        let arr = [1, 3, 2, 5];
+
+        binary_search_hybrid(&target, &arr, DEFAULT_HYBRID_THRESHOLD);
+    }
+
+    #[test]
+    fn binary_search_hybrid_returns_none_for_empty_arr() {
+        let arr: [i32; 0] = [];
+
+        for threshold in 0..3 {
+            assert_eq!(binary_search_hybrid(&5, &arr, threshold), None);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn binary_search_hybrid_matches_binary_search_for_every_threshold() {
+        let mut rng = Rng::new(0x4b1d);
+
+        for _ in 0..200 {
+            let len = rng.below(100) as usize;
+            let mut arr: Vec<u32> = (0..len).map(|_| rng.below(300) as u32).collect();
+
+            arr.sort_unstable();
+            arr.dedup();
+
+            for threshold in [0, 1, 2, 3, 8, DEFAULT_HYBRID_THRESHOLD, 200] {
+                for target in 0..302 {
+                    assert_eq!(
+                        binary_search_hybrid(&target, &arr, threshold),
+                        binary_search(&target, &arr),
+                        "threshold {} searching {} in {:?}",
+                        threshold,
+                        target,
+                        arr
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn binary_search_hybrid_finds_an_occurrence_of_duplicates() {
+        let arr = [1, 2, 2, 2, 3, 3, 5, 5, 5, 5, 8];
+
+        for threshold in 0..12 {
+            for target in 0..10 {
+                let found = binary_search_hybrid(&target, &arr, threshold);
+
+                assert_eq!(found.is_some(), arr.contains(&target));
+                assert!(found.is_none_or(|index| arr[index] == target));
+            }
+        }
+    }
+}
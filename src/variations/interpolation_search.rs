@@ -1,5 +1,5 @@
 use crate::utils;
-use std::{
+use core::{
     cmp::Ordering,
     ops::{Div, Sub},
 };
@@ -34,6 +34,10 @@ where
 /// Performs [interpolation search](https://en.wikipedia.org/wiki/Interpolation_search) on `arr` in order to find the index of `target`
 /// The function's `interpolation_fn` should calculate the midpoint.
 ///
+/// `interpolation_fn` is only called while `target` lies strictly between the elements at the ends of the window, so
+/// its `l` and `r` arguments always differ and `*r - *l` is never zero. Windows whose ends are equal, such as in an
+/// array of equal values, are resolved by comparing the ends instead.
+///
 /// # Examples
 ///
 /// ```
@@ -62,6 +66,33 @@ where
         panic!("Interpolation search encountered an array that is note sorted");
     }
 
+    interpolation_search_unchecked(target, arr, interpolation_fn)
+}
+
+/// Performs interpolation search on `arr` like [`interpolation_search`], without checking that `arr` is sorted.
+///
+/// This skips the `O(n)` scan of [`interpolation_search`]. Passing an unsorted array is not memory unsafety, it only
+/// gives a meaningless result. The search can only panic if `interpolation_fn` returns an offset past the right end of
+/// the window, which in an unsorted window can move the left end past the end of `arr`.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+///
+/// assert_eq!(variations::interpolation_search_unchecked(&5u16, &arr, |t, l, r| ((*t - *l) / (*r - *l)).into()), Some(4));
+/// ```
+pub fn interpolation_search_unchecked<T, InterpolationFn>(
+    target: &T,
+    arr: &[T],
+    interpolation_fn: InterpolationFn,
+) -> Option<usize>
+where
+    InterpolationFn: Fn(&T, &T, &T) -> usize,
+    T: Ord,
+{
     if arr.is_empty() {
         return None;
     }
@@ -83,12 +114,85 @@ where
         }
     }
 
+    // The loop stops as soon as the target is not strictly inside the window, which includes landing on either end
+    if left <= right && &arr[left] == target {
+        Some(left)
+    } else if left <= right && &arr[right] == target {
+        Some(right)
+    } else {
+        None
+    }
+}
+
+/// Performs [interpolation search](https://en.wikipedia.org/wiki/Interpolation_search) on an `i64` array in order to
+/// find the index of `target`, using [linear interpolation](https://en.wikipedia.org/wiki/Linear_interpolation)
+/// between the ends of the window.
+///
+/// Unlike [`linear_interpolation_search`], this accepts negative values: the interpolation is computed in `i128`, where
+/// differences between any two `i64`s fit, and the probe is clamped into the window. A window whose ends are equal is
+/// resolved without dividing.
+///
+/// The result is the same as [`binary_search`](crate::binary_search) when the elements are distinct. When `target`
+/// appears several times, it is the index of one of its occurrences.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let arr = [-40, -25, -10, 0, 15, 30];
+///
+/// assert_eq!(variations::linear_interpolation_search_i64(-10, &arr), Some(2));
+/// assert_eq!(variations::linear_interpolation_search_i64(-11, &arr), None);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn linear_interpolation_search_i64(target: i64, arr: &[i64]) -> Option<usize> {
+    if !utils::is_sorted(arr) {
+        panic!("Interpolation search encountered an array that is note sorted");
+    }
+
+    if arr.is_empty() {
+        return None;
+    }
+
+    let mut left = 0;
+    let mut right = arr.len() - 1;
+
+    while left <= right && arr[left] <= target && target <= arr[right] {
+        if arr[left] == arr[right] {
+            return Some(left);
+        }
+
+        let span = i128::from(arr[right]) - i128::from(arr[left]);
+        let distance = i128::from(target) - i128::from(arr[left]);
+        let offset = distance * (right - left) as i128 / span;
+        let middle = left + (offset as usize).min(right - left);
+
+        match arr[middle].cmp(&target) {
+            Ordering::Less => left = middle + 1,
+            Ordering::Equal => return Some(middle),
+            // `arr[left] <= target`, so a larger element cannot be at `left` and `middle - 1` does not underflow
+            Ordering::Greater => right = middle - 1,
+        }
+    }
+
     None
 }
 
 #[cfg(test)]
 mod interpolation_search_tests {
-    use super::interpolation_search;
+    #[cfg(feature = "std")]
+    use super::interpolation_search_unchecked;
+    use super::{
+        interpolation_search, linear_interpolation_search, linear_interpolation_search_i64,
+    };
+    #[cfg(feature = "std")]
+    use crate::binary_search;
+    #[cfg(feature = "std")]
+    use crate::test_rng::Rng;
 
     #[test]
     #[should_panic(expected = "Interpolation search encountered an array that is note sorted")]
@@ -123,4 +227,125 @@ mod interpolation_search_tests {
         let found = interpolation_search(&target, &arr, |t, l, r| ((*t - *l) / (*r - *l)).into());
         assert_eq!(found, None);
     }
+
+    #[test]
+    fn interpolation_search_returns_some_index_if_target_at_either_end() {
+        let arr = [1u16, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+        assert_eq!(linear_interpolation_search(&1, &arr), Some(0));
+        assert_eq!(linear_interpolation_search(&10, &arr), Some(9));
+        assert_eq!(linear_interpolation_search(&7, &[7u16]), Some(0));
+    }
+
+    #[test]
+    fn linear_interpolation_search_i64_finds_negative_values() {
+        let arr = [-1000, -999, -500, -3, -2, -1, 0, 7, 12];
+
+        for (index, &element) in arr.iter().enumerate() {
+            assert_eq!(linear_interpolation_search_i64(element, &arr), Some(index));
+        }
+
+        assert_eq!(linear_interpolation_search_i64(-4, &arr), None);
+        assert_eq!(linear_interpolation_search_i64(-1001, &arr), None);
+        assert_eq!(linear_interpolation_search_i64(13, &arr), None);
+    }
+
+    #[test]
+    fn linear_interpolation_search_i64_handles_the_extremes_of_i64() {
+        let arr = [i64::MIN, i64::MIN + 1, -1, 0, i64::MAX - 1, i64::MAX];
+
+        for (index, &element) in arr.iter().enumerate() {
+            assert_eq!(linear_interpolation_search_i64(element, &arr), Some(index));
+        }
+
+        assert_eq!(linear_interpolation_search_i64(1, &arr), None);
+    }
+
+    #[test]
+    fn linear_interpolation_search_i64_handles_constant_arrays() {
+        let arr = [-5; 8];
+
+        assert_eq!(
+            linear_interpolation_search_i64(-5, &arr).map(|index| arr[index]),
+            Some(-5)
+        );
+        assert_eq!(linear_interpolation_search_i64(-4, &arr), None);
+        assert_eq!(linear_interpolation_search_i64(-6, &arr), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn linear_interpolation_search_i64_matches_binary_search_on_random_signed_arrays() {
+        let mut rng = Rng::new(0x51a7);
+
+        for _ in 0..300 {
+            let len = rng.below(80) as usize;
+            let mut arr: Vec<i64> = (0..len)
+                .map(|_| rng.below(2_000_000) as i64 - 1_000_000)
+                .collect();
+
+            arr.sort_unstable();
+            arr.dedup();
+
+            for _ in 0..50 {
+                let target = match rng.below(2) {
+                    0 if !arr.is_empty() => arr[rng.below(arr.len() as u64) as usize],
+                    _ => rng.below(2_000_002) as i64 - 1_000_001,
+                };
+
+                assert_eq!(
+                    linear_interpolation_search_i64(target, &arr),
+                    binary_search(&target, &arr)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn interpolation_search_does_not_divide_by_zero_on_all_equal_arrays() {
+        let arr = [5u16, 5, 5, 5];
+
+        assert_eq!(
+            linear_interpolation_search(&5, &arr).map(|index| arr[index]),
+            Some(5)
+        );
+        assert_eq!(linear_interpolation_search(&6, &arr), None);
+        assert_eq!(linear_interpolation_search(&4, &arr), None);
+    }
+
+    #[test]
+    fn interpolation_search_does_not_divide_by_zero_when_window_collapses_to_equal_ends() {
+        let arrs: [&[u16]; 4] = [
+            &[1, 5, 5, 5, 9],
+            &[5, 5, 5, 9],
+            &[1, 5, 5, 5],
+            &[1, 2, 5, 5, 5, 5, 8],
+        ];
+
+        for arr in arrs.iter() {
+            for target in 0..11 {
+                let found = linear_interpolation_search(&target, arr);
+
+                assert_eq!(found.is_some(), arr.contains(&target));
+                assert!(found.is_none_or(|index| arr[index] == target));
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn interpolation_search_unchecked_matches_interpolation_search_on_sorted_arrs() {
+        let interpolation_fn = |t: &u32, l: &u32, r: &u32| ((*t - *l) / (*r - *l)) as usize;
+
+        for len in 0..40 {
+            let arr: Vec<u32> = (0..len).map(|element| element * 2).collect();
+
+            for target in 0..=len * 2 {
+                assert_eq!(
+                    interpolation_search_unchecked(&target, &arr, interpolation_fn),
+                    interpolation_search(&target, &arr, interpolation_fn)
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,187 @@
+use ::core::cmp::min;
+
+use crate::utils;
+
+/// Performs [jump search](https://en.wikipedia.org/wiki/Jump_search) on `arr` in order to find the index of `target`.
+/// The array is traversed in blocks of `sqrt(arr.len())` elements, the block that may contain `target` then being
+/// scanned linearly.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let target = 5;
+/// let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9 ,10];
+/// let found = variations::jump_search(&target, &arr);
+///
+/// assert_eq!(found, Some(4));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn jump_search<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Jump search encountered an array that is note sorted");
+    }
+
+    jump_search_unchecked(target, arr)
+}
+
+/// Performs jump search on `arr` like [`jump_search`], without checking that `arr` is sorted.
+///
+/// This skips the `O(n)` scan of [`jump_search`]. Passing an unsorted array is not memory unsafety, it only gives a
+/// meaningless result. Every block ends at the end of the array at the latest and the scan of the last block starts
+/// after the previous one, so neither the jumps nor the slicing can panic.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+///
+/// assert_eq!(variations::jump_search_unchecked(&5, &arr), Some(4));
+/// ```
+pub fn jump_search_unchecked<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    if arr.is_empty() {
+        return None;
+    }
+
+    let arr_size = arr.len();
+    let block_size = block_size(arr_size);
+    let mut block_start = 0;
+    let mut block_end = min(block_size, arr_size);
+
+    while &arr[block_end - 1] < target {
+        block_start = block_end;
+
+        if block_start >= arr_size {
+            return None;
+        }
+
+        block_end = min(block_end + block_size, arr_size);
+    }
+
+    arr[block_start..block_end]
+        .iter()
+        .position(|element| element >= target)
+        .filter(|&offset| &arr[block_start + offset] == target)
+        .map(|offset| block_start + offset)
+}
+
+/// Calculates the size of the blocks to jump over, the integer square root of `len` but at least 1
+fn block_size(len: usize) -> usize {
+    len.isqrt().max(1)
+}
+
+#[cfg(test)]
+mod jump_search_tests {
+    #[cfg(feature = "std")]
+    use super::jump_search_unchecked;
+    use super::{block_size, jump_search};
+
+    #[test]
+    #[should_panic(expected = "Jump search encountered an array that is note sorted")]
+    fn jump_search_panics_when_arr_is_not_sorted() {
+        let target = 5;
+        let arr = [1, 3, 2, 5];
+
+        jump_search(&target, &arr);
+    }
+
+    #[test]
+    fn jump_search_returns_none_for_empty_arr() {
+        let target = 5;
+        let arr = [];
+        let found = jump_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn jump_search_returns_none_if_target_not_in_one_element_arr() {
+        let target = 5;
+        let arr = [4];
+        let found = jump_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn jump_search_returns_some_index_if_target_in_one_element_arr() {
+        let target = 5;
+        let arr = [5];
+        let found = jump_search(&target, &arr);
+
+        assert_eq!(found, Some(0));
+    }
+
+    #[test]
+    fn jump_search_returns_none_if_target_not_in_arr() {
+        let target = 12;
+        let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let found = jump_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn jump_search_returns_some_index_if_target_in_arr() {
+        let target = 5;
+        let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let found = jump_search(&target, &arr);
+
+        assert_eq!(found, Some(4));
+    }
+
+    #[test]
+    fn jump_search_returns_some_index_if_target_at_block_boundary() {
+        let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+        assert_eq!(block_size(arr.len()), 3);
+        assert_eq!(jump_search(&3, &arr), Some(2));
+        assert_eq!(jump_search(&4, &arr), Some(3));
+        assert_eq!(jump_search(&9, &arr), Some(8));
+        assert_eq!(jump_search(&10, &arr), Some(9));
+    }
+
+    #[test]
+    fn jump_search_returns_none_if_target_between_elements_at_block_boundary() {
+        let target = 7;
+        let arr = [2, 4, 6, 8, 10, 12, 14, 16, 18];
+        let found = jump_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn block_size_is_at_least_one() {
+        assert_eq!(block_size(0), 1);
+        assert_eq!(block_size(1), 1);
+        assert_eq!(block_size(16), 4);
+        assert_eq!(block_size(17), 4);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn jump_search_unchecked_matches_jump_search_on_sorted_arrs() {
+        for len in 0..40 {
+            let arr: Vec<u32> = (0..len).map(|element| element * 2).collect();
+
+            for target in 0..=len * 2 {
+                assert_eq!(
+                    jump_search_unchecked(&target, &arr),
+                    jump_search(&target, &arr)
+                );
+            }
+        }
+    }
+}
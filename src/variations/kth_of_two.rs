@@ -0,0 +1,138 @@
+use ::core::cmp::max;
+
+use crate::utils;
+
+/// Finds the `k`-th smallest element, counting from 0, of the sorted sequence obtained by merging the sorted arrays `a`
+/// and `b`, without merging them.
+///
+/// The search binary searches how many of the `k + 1` smallest elements come from the shorter array, so it makes
+/// O(log(min(m, n))) comparisons after checking that both arrays are sorted. Returns `None` if `k` is not smaller than
+/// the total number of elements.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let a = [1, 4, 9];
+/// let b = [2, 3, 10, 12];
+///
+/// assert_eq!(variations::kth_of_two(&a, &b, 0), Some(1));
+/// assert_eq!(variations::kth_of_two(&a, &b, 4), Some(9));
+/// assert_eq!(variations::kth_of_two(&a, &b, 7), None);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if either array is not sorted.
+pub fn kth_of_two<T>(a: &[T], b: &[T], k: usize) -> Option<T>
+where
+    T: Ord + Copy,
+{
+    if !utils::is_sorted(a) || !utils::is_sorted(b) {
+        panic!("Kth of two search encountered an array that is note sorted");
+    }
+
+    let (short, long) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+
+    if k >= short.len() + long.len() {
+        return None;
+    }
+
+    // The number of elements to take, from `short` and `long` together
+    let count = k + 1;
+    // Searches the number of elements taken from `short`, the first one for which its next element is not smaller
+    // than the last element taken from `long`
+    let mut left = count.saturating_sub(long.len());
+    let mut right = count.min(short.len());
+
+    while left < right {
+        let middle = utils::midpoint(left, right);
+
+        if short[middle] < long[count - middle - 1] {
+            left = middle + 1;
+        } else {
+            right = middle;
+        }
+    }
+
+    let taken_from_long = count - left;
+
+    match (left.checked_sub(1), taken_from_long.checked_sub(1)) {
+        (Some(i), Some(j)) => Some(max(short[i], long[j])),
+        (Some(i), None) => Some(short[i]),
+        (None, Some(j)) => Some(long[j]),
+        (None, None) => unreachable!("at least one element is taken"),
+    }
+}
+
+#[cfg(test)]
+mod kth_of_two_tests {
+    use super::kth_of_two;
+    #[cfg(feature = "std")]
+    use crate::test_rng::Rng;
+
+    #[test]
+    #[should_panic(expected = "Kth of two search encountered an array that is note sorted")]
+    fn kth_of_two_panics_when_an_arr_is_not_sorted() {
+        kth_of_two(&[1, 2, 3], &[4, 6, 5], 0);
+    }
+
+    #[test]
+    fn kth_of_two_returns_none_for_empty_arrs() {
+        let empty: [i32; 0] = [];
+
+        assert_eq!(kth_of_two(&empty, &empty, 0), None);
+    }
+
+    #[test]
+    fn kth_of_two_handles_empty_a() {
+        let b = [2, 4, 6];
+
+        assert_eq!(kth_of_two(&[], &b, 0), Some(2));
+        assert_eq!(kth_of_two(&[], &b, 2), Some(6));
+        assert_eq!(kth_of_two(&[], &b, 3), None);
+    }
+
+    #[test]
+    fn kth_of_two_handles_empty_b() {
+        let a = [2, 4, 6];
+
+        assert_eq!(kth_of_two(&a, &[], 0), Some(2));
+        assert_eq!(kth_of_two(&a, &[], 2), Some(6));
+        assert_eq!(kth_of_two(&a, &[], 3), None);
+    }
+
+    #[test]
+    fn kth_of_two_returns_the_ends_of_the_merged_sequence() {
+        let a = [3, 5, 7];
+        let b = [1, 2, 8, 9];
+
+        assert_eq!(kth_of_two(&a, &b, 0), Some(1));
+        assert_eq!(kth_of_two(&a, &b, 6), Some(9));
+        assert_eq!(kth_of_two(&a, &b, 7), None);
+        assert_eq!(kth_of_two(&a, &b, usize::MAX), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn kth_of_two_matches_merging_random_arrs() {
+        let mut rng = Rng::new(0x2a7f);
+
+        for _ in 0..300 {
+            let mut a: Vec<u64> = (0..rng.below(20)).map(|_| rng.below(30)).collect();
+            let mut b: Vec<u64> = (0..rng.below(20)).map(|_| rng.below(30)).collect();
+
+            a.sort_unstable();
+            b.sort_unstable();
+
+            let mut merged = [a.clone(), b.clone()].concat();
+
+            merged.sort_unstable();
+
+            for k in 0..=merged.len() {
+                assert_eq!(kth_of_two(&a, &b, k), merged.get(k).copied());
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::utils;
+use ::core::cmp::Ordering;
+
+/// Performs [staircase search](https://en.wikipedia.org/wiki/Young_tableau) on a matrix sorted ascending along both its
+/// rows and its columns, in order to find the `(row, column)` of `target`. Starting from the top-right corner, every
+/// comparison discards either a row or a column, so the search takes O(m + n) comparisons.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let matrix = vec![
+///     vec![1, 4, 7, 11],
+///     vec![2, 5, 8, 12],
+///     vec![3, 6, 9, 16],
+/// ];
+///
+/// assert_eq!(variations::matrix_search(&8, &matrix), Some((1, 2)));
+/// assert_eq!(variations::matrix_search(&10, &matrix), None);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the rows have different lengths or if the matrix is not sorted along its rows and columns.
+pub fn matrix_search<T>(target: &T, matrix: &[Vec<T>]) -> Option<(usize, usize)>
+where
+    T: Ord,
+{
+    let columns = matrix.first().map_or(0, Vec::len);
+
+    if matrix.iter().any(|row| row.len() != columns) {
+        panic!("Matrix search encountered rows of different lengths");
+    }
+
+    let rows_sorted = matrix.iter().all(|row| utils::is_sorted(row));
+    let columns_sorted = matrix.windows(2).all(|pair| {
+        pair[0]
+            .iter()
+            .zip(&pair[1])
+            .all(|(above, below)| above <= below)
+    });
+
+    if !rows_sorted || !columns_sorted {
+        panic!("Matrix search encountered a matrix that is note sorted");
+    }
+
+    let mut row = 0;
+    let mut column = columns;
+
+    while row < matrix.len() && column > 0 {
+        match matrix[row][column - 1].cmp(target) {
+            Ordering::Equal => return Some((row, column - 1)),
+            Ordering::Greater => column -= 1,
+            Ordering::Less => row += 1,
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod matrix_search_tests {
+    use super::matrix_search;
+
+    #[test]
+    #[should_panic(expected = "Matrix search encountered a matrix that is note sorted")]
+    fn matrix_search_panics_when_column_is_not_sorted() {
+        let target = 5;
+        let matrix = vec![vec![1, 4], vec![2, 3]];
+
+        matrix_search(&target, &matrix);
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix search encountered rows of different lengths")]
+    fn matrix_search_panics_for_ragged_rows() {
+        let target = 5;
+        let matrix = vec![vec![1, 4], vec![2]];
+
+        matrix_search(&target, &matrix);
+    }
+
+    #[test]
+    fn matrix_search_returns_none_for_empty_matrix_and_empty_rows() {
+        let target = 5;
+        let empty: Vec<Vec<i32>> = Vec::new();
+        let empty_rows: Vec<Vec<i32>> = vec![Vec::new(), Vec::new()];
+
+        assert_eq!(matrix_search(&target, &empty), None);
+        assert_eq!(matrix_search(&target, &empty_rows), None);
+    }
+
+    #[test]
+    fn matrix_search_finds_every_element_of_non_square_matrix() {
+        let matrix = vec![
+            vec![1, 3, 5, 7, 9],
+            vec![2, 4, 6, 8, 10],
+            vec![11, 12, 13, 14, 15],
+        ];
+
+        for (row, elements) in matrix.iter().enumerate() {
+            for (column, element) in elements.iter().enumerate() {
+                assert_eq!(matrix_search(element, &matrix), Some((row, column)));
+            }
+        }
+    }
+
+    #[test]
+    fn matrix_search_returns_none_if_target_not_in_matrix() {
+        let matrix = vec![vec![1, 4], vec![2, 5], vec![3, 8]];
+
+        assert_eq!(matrix_search(&0, &matrix), None);
+        assert_eq!(matrix_search(&6, &matrix), None);
+        assert_eq!(matrix_search(&9, &matrix), None);
+    }
+}
@@ -1,9 +1,73 @@
 //! Variations of binary search
+//!
+//! # Unchecked searches
+//!
+//! The `*_unchecked` searches skip the `O(n)` sortedness scan of their checked counterparts. They are safe functions
+//! on purpose rather than `unsafe fn`: every index they probe is bounded by the length of the array, never by the
+//! order of its elements, and none of them calls `get_unchecked` on an index derived from that order. An unsorted
+//! array therefore cannot make them read out of bounds.
 
+mod algorithm;
+mod auto;
+#[cfg(feature = "std")]
+mod blocked;
+mod branchless_search;
 mod exponential_search;
+#[cfg(feature = "std")]
+mod eytzinger;
+mod fibonacci_search;
+mod hybrid_search;
 mod interpolation_search;
+mod jump_search;
+mod kth_of_two;
+#[cfg(feature = "std")]
+mod matrix_search;
+mod parallel_search;
+mod peak_search;
+#[cfg(feature = "prefetch")]
+mod prefetch_search;
+mod rotated_search;
+mod searcher;
+mod ternary_search;
+mod timestamp_search;
+mod unbounded_search;
 mod uniform;
 
-pub use exponential_search::exponential_search;
-pub use interpolation_search::{interpolation_search, linear_interpolation_search};
+pub use algorithm::{search_numeric_with, search_with, Algorithm, NumericAlgorithm};
+pub use auto::{
+    choose_algorithm, search_auto, AUTO_EXPONENTIAL_DIVISOR, AUTO_MAX_DEVIATION_PERCENT,
+    AUTO_MAX_INTERPOLATION_PROBES, AUTO_MIN_LEN, AUTO_SAMPLES,
+};
+#[cfg(feature = "std")]
+pub use blocked::BlockedLayout;
+pub use branchless_search::{
+    branchless_leftmost_rank, branchless_search, branchless_search_unchecked,
+};
+pub use exponential_search::{
+    exponential_search, exponential_search_from, exponential_search_unchecked,
+};
+#[cfg(feature = "std")]
+pub use eytzinger::{eytzinger_search, to_eytzinger, EytzingerLayout};
+pub use fibonacci_search::{fibonacci_search, fibonacci_search_unchecked};
+pub use hybrid_search::{binary_search_hybrid, DEFAULT_HYBRID_THRESHOLD};
+pub use interpolation_search::{
+    interpolation_search, interpolation_search_unchecked, linear_interpolation_search,
+    linear_interpolation_search_i64,
+};
+pub use jump_search::{jump_search, jump_search_unchecked};
+pub use kth_of_two::kth_of_two;
+#[cfg(feature = "std")]
+pub use matrix_search::matrix_search;
+pub use parallel_search::parallel_search;
+pub use peak_search::find_peak;
+#[cfg(feature = "prefetch")]
+pub use prefetch_search::prefetch_search;
+pub use rotated_search::{rotated_search, rotation_point};
+pub use searcher::{
+    BinarySearch, ExponentialSearch, FibonacciSearch, InterpolationSearch, JumpSearch,
+    LinearInterpolationSearch, Searcher, TernarySearch,
+};
+pub use ternary_search::{ternary_search, ternary_search_unchecked};
+pub use timestamp_search::{timestamp_rank, timestamp_search};
+pub use unbounded_search::{unbounded_search, unbounded_search_u64};
 pub use uniform::UniformBinarySearch;
@@ -0,0 +1,125 @@
+use crate::core;
+#[cfg(not(feature = "rayon"))]
+use crate::utils;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Performs binary search on `arr` split into one chunk per thread, in order to find the index of the first occurrence
+/// of `target`.
+///
+/// With the `rayon` feature the sortedness check and the chunk searches run on the rayon thread pool. This does not
+/// improve on the O(log n) of a serial search, but on huge arrays it spreads the O(n) check and the cache misses of
+/// the probes over the memory bandwidth of several cores. Without the feature, the function runs the same search on
+/// the calling thread.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let arr: Vec<u32> = (0..100_000).map(|element| element / 4).collect();
+///
+/// assert_eq!(variations::parallel_search(&1000, &arr), Some(4000));
+/// assert_eq!(variations::parallel_search(&30_000, &arr), None);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn parallel_search<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord + Sync,
+{
+    #[cfg(feature = "rayon")]
+    {
+        if !arr.par_windows(2).all(|pair| pair[0] <= pair[1]) {
+            panic!("Parallel search encountered an array that is note sorted");
+        }
+
+        let chunk_len = (arr.len() / rayon::current_num_threads()).max(1);
+
+        arr.par_chunks(chunk_len)
+            .enumerate()
+            .find_map_first(|(chunk, elements)| {
+                first_in_chunk(target, elements).map(|offset| chunk * chunk_len + offset)
+            })
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    {
+        if !utils::is_sorted(arr) {
+            panic!("Parallel search encountered an array that is note sorted");
+        }
+
+        first_in_chunk(target, arr)
+    }
+}
+
+/// Returns the offset of the first occurrence of `target` in the sorted `chunk`
+fn first_in_chunk<T>(target: &T, chunk: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    let offset = core::lower_bound(target, chunk);
+
+    match chunk.get(offset) {
+        Some(element) if element == target => Some(offset),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod parallel_search_tests {
+    use super::parallel_search;
+    #[cfg(feature = "std")]
+    use crate::test_rng::Rng;
+    #[cfg(feature = "std")]
+    use crate::{binary_search, binary_search_first};
+
+    #[test]
+    #[should_panic(expected = "Parallel search encountered an array that is note sorted")]
+    fn parallel_search_panics_when_arr_is_not_sorted() {
+        let target = 5;
+        let arr = [1, 3, 2, 5];
+
+        parallel_search(&target, &arr);
+    }
+
+    #[test]
+    fn parallel_search_returns_none_for_empty_arr() {
+        let arr: [u32; 0] = [];
+
+        assert_eq!(parallel_search(&5, &arr), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn parallel_search_matches_binary_search_on_distinct_elements() {
+        let arr: Vec<u64> = (0..2000).map(|element| element * 3).collect();
+
+        for target in 0..6010 {
+            assert_eq!(parallel_search(&target, &arr), binary_search(&target, &arr));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn parallel_search_returns_first_occurrence_across_chunks() {
+        let mut rng = Rng::new(0x9a7a);
+
+        for _ in 0..100 {
+            let len = rng.below(3000) as usize;
+            let mut arr: Vec<u32> = (0..len).map(|_| rng.below(40) as u32).collect();
+
+            arr.sort_unstable();
+
+            for target in 0..41 {
+                assert_eq!(
+                    parallel_search(&target, &arr),
+                    binary_search_first(&target, &arr)
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::utils;
+
+/// Performs binary search on `arr`, a bitonic array that strictly increases and then strictly decreases, in order to
+/// find the index of its largest element.
+///
+/// Each step compares the middle element with its right neighbour: if the neighbour is larger the peak lies to the
+/// right, otherwise it lies at the middle or to its left. Unlike the other variations the array is not validated, as
+/// that would be the only O(n) part of the search. On an array that is not bitonic the result is the index of some
+/// element that is not smaller than its neighbours.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let arr = [1, 3, 8, 12, 9, 4, 2];
+///
+/// assert_eq!(variations::find_peak(&arr), Some(3));
+/// ```
+pub fn find_peak<T>(arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    if arr.is_empty() {
+        return None;
+    }
+
+    let mut left = 0;
+    let mut right = arr.len() - 1;
+
+    while left < right {
+        let middle = utils::midpoint(left, right);
+
+        if arr[middle] < arr[middle + 1] {
+            left = middle + 1;
+        } else {
+            right = middle;
+        }
+    }
+
+    Some(left)
+}
+
+#[cfg(test)]
+mod peak_search_tests {
+    use super::find_peak;
+
+    #[test]
+    fn find_peak_returns_none_for_empty_arr() {
+        let arr: [i32; 0] = [];
+
+        assert_eq!(find_peak(&arr), None);
+    }
+
+    #[test]
+    fn find_peak_returns_zero_for_single_element() {
+        assert_eq!(find_peak(&[7]), Some(0));
+    }
+
+    #[test]
+    fn find_peak_returns_peak_in_the_middle() {
+        let arr = [1, 3, 8, 12, 9, 4, 2];
+
+        assert_eq!(find_peak(&arr), Some(3));
+    }
+
+    #[test]
+    fn find_peak_returns_last_index_of_strictly_increasing_arr() {
+        let arr = [1, 2, 3, 5, 8, 13];
+
+        assert_eq!(find_peak(&arr), Some(5));
+    }
+
+    #[test]
+    fn find_peak_returns_first_index_of_strictly_decreasing_arr() {
+        let arr = [13, 8, 5, 3, 2, 1];
+
+        assert_eq!(find_peak(&arr), Some(0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn find_peak_finds_every_peak_position() {
+        for len in 1..40 {
+            for peak in 0..len {
+                let arr: Vec<i32> = (0..len as i32)
+                    .map(|index| -(index - peak as i32).abs())
+                    .collect();
+
+                assert_eq!(find_peak(&arr), Some(peak));
+            }
+        }
+    }
+}
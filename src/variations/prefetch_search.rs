@@ -0,0 +1,71 @@
+use crate::{core, utils};
+
+/// Performs [binary search](https://en.wikipedia.org/wiki/Binary_search_algorithm) on `arr` in order to find the index
+/// of `target`, prefetching both possible next probes before every comparison.
+///
+/// Once the array no longer fits in the cache, every probe of a binary search waits on memory. Prefetching the
+/// midpoints of both halves lets the next probe load while the current one is compared, at the cost of fetching one
+/// line that is not used. On architectures without a prefetch instruction exposed to stable Rust, i.e. other than x86
+/// and x86_64, this is a plain binary search. Either way it probes the same elements and returns the same index as
+/// [`binary_search`](crate::binary_search).
+///
+/// The O(n) sortedness check runs on every call; use [`SortedSlice::prefetch_search`](crate::SortedSlice::prefetch_search)
+/// to check once.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let target = 5;
+/// let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+/// let found = variations::prefetch_search(&target, &arr);
+///
+/// assert_eq!(found, Some(4));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn prefetch_search<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Binary search encountered an array that is note sorted");
+    }
+
+    core::binary_search_prefetch(target, arr)
+}
+
+#[cfg(test)]
+mod prefetch_search_tests {
+    use super::prefetch_search;
+    use crate::binary_search;
+    use crate::test_rng::Rng;
+
+    #[test]
+    #[should_panic(expected = "Binary search encountered an array that is note sorted")]
+    fn prefetch_search_panics_when_arr_is_not_sorted() {
+        let target = 5;
+        let arr = [1, 3, 2, 5];
+
+        prefetch_search(&target, &arr);
+    }
+
+    #[test]
+    fn prefetch_search_returns_the_same_index_as_binary_search() {
+        let mut rng = Rng::new(0x9ef7);
+
+        for _ in 0..300 {
+            let len = rng.below(300) as usize;
+            let mut arr: Vec<u32> = (0..len).map(|_| rng.below(100) as u32).collect();
+
+            arr.sort_unstable();
+
+            for target in 0..102 {
+                assert_eq!(prefetch_search(&target, &arr), binary_search(&target, &arr));
+            }
+        }
+    }
+}
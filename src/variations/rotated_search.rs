@@ -0,0 +1,209 @@
+use ::core::cmp::Ordering;
+
+use crate::{core, utils};
+
+/// Performs binary search on `arr`, a sorted array rotated by some offset (e.g. a sorted ring buffer), in order to
+/// find the index of `target`.
+///
+/// The pivot is the start of the run holding the smallest elements. Once it is known, `target` is compared against
+/// the last element to pick the run that may hold it, and that run alone is searched in O(log n). As with the other
+/// variations the array is validated first, which is O(n); the validation pass also locates the pivot.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let target = 2;
+/// let arr = [4, 5, 6, 1, 2, 3];
+/// let found = variations::rotated_search(&target, &arr);
+///
+/// assert_eq!(found, Some(4));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not a rotation of a sorted array.
+pub fn rotated_search<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    let pivot = match utils::rotation_offset(arr) {
+        Some(pivot) => pivot,
+        None => panic!("Rotated search encountered an array that is not a rotated sorted array"),
+    };
+
+    let (front, back) = arr.split_at(pivot);
+
+    match back.last() {
+        Some(last) if target <= last => {
+            core::binary_search_by(back, |element| element.cmp(target)).map(|index| pivot + index)
+        }
+        _ => core::binary_search_by(front, |element| element.cmp(target)),
+    }
+}
+
+/// Performs binary search on `arr`, a sorted array rotated by some offset, in order to find that offset, i.e. the
+/// index of its smallest element. An array that is not rotated, empty or not, returns 0.
+///
+/// Each step compares the middle element with the last element of the window: a larger middle element means the
+/// offset lies to its right, a smaller one that it lies at the middle or to its left. This takes O(log n) comparisons
+/// when the elements are distinct. Elements equal to the last one give no direction, so the window only shrinks by
+/// one, and arrays with many duplicates can take up to O(n). Like [`find_peak`](super::find_peak), the array is not
+/// validated, that being the only O(n) part; [`rotated_search`] validates it and finds the offset in the same pass.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// assert_eq!(variations::rotation_point(&[4, 5, 6, 1, 2, 3]), 3);
+/// assert_eq!(variations::rotation_point(&[1, 2, 3]), 0);
+/// ```
+pub fn rotation_point<T>(arr: &[T]) -> usize
+where
+    T: Ord,
+{
+    if arr.is_empty() {
+        return 0;
+    }
+
+    let mut left = 0;
+    let mut right = arr.len() - 1;
+
+    while left < right {
+        let middle = utils::midpoint(left, right);
+
+        match arr[middle].cmp(&arr[right]) {
+            Ordering::Greater => left = middle + 1,
+            Ordering::Less => right = middle,
+            // The last element can only be dropped if it does not start the smallest run
+            Ordering::Equal if arr[right - 1] > arr[right] => return right,
+            Ordering::Equal => right -= 1,
+        }
+    }
+
+    left
+}
+
+#[cfg(test)]
+mod rotated_search_tests {
+    use super::{rotated_search, rotation_point};
+    #[cfg(feature = "std")]
+    use crate::test_rng::Rng;
+    #[cfg(feature = "std")]
+    use crate::utils;
+
+    #[test]
+    #[should_panic(
+        expected = "Rotated search encountered an array that is not a rotated sorted array"
+    )]
+    fn rotated_search_panics_when_arr_is_not_rotated_sorted() {
+        let target = 5;
+        let arr = [3, 1, 4, 2];
+
+        rotated_search(&target, &arr);
+    }
+
+    #[test]
+    fn rotated_search_returns_none_for_empty_arr() {
+        let target = 5;
+        let arr = [];
+        let found = rotated_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn rotated_search_returns_some_index_if_arr_not_rotated() {
+        let target = 5;
+        let arr = [1, 2, 3, 4, 5, 6];
+        let found = rotated_search(&target, &arr);
+
+        assert_eq!(found, Some(4));
+    }
+
+    #[test]
+    fn rotated_search_returns_none_if_target_not_in_arr() {
+        let target = 7;
+        let arr = [4, 5, 6, 8, 1, 2, 3];
+        let found = rotated_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn rotated_search_finds_every_element_at_every_rotation() {
+        let sorted = [1, 3, 5, 7, 9, 11, 13];
+
+        for offset in 0..sorted.len() {
+            let mut arr = sorted;
+
+            arr.rotate_left(offset);
+
+            for (index, element) in arr.iter().enumerate() {
+                assert_eq!(rotated_search(element, &arr), Some(index));
+                assert_eq!(rotated_search(&(element + 1), &arr), None);
+            }
+
+            assert_eq!(rotated_search(&0, &arr), None);
+        }
+    }
+
+    #[test]
+    fn rotated_search_handles_duplicates_around_the_pivot() {
+        let target = 2;
+        let arr = [2, 3, 1, 2];
+        let found = rotated_search(&target, &arr);
+
+        assert_eq!(found.map(|index| arr[index]), Some(2));
+    }
+
+    #[test]
+    fn rotation_point_returns_zero_for_empty_and_single_element_arrs() {
+        let empty: [i32; 0] = [];
+
+        assert_eq!(rotation_point(&empty), 0);
+        assert_eq!(rotation_point(&[7]), 0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn rotation_point_returns_every_offset_of_distinct_elements() {
+        let sorted: Vec<i32> = (0..13).collect();
+
+        for offset in 0..sorted.len() {
+            let mut arr = sorted.clone();
+
+            arr.rotate_right(offset);
+
+            assert_eq!(rotation_point(&arr), offset);
+        }
+    }
+
+    #[test]
+    fn rotation_point_returns_zero_for_all_equal_elements() {
+        assert_eq!(rotation_point(&[4, 4, 4, 4, 4]), 0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn rotation_point_matches_scan_with_duplicates() {
+        let mut rng = Rng::new(0x707a7e);
+
+        for _ in 0..500 {
+            let len = rng.below(20) as usize + 1;
+            let mut arr: Vec<u64> = (0..len).map(|_| rng.below(4)).collect();
+
+            arr.sort_unstable();
+            arr.rotate_left(rng.below(len as u64) as usize);
+
+            let point = rotation_point(&arr);
+            let mut rotated = arr.clone();
+
+            rotated.rotate_left(point);
+
+            assert!(utils::is_sorted(&rotated), "{:?} at {}", arr, point);
+        }
+    }
+}
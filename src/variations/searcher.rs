@@ -0,0 +1,213 @@
+use super::{
+    exponential_search, fibonacci_search, interpolation_search, jump_search,
+    linear_interpolation_search, ternary_search, UniformBinarySearch,
+};
+use core::ops::{Div, Sub};
+
+/// A search strategy, so that code can be written generically over the algorithm
+///
+/// The element type is a parameter of the trait rather than of `search`, so that strategies needing more than `Ord`,
+/// such as interpolation search, can implement it for the types they support.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations::{
+///     BinarySearch, ExponentialSearch, Searcher, UniformBinarySearch,
+/// };
+///
+/// fn search_all<S: Searcher<i32>>(searcher: &mut S, targets: &[i32], arr: &[i32]) -> Vec<Option<usize>> {
+///     targets.iter().map(|target| searcher.search(target, arr)).collect()
+/// }
+///
+/// let targets = [3, 4, 9];
+/// let arr = [1, 3, 5, 7, 9];
+///
+/// assert_eq!(search_all(&mut BinarySearch, &targets, &arr), vec![Some(1), None, Some(4)]);
+/// assert_eq!(search_all(&mut ExponentialSearch, &targets, &arr), vec![Some(1), None, Some(4)]);
+/// assert_eq!(search_all(&mut UniformBinarySearch::new(), &targets, &arr), vec![Some(1), None, Some(4)]);
+/// ```
+pub trait Searcher<T> {
+    /// Searches `arr` for the index of `target`.
+    ///
+    /// # Panics
+    ///
+    /// Implementations panic if the array is not sorted.
+    fn search(&mut self, target: &T, arr: &[T]) -> Option<usize>;
+}
+
+/// The standard binary search, see [`binary_search`](crate::binary_search)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BinarySearch;
+
+impl<T> Searcher<T> for BinarySearch
+where
+    T: Ord,
+{
+    fn search(&mut self, target: &T, arr: &[T]) -> Option<usize> {
+        crate::binary_search(target, arr)
+    }
+}
+
+/// Exponential search, see [`exponential_search`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ExponentialSearch;
+
+impl<T> Searcher<T> for ExponentialSearch
+where
+    T: Ord,
+{
+    fn search(&mut self, target: &T, arr: &[T]) -> Option<usize> {
+        exponential_search(target, arr)
+    }
+}
+
+/// Fibonacci search, see [`fibonacci_search`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FibonacciSearch;
+
+impl<T> Searcher<T> for FibonacciSearch
+where
+    T: Ord,
+{
+    fn search(&mut self, target: &T, arr: &[T]) -> Option<usize> {
+        fibonacci_search(target, arr)
+    }
+}
+
+/// Jump search, see [`jump_search`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct JumpSearch;
+
+impl<T> Searcher<T> for JumpSearch
+where
+    T: Ord,
+{
+    fn search(&mut self, target: &T, arr: &[T]) -> Option<usize> {
+        jump_search(target, arr)
+    }
+}
+
+/// Ternary search, see [`ternary_search`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TernarySearch;
+
+impl<T> Searcher<T> for TernarySearch
+where
+    T: Ord,
+{
+    fn search(&mut self, target: &T, arr: &[T]) -> Option<usize> {
+        ternary_search(target, arr)
+    }
+}
+
+/// Interpolation search with linear interpolation, see [`linear_interpolation_search`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LinearInterpolationSearch;
+
+impl<T> Searcher<T> for LinearInterpolationSearch
+where
+    T: Ord + Sub + Copy,
+    <T as Sub>::Output: Div,
+    <<T as Sub>::Output as Div>::Output: Into<usize>,
+{
+    fn search(&mut self, target: &T, arr: &[T]) -> Option<usize> {
+        linear_interpolation_search(target, arr)
+    }
+}
+
+/// Interpolation search with a custom interpolation function, see [`interpolation_search`]
+#[derive(Debug, Clone, Copy)]
+pub struct InterpolationSearch<InterpolationFn> {
+    interpolation_fn: InterpolationFn,
+}
+
+impl<InterpolationFn> InterpolationSearch<InterpolationFn> {
+    /// Returns a new `InterpolationSearch` using `interpolation_fn` to calculate the midpoint
+    pub fn new(interpolation_fn: InterpolationFn) -> Self {
+        Self { interpolation_fn }
+    }
+}
+
+impl<T, InterpolationFn> Searcher<T> for InterpolationSearch<InterpolationFn>
+where
+    InterpolationFn: Fn(&T, &T, &T) -> usize,
+    T: Ord,
+{
+    fn search(&mut self, target: &T, arr: &[T]) -> Option<usize> {
+        interpolation_search(target, arr, &self.interpolation_fn)
+    }
+}
+
+impl<T> Searcher<T> for UniformBinarySearch
+where
+    T: Ord,
+{
+    fn search(&mut self, target: &T, arr: &[T]) -> Option<usize> {
+        UniformBinarySearch::search(self, target, arr)
+    }
+}
+
+#[cfg(test)]
+mod searcher_tests {
+    #[cfg(feature = "std")]
+    use super::{
+        BinarySearch, ExponentialSearch, FibonacciSearch, InterpolationSearch, JumpSearch,
+        LinearInterpolationSearch, Searcher, TernarySearch,
+    };
+    #[cfg(feature = "std")]
+    use crate::variations::UniformBinarySearch;
+
+    #[cfg(feature = "std")]
+    fn search_all<S>(searcher: &mut S, arr: &[u16]) -> Vec<Option<usize>>
+    where
+        S: Searcher<u16>,
+    {
+        (0..=arr[arr.len() - 1] + 1)
+            .map(|target| searcher.search(&target, arr))
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn searchers_return_identical_results() {
+        let arr = [1, 3, 4, 6, 7, 8, 12, 13, 15, 20, 21, 22, 30];
+        let expected = search_all(&mut BinarySearch, &arr);
+
+        assert_eq!(search_all(&mut ExponentialSearch, &arr), expected);
+        assert_eq!(search_all(&mut FibonacciSearch, &arr), expected);
+        assert_eq!(search_all(&mut JumpSearch, &arr), expected);
+        assert_eq!(search_all(&mut TernarySearch, &arr), expected);
+        assert_eq!(search_all(&mut LinearInterpolationSearch, &arr), expected);
+        assert_eq!(
+            search_all(
+                &mut InterpolationSearch::new(|t: &u16, l: &u16, r: &u16| {
+                    ((*t - *l) / (*r - *l)).into()
+                }),
+                &arr
+            ),
+            expected
+        );
+        assert_eq!(search_all(&mut UniformBinarySearch::new(), &arr), expected);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn searchers_return_none_for_empty_arr() {
+        let target = 1u16;
+        let arr: [u16; 0] = [];
+        let mut searchers: Vec<Box<dyn Searcher<u16>>> = vec![
+            Box::new(BinarySearch),
+            Box::new(ExponentialSearch),
+            Box::new(FibonacciSearch),
+            Box::new(JumpSearch),
+            Box::new(TernarySearch),
+            Box::new(LinearInterpolationSearch),
+            Box::new(UniformBinarySearch::new()),
+        ];
+
+        for searcher in searchers.iter_mut() {
+            assert_eq!(searcher.search(&target, &arr), None);
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::utils;
+
+/// Performs [ternary search](https://en.wikipedia.org/wiki/Ternary_search) on `arr` in order to find the index of `target`.
+/// The remaining range is split into thirds on each iteration.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let target = 5;
+/// let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9 ,10];
+/// let found = variations::ternary_search(&target, &arr);
+///
+/// assert_eq!(found, Some(4));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn ternary_search<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    if !utils::is_sorted(arr) {
+        panic!("Ternary search encountered an array that is note sorted");
+    }
+
+    ternary_search_unchecked(target, arr)
+}
+
+/// Performs ternary search on `arr` like [`ternary_search`], without checking that `arr` is sorted.
+///
+/// This skips the `O(n)` scan of [`ternary_search`]. Passing an unsorted array is not memory unsafety, it only gives a
+/// meaningless result. Both probes stay inside a window that shrinks on every step, so the search never panics.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+///
+/// assert_eq!(variations::ternary_search_unchecked(&5, &arr), Some(4));
+/// ```
+pub fn ternary_search_unchecked<T>(target: &T, arr: &[T]) -> Option<usize>
+where
+    T: Ord,
+{
+    let mut left = 0;
+    let mut right = arr.len();
+
+    while left < right {
+        let third = (right - left) / 3;
+        let first_middle = left + third;
+        let second_middle = right - 1 - third;
+
+        if &arr[first_middle] == target {
+            return Some(first_middle);
+        }
+
+        if &arr[second_middle] == target {
+            return Some(second_middle);
+        }
+
+        if target < &arr[first_middle] {
+            right = first_middle;
+        } else if target > &arr[second_middle] {
+            left = second_middle + 1;
+        } else {
+            left = first_middle + 1;
+            right = second_middle;
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod ternary_search_tests {
+    use super::ternary_search;
+    #[cfg(feature = "std")]
+    use super::ternary_search_unchecked;
+
+    #[test]
+    #[should_panic(expected = "Ternary search encountered an array that is note sorted")]
+    fn ternary_search_panics_when_arr_is_not_sorted() {
+        let target = 5;
+        let arr = [1, 3, 2, 5];
+
+        ternary_search(&target, &arr);
+    }
+
+    #[test]
+    fn ternary_search_returns_none_for_empty_arr() {
+        let target = 5;
+        let arr = [];
+        let found = ternary_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn ternary_search_returns_none_if_target_not_in_one_element_arr() {
+        let target = 5;
+        let arr = [4];
+        let found = ternary_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn ternary_search_returns_some_index_if_target_in_one_element_arr() {
+        let target = 5;
+        let arr = [5];
+        let found = ternary_search(&target, &arr);
+
+        assert_eq!(found, Some(0));
+    }
+
+    #[test]
+    fn ternary_search_returns_none_if_target_not_in_arr() {
+        let target = 12;
+        let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let found = ternary_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn ternary_search_returns_none_if_target_below_minimum() {
+        let target = 0;
+        let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let found = ternary_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn ternary_search_returns_none_if_target_above_maximum() {
+        let target = 11;
+        let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let found = ternary_search(&target, &arr);
+
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn ternary_search_returns_some_index_if_target_in_arr() {
+        let target = 5;
+        let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let found = ternary_search(&target, &arr);
+
+        assert_eq!(found, Some(4));
+    }
+
+    #[test]
+    fn ternary_search_finds_every_element() {
+        let arr = [1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21];
+
+        for (index, element) in arr.iter().enumerate() {
+            assert_eq!(ternary_search(element, &arr), Some(index));
+            assert_eq!(ternary_search(&(element + 1), &arr), None);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn ternary_search_unchecked_matches_ternary_search_on_sorted_arrs() {
+        for len in 0..40 {
+            let arr: Vec<u32> = (0..len).map(|element| element * 2).collect();
+
+            for target in 0..=len * 2 {
+                assert_eq!(
+                    ternary_search_unchecked(&target, &arr),
+                    ternary_search(&target, &arr)
+                );
+            }
+        }
+    }
+}
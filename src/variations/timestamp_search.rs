@@ -0,0 +1,289 @@
+use crate::utils;
+
+/// Performs a search tuned for monotone timestamps on `arr` in order to find the index of `target`.
+///
+/// Timestamps tend to be near-uniform locally with large global gaps (e.g. quiet nights), and recent data is
+/// queried most. The search therefore first gallops backwards from the end of the array to bracket `target`, then
+/// interpolates within the bracket using its local density, bisecting instead whenever an interpolation step shrinks
+/// the bracket by less than half.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let arr = [1_000, 1_010, 1_020, 1_030, 90_000, 90_010, 90_020];
+/// let found = variations::timestamp_search(90_010, &arr);
+///
+/// assert_eq!(found, Some(5));
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn timestamp_search(target: i64, arr: &[i64]) -> Option<usize> {
+    let rank = timestamp_rank(target, arr);
+
+    if rank < arr.len() && arr[rank] == target {
+        Some(rank)
+    } else {
+        None
+    }
+}
+
+/// Calculates the leftmost rank of `target` in `arr` using the same strategy as [`timestamp_search`].
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// let arr = [1_000, 1_010, 1_020, 1_030, 90_000, 90_010, 90_020];
+/// let rank = variations::timestamp_rank(50_000, &arr);
+///
+/// assert_eq!(rank, 4);
+/// ```
+///
+/// # Panics
+///
+/// The function panics if the array is not sorted.
+pub fn timestamp_rank(target: i64, arr: &[i64]) -> usize {
+    if !utils::is_sorted(arr) {
+        panic!("Timestamp search encountered an array that is note sorted");
+    }
+
+    lower_bound(target, arr, &mut 0)
+}
+
+/// Calculates the leftmost rank of `target`, counting the number of elements probed in `probes`
+fn lower_bound(target: i64, arr: &[i64], probes: &mut usize) -> usize {
+    let arr_size = arr.len();
+
+    if arr_size == 0 {
+        return 0;
+    }
+
+    *probes += 1;
+
+    if arr[arr_size - 1] < target {
+        return arr_size;
+    }
+
+    // Gallop backwards from the end, knowing that arr[arr_size - bound] >= target
+    let mut bound = 1;
+
+    while bound < arr_size {
+        *probes += 1;
+
+        if arr[arr_size - 1 - bound] < target {
+            break;
+        }
+
+        bound *= 2;
+    }
+
+    // The rank is in left..=right, and arr[right] >= target
+    let mut left = arr_size.saturating_sub(bound);
+    let mut right = arr_size - 1 - bound / 2;
+    let mut bisect = false;
+
+    while left < right {
+        let size = right - left;
+        let middle = if bisect {
+            left + size / 2
+        } else {
+            *probes += 1;
+
+            if arr[left] >= target {
+                return left;
+            }
+
+            interpolate(target, arr, left, right)
+        };
+
+        *probes += 1;
+
+        if arr[middle] < target {
+            left = middle + 1;
+        } else {
+            right = middle;
+        }
+
+        bisect = !bisect && (right - left) * 2 > size;
+    }
+
+    left
+}
+
+/// Estimates the position of `target` in `left..right` from the values at both ends, knowing that
+/// `arr[left] < target <= arr[right]`
+fn interpolate(target: i64, arr: &[i64], left: usize, right: usize) -> usize {
+    let low = i128::from(arr[left]);
+    let high = i128::from(arr[right]);
+    let offset = (i128::from(target) - low) * (right - left) as i128 / (high - low);
+
+    (left + offset as usize).min(right - 1)
+}
+
+#[cfg(test)]
+mod timestamp_search_tests {
+    #[cfg(feature = "std")]
+    use super::lower_bound;
+    use super::{timestamp_rank, timestamp_search};
+    use crate::core;
+    #[cfg(feature = "std")]
+    use crate::utils;
+
+    /// Builds ten days of events every 30 seconds during the day, with nothing at night
+    #[cfg(feature = "std")]
+    fn day_night_timestamps() -> Vec<i64> {
+        (0..10)
+            .flat_map(|day| (0..960).map(move |event| day * 86_400 + 28_800 + event * 30))
+            .collect()
+    }
+
+    /// Counts the probes of a plain galloping search from the end followed by bisection
+    #[cfg(feature = "std")]
+    fn galloping_probes(target: i64, arr: &[i64]) -> usize {
+        let mut probes = 1;
+        let mut bound = 1;
+
+        while bound < arr.len() {
+            probes += 1;
+
+            if arr[arr.len() - 1 - bound] < target {
+                break;
+            }
+
+            bound *= 2;
+        }
+
+        let mut left = arr.len().saturating_sub(bound);
+        let mut right = arr.len() - 1 - bound / 2;
+
+        while left < right {
+            let middle = utils::midpoint(left, right);
+
+            probes += 1;
+
+            if arr[middle] < target {
+                left = middle + 1;
+            } else {
+                right = middle;
+            }
+        }
+
+        probes
+    }
+
+    /// Counts the probes of a plain interpolation search over the whole array
+    #[cfg(feature = "std")]
+    fn interpolation_probes(target: i64, arr: &[i64]) -> usize {
+        let mut probes = 0;
+        let mut left = 0;
+        let mut right = arr.len() - 1;
+
+        while left < right {
+            probes += 1;
+
+            if arr[left] >= target {
+                break;
+            }
+
+            let middle = super::interpolate(target, arr, left, right);
+
+            probes += 1;
+
+            if arr[middle] < target {
+                left = middle + 1;
+            } else {
+                right = middle;
+            }
+        }
+
+        probes
+    }
+
+    #[test]
+    #[should_panic(expected = "Timestamp search encountered an array that is note sorted")]
+    fn timestamp_search_panics_when_arr_is_not_sorted() {
+        let arr = [1, 3, 2, 5];
+
+        timestamp_search(5, &arr);
+    }
+
+    #[test]
+    fn timestamp_search_returns_none_for_empty_arr() {
+        let arr = [];
+        let found = timestamp_search(5, &arr);
+
+        assert_eq!(found, None);
+        assert_eq!(timestamp_rank(5, &arr), 0);
+    }
+
+    #[test]
+    fn timestamp_search_handles_one_element_arr() {
+        let arr = [5];
+
+        assert_eq!(timestamp_search(5, &arr), Some(0));
+        assert_eq!(timestamp_search(4, &arr), None);
+        assert_eq!(timestamp_search(6, &arr), None);
+    }
+
+    #[test]
+    fn timestamp_search_returns_some_index_if_target_in_arr() {
+        let arr = [-50, -10, 0, 7, 12, 1_000_000];
+
+        assert_eq!(timestamp_search(7, &arr), Some(3));
+        assert_eq!(timestamp_search(-50, &arr), Some(0));
+        assert_eq!(timestamp_search(1_000_000, &arr), Some(5));
+        assert_eq!(timestamp_search(8, &arr), None);
+    }
+
+    #[test]
+    fn timestamp_rank_matches_leftmost_rank_with_duplicates() {
+        let arr = [i64::MIN, 1, 1, 1, 5, 5, 9, 9, i64::MAX];
+
+        for target in [i64::MIN, 0, 1, 2, 5, 8, 9, 10, i64::MAX] {
+            assert_eq!(
+                timestamp_rank(target, &arr),
+                core::lower_bound(&target, &arr)
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn timestamp_search_matches_binary_search_on_day_night_data() {
+        let arr = day_night_timestamps();
+
+        for target in (0..10 * 86_400).step_by(997) {
+            assert_eq!(
+                timestamp_rank(target, &arr),
+                core::lower_bound(&target, &arr)
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn timestamp_search_probes_less_than_pure_strategies_on_recent_data() {
+        let arr = day_night_timestamps();
+        let targets: Vec<i64> = arr[arr.len() - 3_000..]
+            .iter()
+            .step_by(7)
+            .copied()
+            .collect();
+        let mut probes = 0;
+
+        for &target in &targets {
+            lower_bound(target, &arr, &mut probes);
+        }
+
+        let galloping: usize = targets.iter().map(|&t| galloping_probes(t, &arr)).sum();
+        let interpolation: usize = targets.iter().map(|&t| interpolation_probes(t, &arr)).sum();
+
+        assert!(probes < galloping, "{} >= {}", probes, galloping);
+        assert!(probes < interpolation, "{} >= {}", probes, interpolation);
+    }
+}
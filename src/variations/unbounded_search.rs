@@ -0,0 +1,239 @@
+use crate::utils;
+use ::core::cmp::Ordering;
+
+/// Performs [exponential search](https://en.wikipedia.org/wiki/Exponential_search) on a sorted sequence of unknown
+/// length, in order to find the index of `target`.
+///
+/// `get` returns the element at an index, or `None` past the end of the sequence. The probe index doubles until it
+/// reaches an element not smaller than `target` or the end of the sequence, then the bracketed interval is binary
+/// searched, so `get` is called O(log i) times for a target at index `i`. The index `usize::MAX` itself is never
+/// probed. The sequence is not checked to be sorted.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// // The squares, as an endless sequence
+/// let found = variations::unbounded_search(&144, |index| Some(index * index));
+///
+/// assert_eq!(found, Some(12));
+/// assert_eq!(variations::unbounded_search(&145, |index| Some(index * index)), None);
+/// ```
+pub fn unbounded_search<T, F>(target: &T, mut get: F) -> Option<usize>
+where
+    T: Ord,
+    F: FnMut(usize) -> Option<T>,
+{
+    let mut left = 0;
+    let mut bound = 1;
+
+    let mut right = loop {
+        match get(bound) {
+            Some(ref element) if element < target => {
+                left = bound + 1;
+
+                match bound.checked_mul(2) {
+                    Some(next) => bound = next,
+                    None => break usize::MAX,
+                }
+            }
+            Some(_) => break bound + 1,
+            None => break bound,
+        }
+    };
+
+    while left < right {
+        let middle = utils::midpoint(left, right);
+
+        match get(middle).map(|element| element.cmp(target)) {
+            Some(Ordering::Equal) => return Some(middle),
+            Some(Ordering::Less) => left = middle + 1,
+            Some(Ordering::Greater) | None => right = middle,
+        }
+    }
+
+    None
+}
+
+/// Performs [exponential search](https://en.wikipedia.org/wiki/Exponential_search) on a sorted sequence of unknown
+/// length indexed by `u64`, in order to find the index of `target`.
+///
+/// This is [`unbounded_search`] for sources whose indices don't fit in a `usize` on every platform, such as generators
+/// of sorted IDs. `get` returns the element at an index, or `None` past the end of the sequence. The index `u64::MAX`
+/// itself is never probed.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::variations;
+///
+/// // Sorted IDs, with nothing past the millionth one
+/// let ids = |index: u64| if index < 1_000_000 { Some(index * 7 + 3) } else { None };
+///
+/// assert_eq!(variations::unbounded_search_u64(&703, ids), Some(100));
+/// assert_eq!(variations::unbounded_search_u64(&704, ids), None);
+/// ```
+pub fn unbounded_search_u64<T, F>(target: &T, mut get: F) -> Option<u64>
+where
+    T: Ord,
+    F: FnMut(u64) -> Option<T>,
+{
+    let mut left = 0;
+    let mut bound = 1;
+
+    let mut right = loop {
+        match get(bound) {
+            Some(ref element) if element < target => {
+                left = bound + 1;
+
+                match bound.checked_mul(2) {
+                    Some(next) => bound = next,
+                    None => break u64::MAX,
+                }
+            }
+            Some(_) => break bound + 1,
+            None => break bound,
+        }
+    };
+
+    while left < right {
+        let middle = left + (right - left) / 2;
+
+        match get(middle).map(|element| element.cmp(target)) {
+            Some(Ordering::Equal) => return Some(middle),
+            Some(Ordering::Less) => left = middle + 1,
+            Some(Ordering::Greater) | None => right = middle,
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod unbounded_search_tests {
+    use super::{unbounded_search, unbounded_search_u64};
+    #[cfg(feature = "std")]
+    use crate::binary_search;
+    use core::cell::Cell;
+
+    /// Backs `unbounded_search` with a slice, as a sequence whose length the search does not know
+    fn getter(arr: &[i32]) -> impl Fn(usize) -> Option<i32> + '_ {
+        move |index| arr.get(index).copied()
+    }
+
+    #[test]
+    fn unbounded_search_returns_none_for_empty_sequence() {
+        let arr: [i32; 0] = [];
+
+        assert_eq!(unbounded_search(&5, getter(&arr)), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn unbounded_search_matches_binary_search_on_slices() {
+        for len in 0..70 {
+            let arr: Vec<i32> = (0..len).map(|element| element * 3).collect();
+
+            for target in -2..len * 3 + 2 {
+                assert_eq!(
+                    unbounded_search(&target, getter(&arr)),
+                    binary_search(&target, &arr),
+                    "searching {} in {:?}",
+                    target,
+                    arr
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn unbounded_search_probes_logarithmically_in_the_target_index() {
+        let calls = Cell::new(0);
+        let found = unbounded_search(&1000, |index| {
+            calls.set(calls.get() + 1);
+            Some(index)
+        });
+
+        assert_eq!(found, Some(1000));
+        assert!(calls.get() <= 22, "{} calls", calls.get());
+    }
+
+    #[test]
+    fn unbounded_search_does_not_overflow_the_probe_index() {
+        let found = unbounded_search(&usize::MAX, Some);
+
+        assert_eq!(found, None);
+        assert_eq!(
+            unbounded_search(&(usize::MAX - 1), Some),
+            Some(usize::MAX - 1)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn unbounded_search_u64_returns_none_if_first_probe_is_none() {
+        let mut probes = Vec::new();
+        let found = unbounded_search_u64(&5, |index| {
+            probes.push(index);
+            None::<u64>
+        });
+
+        assert_eq!(found, None);
+        assert!(probes.len() <= 2, "{:?}", probes);
+    }
+
+    #[test]
+    fn unbounded_search_u64_returns_target_at_index_0() {
+        let arr = [5, 8, 13];
+
+        assert_eq!(
+            unbounded_search_u64(&5, |index| arr.get(index as usize).copied()),
+            Some(0)
+        );
+        assert_eq!(
+            unbounded_search_u64(&5, |index| [5].get(index as usize).copied()),
+            Some(0)
+        );
+        assert_eq!(
+            unbounded_search_u64(&4, |index| arr.get(index as usize).copied()),
+            None
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn unbounded_search_u64_matches_binary_search_on_slices() {
+        for len in 0..70 {
+            let arr: Vec<i32> = (0..len).map(|element| element * 3).collect();
+
+            for target in -2..len * 3 + 2 {
+                assert_eq!(
+                    unbounded_search_u64(&target, |index| arr.get(index as usize).copied()),
+                    binary_search(&target, &arr).map(|index| index as u64)
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn unbounded_search_u64_does_not_overflow_when_doubling_past_half_of_u64() {
+        let mut probes = Vec::new();
+        let found = unbounded_search_u64(&u64::MAX, |index| {
+            probes.push(index);
+            Some(index)
+        });
+
+        assert_eq!(found, None);
+        assert!(probes.iter().all(|&index| index < u64::MAX));
+        assert_eq!(
+            unbounded_search_u64(&(u64::MAX - 1), Some),
+            Some(u64::MAX - 1)
+        );
+        assert_eq!(
+            unbounded_search_u64(&(u64::MAX / 2 + 3), Some),
+            Some(u64::MAX / 2 + 3)
+        );
+    }
+}
@@ -1,9 +1,13 @@
 //! [Uniform binary search](https://en.wikipedia.org/wiki/Uniform_binary_search)
 
-use crate::utils;
-use std::cmp::Ordering;
+use crate::{
+    stats::{self, SearchStats},
+    utils,
+};
+use core::cmp::Ordering;
 
-const MAX_LOOKUP_TABLE_SIZE: usize = 64;
+/// One entry per halving of a `usize` length, down to the terminating zero
+const MAX_LOOKUP_TABLE_SIZE: usize = usize::BITS as usize + 1;
 
 /// Struct used for performing uniform binary search
 pub struct UniformBinarySearch {
@@ -52,7 +56,7 @@ impl UniformBinarySearch {
     where
         T: Ord,
     {
-        if !utils::is_sorted(&arr) {
+        if !utils::is_sorted(arr) {
             panic!("Uniform binary search encountered an array that is note sorted");
         }
 
@@ -62,23 +66,113 @@ impl UniformBinarySearch {
 
         let arr_len = arr.len();
 
-        if let Some(last_arr_size) = self.last_arr_size {
-            if last_arr_size != arr_len {
-                self.update_lookup_table(arr_len);
-            }
-        } else {
+        if self.last_arr_size != Some(arr_len) {
             self.update_lookup_table(arr_len);
         }
 
         self.inner_search(target, arr)
     }
 
+    /// Performs [uniform binary search](https://en.wikipedia.org/wiki/Uniform_binary_search) like
+    /// [`search`](Self::search), also returning the work it performed. Rebuilding the lookup table is not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binary_search::variations::UniformBinarySearch;
+    ///
+    /// let target = 5;
+    /// let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    /// let mut uniform_binary_search = UniformBinarySearch::new();
+    /// let (found, stats) = uniform_binary_search.search_with_stats(&target, &arr);
+    ///
+    /// assert_eq!(found, Some(4));
+    /// assert_eq!(stats.comparisons, stats.probes);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// The function panics if the array is not sorted.
+    pub fn search_with_stats<T>(&mut self, target: &T, arr: &[T]) -> (Option<usize>, SearchStats)
+    where
+        T: Ord,
+    {
+        if !utils::is_sorted(arr) {
+            panic!("Uniform binary search encountered an array that is note sorted");
+        }
+
+        let mut stats = SearchStats::default();
+
+        if arr.is_empty() {
+            return (None, stats);
+        }
+
+        if self.last_arr_size != Some(arr.len()) {
+            self.update_lookup_table(arr.len());
+        }
+
+        let mut position = self.lookup_table[0];
+        let mut lookup_table_index = 0;
+
+        loop {
+            if self.lookup_table[lookup_table_index] == 0 {
+                return (None, stats);
+            }
+
+            stats.probes += 1;
+
+            let ordering = if position == 0 || position > arr.len() {
+                compare_at(arr, position, target)
+            } else {
+                stats::compare(&arr[position - 1], target, &mut stats)
+            };
+
+            match ordering {
+                Ordering::Less => {
+                    lookup_table_index += 1;
+                    position += self.lookup_table[lookup_table_index];
+                }
+                Ordering::Equal => return (Some(position - 1), stats),
+                Ordering::Greater => {
+                    lookup_table_index += 1;
+                    position -= self.lookup_table[lookup_table_index];
+                }
+            }
+        }
+    }
+
+    /// Returns the length of the array the lookup table was last built for, or `None` if no table has been built yet
+    ///
+    /// Searching an array of a different length rebuilds the table in O(log n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binary_search::variations::UniformBinarySearch;
+    ///
+    /// let mut uniform_binary_search = UniformBinarySearch::new();
+    ///
+    /// assert_eq!(uniform_binary_search.cached_len(), None);
+    ///
+    /// uniform_binary_search.search(&3, &[1, 2, 3]);
+    ///
+    /// assert_eq!(uniform_binary_search.cached_len(), Some(3));
+    /// ```
+    pub fn cached_len(&self) -> Option<usize> {
+        self.last_arr_size
+    }
+
+    /// Clears the cached lookup table, so that the next search rebuilds it
+    pub fn reset(&mut self) {
+        self.last_arr_size = None;
+    }
+
     /// Perform binary search using the current lookup table
     fn inner_search<T>(&self, target: &T, arr: &[T]) -> Option<usize>
     where
         T: Ord,
     {
-        let mut index = self.lookup_table[0] - 1;
+        let mut position = self.lookup_table[0];
         let mut lookup_table_index = 0;
 
         loop {
@@ -86,37 +180,54 @@ impl UniformBinarySearch {
                 return None;
             }
 
-            match arr[index].cmp(target) {
+            match compare_at(arr, position, target) {
                 Ordering::Less => {
                     lookup_table_index += 1;
-                    index += self.lookup_table[lookup_table_index];
+                    position += self.lookup_table[lookup_table_index];
                 }
-                Ordering::Equal => return Some(index),
+                Ordering::Equal => return Some(position - 1),
                 Ordering::Greater => {
                     lookup_table_index += 1;
-                    index -= self.lookup_table[lookup_table_index];
+                    position -= self.lookup_table[lookup_table_index];
                 }
             }
         }
     }
 
     /// Updates lookup table
+    ///
+    /// Entry `i` is `len / 2^(i + 1)` rounded to the nearest integer, computed without overflowing for lengths close to
+    /// `usize::MAX`. The table ends at the first zero entry.
     fn update_lookup_table(&mut self, len: usize) {
-        let mut power = 1;
-        let mut i = 0;
-
-        loop {
-            let half = power;
+        for i in 0..MAX_LOOKUP_TABLE_SIZE {
+            let shift = i as u32 + 1;
+            let quotient = len.checked_shr(shift).unwrap_or(0);
+            // The bit below the quotient decides whether to round up
+            let round_up = len.checked_shr(shift - 1).unwrap_or(0) & 1;
 
-            power <<= 1;
-            self.lookup_table[i] = (len + half) / power;
+            self.lookup_table[i] = quotient + round_up;
 
             if self.lookup_table[i] == 0 {
                 break;
             }
-
-            i += 1;
         }
+
+        self.last_arr_size = Some(len);
+    }
+}
+
+/// Compares the element at the one-based `position` to `target`
+///
+/// The search can step onto the virtual elements at positions 0 and `arr.len() + 1`, just outside the array, which
+/// compare as smaller and greater than every target respectively.
+fn compare_at<T>(arr: &[T], position: usize, target: &T) -> Ordering
+where
+    T: Ord,
+{
+    match position.checked_sub(1).and_then(|index| arr.get(index)) {
+        Some(element) => element.cmp(target),
+        None if position == 0 => Ordering::Less,
+        None => Ordering::Greater,
     }
 }
 
@@ -126,6 +237,13 @@ impl Default for UniformBinarySearch {
     }
 }
 
+#[cfg(feature = "std")]
+impl crate::memory::MemoryUsage for UniformBinarySearch {
+    fn memory_bytes(&self) -> crate::memory::MemoryBreakdown {
+        crate::memory::MemoryBreakdown::new(self)
+    }
+}
+
 #[cfg(test)]
 mod uniform_tests {
     use crate::variations::uniform::MAX_LOOKUP_TABLE_SIZE;
@@ -235,4 +353,134 @@ mod uniform_tests {
 
         assert_eq!(found, Some(2));
     }
+
+    #[test]
+    fn update_lookup_table_handles_largest_lengths() {
+        let mut uniform_binary_search = UniformBinarySearch::new();
+
+        uniform_binary_search.update_lookup_table(usize::MAX);
+
+        let table = uniform_binary_search.lookup_table;
+
+        assert_eq!(table[0], 1 << (usize::BITS - 1));
+        assert_eq!(table[usize::BITS as usize - 1], 1);
+        assert_eq!(table[usize::BITS as usize], 0);
+    }
+
+    #[test]
+    fn update_lookup_table_matches_rounded_halvings() {
+        let mut uniform_binary_search = UniformBinarySearch::new();
+
+        for len in 1..300 {
+            uniform_binary_search.update_lookup_table(len);
+
+            let mut power = 1;
+
+            for &entry in uniform_binary_search.lookup_table.iter() {
+                let half = power;
+
+                power <<= 1;
+
+                assert_eq!(entry, (len + half) / power);
+
+                if entry == 0 {
+                    break;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn cached_len_reflects_last_search() {
+        let mut uniform_binary_search = UniformBinarySearch::new();
+
+        uniform_binary_search.search(&1, &[1, 2, 3]);
+
+        assert_eq!(uniform_binary_search.cached_len(), Some(3));
+
+        uniform_binary_search.search(&1, &[1, 2, 3, 4, 5]);
+
+        assert_eq!(uniform_binary_search.cached_len(), Some(5));
+    }
+
+    #[test]
+    fn cached_len_ignores_empty_arr() {
+        let mut uniform_binary_search = UniformBinarySearch::new();
+
+        uniform_binary_search.search(&1, &[1, 2, 3]);
+        uniform_binary_search.search(&1, &[]);
+
+        assert_eq!(uniform_binary_search.cached_len(), Some(3));
+    }
+
+    #[test]
+    fn search_reuses_table_for_same_length_arrs() {
+        let mut uniform_binary_search = UniformBinarySearch::new();
+        let first = [1, 2, 3, 4, 5];
+        let second = [10, 20, 30, 40, 50];
+
+        uniform_binary_search.search(&1, &first);
+
+        let table = uniform_binary_search.lookup_table;
+
+        assert_eq!(uniform_binary_search.search(&40, &second), Some(3));
+        assert_eq!(uniform_binary_search.lookup_table, table);
+    }
+
+    #[test]
+    fn reset_forces_table_recompute() {
+        let mut uniform_binary_search = UniformBinarySearch::new();
+        let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+        uniform_binary_search.search(&1, &arr);
+        uniform_binary_search.lookup_table = [0; MAX_LOOKUP_TABLE_SIZE];
+        uniform_binary_search.reset();
+
+        assert_eq!(uniform_binary_search.cached_len(), None);
+        assert_eq!(uniform_binary_search.search(&7, &arr), Some(6));
+        assert_eq!(uniform_binary_search.cached_len(), Some(10));
+    }
+
+    #[test]
+    fn search_with_stats_returns_the_same_results_as_search() {
+        let arr = [1, 3, 4, 6, 7, 8, 12, 13, 15, 20, 21, 22, 30];
+        let mut uniform_binary_search = UniformBinarySearch::new();
+
+        for target in 0..32 {
+            let (found, stats) = uniform_binary_search.search_with_stats(&target, &arr);
+
+            assert_eq!(found, uniform_binary_search.search(&target, &arr));
+            assert_eq!(stats.comparisons, stats.probes);
+            assert!(stats.probes <= 5);
+        }
+    }
+
+    #[test]
+    fn search_returns_none_if_target_below_first_element_of_two_element_arr() {
+        let mut uniform_binary_search = UniformBinarySearch::new();
+        let arr = [1, 3];
+
+        assert_eq!(uniform_binary_search.search(&0, &arr), None);
+        assert_eq!(uniform_binary_search.search_with_stats(&0, &arr).0, None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn search_matches_binary_search_for_every_small_length() {
+        let mut uniform_binary_search = UniformBinarySearch::new();
+
+        for len in 0..64 {
+            let arr: Vec<usize> = (0..len).map(|element| element * 2 + 1).collect();
+
+            for target in 0..len * 2 + 2 {
+                let expected = crate::binary_search(&target, &arr);
+
+                assert_eq!(uniform_binary_search.search(&target, &arr), expected);
+                assert_eq!(
+                    uniform_binary_search.search_with_stats(&target, &arr).0,
+                    expected
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,226 @@
+//! Verification of search results produced elsewhere against an authoritative sorted array
+
+use crate::core;
+use ::core::ops::Range;
+
+/// How a claimed range of indices compares to the range of elements equal to a target
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MatchVerdict {
+    /// The claimed range is exactly the range of matches
+    Exact,
+    /// The claimed range holds every match, plus non-matching elements on either side
+    Superset {
+        /// The number of non-matching elements claimed before the first match
+        extra_front: usize,
+        /// The number of non-matching elements claimed after the last match
+        extra_back: usize,
+    },
+    /// The claimed range holds only matches, but misses some on either side
+    Subset {
+        /// The number of matches missed before the claimed range
+        missing_front: usize,
+        /// The number of matches missed after the claimed range
+        missing_back: usize,
+    },
+    /// The claimed range holds some matches and some non-matching elements, but misses other matches
+    Overlapping {
+        /// The number of non-matching elements claimed
+        extra: usize,
+        /// The number of matches missed
+        missing: usize,
+    },
+    /// The claimed range holds no match and is not the empty range at the insertion point of the target
+    Disjoint,
+}
+
+/// Verifies that `claimed` is exactly the range of the elements of `arr` equal to `target`.
+///
+/// The verdict is computed with two rank searches, in O(log n) regardless of the length of `claimed`. When `target`
+/// is not present, the only exact claim is the empty range at its insertion point.
+///
+/// `arr` is trusted to be sorted, as checking it would cost O(n); the verdict is meaningless otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::verify::{self, MatchVerdict};
+///
+/// let target = 4;
+/// let arr = [1, 2, 4, 4, 4, 5];
+///
+/// assert_eq!(verify::is_exact_match_set(&target, &arr, 2..5), MatchVerdict::Exact);
+/// assert_eq!(
+///     verify::is_exact_match_set(&target, &arr, 1..5),
+///     MatchVerdict::Superset { extra_front: 1, extra_back: 0 }
+/// );
+/// ```
+///
+/// # Panics
+///
+/// The function panics if `claimed` is reversed or out of bounds.
+pub fn is_exact_match_set<T>(target: &T, arr: &[T], claimed: Range<usize>) -> MatchVerdict
+where
+    T: Ord,
+{
+    if claimed.start > claimed.end {
+        panic!(
+            "Verify encountered a reversed range {}..{}",
+            claimed.start, claimed.end
+        );
+    }
+
+    if claimed.end > arr.len() {
+        panic!(
+            "Verify range {}..{} is out of bounds for an array of length {}",
+            claimed.start,
+            claimed.end,
+            arr.len()
+        );
+    }
+
+    let first = core::lower_bound(target, arr);
+    let last = core::upper_bound(target, arr);
+
+    if claimed.start == first && claimed.end == last {
+        MatchVerdict::Exact
+    } else if claimed.start <= first && last <= claimed.end {
+        MatchVerdict::Superset {
+            extra_front: first - claimed.start,
+            extra_back: claimed.end - last,
+        }
+    } else if first <= claimed.start && claimed.end <= last {
+        MatchVerdict::Subset {
+            missing_front: claimed.start - first,
+            missing_back: last - claimed.end,
+        }
+    } else if claimed.start < last && first < claimed.end {
+        let overlap = claimed.end.min(last) - claimed.start.max(first);
+
+        MatchVerdict::Overlapping {
+            extra: claimed.len() - overlap,
+            missing: last - first - overlap,
+        }
+    } else {
+        MatchVerdict::Disjoint
+    }
+}
+
+#[cfg(test)]
+mod verify_tests {
+    use super::{is_exact_match_set, MatchVerdict};
+
+    #[test]
+    fn is_exact_match_set_returns_exact_for_equal_range() {
+        let target = 4;
+        let arr = [1, 2, 4, 4, 4, 5];
+        let verdict = is_exact_match_set(&target, &arr, 2..5);
+
+        assert_eq!(verdict, MatchVerdict::Exact);
+    }
+
+    #[test]
+    fn is_exact_match_set_returns_superset_if_claimed_range_too_wide() {
+        let target = 4;
+        let arr = [1, 2, 4, 4, 4, 5];
+        let verdict = is_exact_match_set(&target, &arr, 1..6);
+
+        assert_eq!(
+            verdict,
+            MatchVerdict::Superset {
+                extra_front: 1,
+                extra_back: 1
+            }
+        );
+    }
+
+    #[test]
+    fn is_exact_match_set_returns_subset_if_claimed_range_too_narrow() {
+        let target = 4;
+        let arr = [1, 2, 4, 4, 4, 5];
+        let verdict = is_exact_match_set(&target, &arr, 3..4);
+
+        assert_eq!(
+            verdict,
+            MatchVerdict::Subset {
+                missing_front: 1,
+                missing_back: 1
+            }
+        );
+    }
+
+    #[test]
+    fn is_exact_match_set_returns_overlapping_if_claimed_range_shifted() {
+        let target = 4;
+        let arr = [1, 2, 4, 4, 4, 5];
+        let verdict = is_exact_match_set(&target, &arr, 3..6);
+
+        assert_eq!(
+            verdict,
+            MatchVerdict::Overlapping {
+                extra: 1,
+                missing: 1
+            }
+        );
+    }
+
+    #[test]
+    fn is_exact_match_set_returns_disjoint_if_claimed_range_holds_no_match() {
+        let target = 4;
+        let arr = [1, 2, 4, 4, 4, 5];
+        let verdict = is_exact_match_set(&target, &arr, 0..2);
+
+        assert_eq!(verdict, MatchVerdict::Disjoint);
+    }
+
+    #[test]
+    fn is_exact_match_set_returns_exact_for_empty_range_at_insertion_point() {
+        let target = 3;
+        let arr = [1, 2, 4, 4, 4, 5];
+        let verdict = is_exact_match_set(&target, &arr, 2..2);
+
+        assert_eq!(verdict, MatchVerdict::Exact);
+    }
+
+    #[test]
+    fn is_exact_match_set_returns_disjoint_for_empty_range_at_wrong_insertion_point() {
+        let target = 3;
+        let arr = [1, 2, 4, 4, 4, 5];
+        let verdict = is_exact_match_set(&target, &arr, 4..4);
+
+        assert_eq!(verdict, MatchVerdict::Disjoint);
+    }
+
+    #[test]
+    fn is_exact_match_set_returns_subset_for_empty_range_inside_matches() {
+        let target = 4;
+        let arr = [1, 2, 4, 4, 4, 5];
+        let verdict = is_exact_match_set(&target, &arr, 3..3);
+
+        assert_eq!(
+            verdict,
+            MatchVerdict::Subset {
+                missing_front: 1,
+                missing_back: 2
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Verify range 4..7 is out of bounds for an array of length 6")]
+    fn is_exact_match_set_panics_for_out_of_bounds_range() {
+        let target = 4;
+        let arr = [1, 2, 4, 4, 4, 5];
+
+        is_exact_match_set(&target, &arr, 4..7);
+    }
+
+    #[test]
+    #[should_panic(expected = "Verify encountered a reversed range 5..2")]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn is_exact_match_set_panics_for_reversed_range() {
+        let target = 4;
+        let arr = [1, 2, 4, 4, 4, 5];
+
+        is_exact_match_set(&target, &arr, 5..2);
+    }
+}